@@ -0,0 +1,47 @@
+//! Frame timing shared by benchmarks
+
+use std::time::{Duration, Instant};
+
+/// Number of timed samples, median sample is reported
+const SAMPLE_COUNT: usize = 11;
+
+/// Median duration of one frame showing `ui_fn` in central panel of 800x600 screen
+///
+/// Layout is calculated in the first frames, they are run before timing. Every sample
+/// measures average of `frame_count` frames, median of samples is less affected by
+/// outliers (e.g. thread scheduling) than a single sample.
+pub fn median_frame_time(
+    ctx: &egui::Context,
+    frame_count: usize,
+    mut ui_fn: impl FnMut(&mut egui::Ui),
+) -> Duration {
+    let mut run_frame = || {
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800., 600.),
+            )),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| ui_fn(ui));
+        });
+    };
+
+    // Warm up
+    for _ in 0..3 {
+        run_frame();
+    }
+
+    let mut samples: Vec<Duration> = (0..SAMPLE_COUNT)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..frame_count {
+                run_frame();
+            }
+            start.elapsed() / frame_count as u32
+        })
+        .collect();
+    samples.sort();
+    samples[SAMPLE_COUNT / 2]
+}
@@ -0,0 +1,67 @@
+//! Compares scroll area with many items against the same scroll area where items are added
+//! with [`TuiBuilderLogic::add_culled`] and content of items outside of viewport is skipped.
+//!
+//! Run with `cargo bench --bench culled_scroll`.
+
+mod common;
+
+use egui_taffy::{taffy, tui, Tui, TuiBuilderLogic};
+use taffy::prelude::{length, percent};
+
+const ITEM_COUNT: usize = 2000;
+const FRAME_COUNT: usize = 100;
+
+fn item_style() -> taffy::Style {
+    taffy::Style {
+        flex_direction: taffy::FlexDirection::Row,
+        flex_shrink: 0.,
+        gap: length(4.),
+        ..Default::default()
+    }
+}
+
+fn item_content(tui: &mut Tui, idx: usize) {
+    tui.label(format!("Item {}", idx));
+    tui.label("value");
+}
+
+fn scroll_area(ui: &mut egui::Ui, id: &str, item: impl Fn(&mut Tui, usize)) {
+    tui(ui, ui.id().with(id))
+        .reserve_available_space()
+        .style(taffy::Style {
+            flex_direction: taffy::FlexDirection::Column,
+            overflow: taffy::Point {
+                x: taffy::Overflow::Visible,
+                y: taffy::Overflow::Scroll,
+            },
+            size: percent(1.),
+            max_size: percent(1.),
+            ..Default::default()
+        })
+        .show(|tui| {
+            for idx in 0..ITEM_COUNT {
+                item(tui, idx);
+            }
+        });
+}
+
+fn main() {
+    let all = common::median_frame_time(&egui::Context::default(), FRAME_COUNT, |ui| {
+        scroll_area(ui, "all", |tui, idx| {
+            tui.style(item_style()).add(|tui| item_content(tui, idx));
+        });
+    });
+
+    let culled = common::median_frame_time(&egui::Context::default(), FRAME_COUNT, |ui| {
+        scroll_area(ui, "culled", |tui, idx| {
+            tui.style(item_style())
+                .add_culled(100., |tui| item_content(tui, idx));
+        });
+    });
+
+    println!("{} items, all built: {:?} per frame", ITEM_COUNT, all);
+    println!(
+        "{} items, culled outside of viewport: {:?} per frame",
+        ITEM_COUNT, culled
+    );
+}
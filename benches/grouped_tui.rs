@@ -0,0 +1,59 @@
+//! Compares many separate tuis against one tui grouping the same layouts with
+//! [`TuiBuilderLogic::sub_region`].
+//!
+//! Run with `cargo bench --bench grouped_tui`.
+
+mod common;
+
+use egui_taffy::{taffy, tui, TuiBuilderLogic};
+use taffy::prelude::length;
+
+const LAYOUT_COUNT: usize = 50;
+const FRAME_COUNT: usize = 200;
+
+fn region_style() -> taffy::Style {
+    taffy::Style {
+        flex_direction: taffy::FlexDirection::Row,
+        gap: length(4.),
+        ..Default::default()
+    }
+}
+
+fn region_content(tui: &mut egui_taffy::Tui, idx: usize) {
+    tui.label(format!("Layout {}", idx));
+    tui.label("value");
+}
+
+fn main() {
+    let separate = common::median_frame_time(&egui::Context::default(), FRAME_COUNT, |ui| {
+        for idx in 0..LAYOUT_COUNT {
+            tui(ui, ui.id().with(("separate", idx)))
+                .reserve_available_width()
+                .style(region_style())
+                .show(|tui| region_content(tui, idx));
+        }
+    });
+
+    let grouped = common::median_frame_time(&egui::Context::default(), FRAME_COUNT, |ui| {
+        tui(ui, ui.id().with("grouped"))
+            .reserve_available_width()
+            .style(taffy::Style {
+                flex_direction: taffy::FlexDirection::Column,
+                ..Default::default()
+            })
+            .show(|tui| {
+                for idx in 0..LAYOUT_COUNT {
+                    tui.style(region_style())
+                        .sub_region(egui::Id::new(("grouped", idx)), |tui| {
+                            region_content(tui, idx)
+                        });
+                }
+            });
+    });
+
+    println!("{} separate tuis: {:?} per frame", LAYOUT_COUNT, separate);
+    println!(
+        "{} sub regions in one tui: {:?} per frame",
+        LAYOUT_COUNT, grouped
+    );
+}
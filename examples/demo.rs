@@ -1,10 +1,22 @@
 use eframe::egui::{self, Vec2b};
 use eframe::{App, Frame};
 use egui_taffy::{
+    measure_ui,
+    style_builder::StyleBuilder,
     taffy, tid, tui,
-    virtual_tui::{VirtualGridRowHelper, VirtualGridRowHelperParams},
-    TuiBuilderLogic,
+    virtual_tui::{
+        VirtualGridColumnAlign, VirtualGridColumnHelper, VirtualGridColumnHelperParams,
+        VirtualGridRowHelper, VirtualGridRowHelperParams, VirtualListHelper,
+        VirtualListHelperParams, VirtualTreeHelper,
+    },
+    widgets::{
+        ChipInputEvent, TaffyImage, TaffySelectableLabel, TuiCanvas, TuiChipInput, TuiCollapsing,
+        TuiCollapsingHeader, TuiComboBox, TuiDecimal, TuiForm, TuiInlineFlow, TuiMenu, TuiSplit,
+        TuiStepper, TuiTabBar, TuiTextEdit,
+    },
+    AppearAnimation, Tui, TuiBuilderLogic, TuiContainerResponse, TuiId, TuiMeasuredWidget,
 };
+use std::collections::HashSet;
 use taffy::{
     prelude::{auto, fr, length, min_content, percent, repeat, span},
     style_helpers, Style,
@@ -19,6 +31,7 @@ pub struct MyApp {
 pub struct State {
     grow_variables: Option<GrowVariables>,
     button_params: ButtonParams,
+    form_params: FormParams,
     show_flex_grid_demo: bool,
     show_flex_demo: bool,
     show_flex_wrap_demo: bool,
@@ -27,6 +40,132 @@ pub struct State {
     show_overflow_demo: bool,
     show_grid_sticky_demo: bool,
     show_virtual_grid_demo: bool,
+    show_virtual_grid_columns_demo: bool,
+    show_form_demo: bool,
+    show_image_demo: bool,
+    show_collapsing_demo: bool,
+    show_terminal_demo: bool,
+    show_pixel_rounding_demo: bool,
+    show_baseline_demo: bool,
+    show_scroll_spy_demo: bool,
+    show_text_scale_demo: bool,
+    show_chat_demo: bool,
+    show_highlight_demo: bool,
+    show_hover_card_demo: bool,
+    show_sidebar_demo: bool,
+    show_touch_scroll_demo: bool,
+    show_vector_image_demo: bool,
+    show_spreadsheet_demo: bool,
+    show_sticky_headers_demo: bool,
+    show_responsive_toolbar_demo: bool,
+    show_footer_demo: bool,
+    show_overflow_indicator_demo: bool,
+    show_sortable_list_demo: bool,
+    show_auto_size_window_demo: bool,
+    show_rubber_band_demo: bool,
+    show_tag_filter_demo: bool,
+    show_nested_sticky_demo: bool,
+    show_checkout_wizard_demo: bool,
+    show_file_tree_demo: bool,
+    show_pixel_snap_demo: bool,
+    show_tab_bar_demo: bool,
+    show_responsive_dashboard_demo: bool,
+    show_command_palette_demo: bool,
+    show_ripple_demo: bool,
+    show_custom_scrollbar_demo: bool,
+    show_appear_demo: bool,
+    show_resize_events_demo: bool,
+    show_virtual_tree_demo: bool,
+    show_tab_order_demo: bool,
+    show_scroll_overflow_demo: bool,
+    show_decimal_table_demo: bool,
+    show_empty_state_demo: bool,
+    show_checkbox_wrap_demo: bool,
+    show_thumbnail_demo: bool,
+    show_reveal_demo: bool,
+    show_scroll_velocity_demo: bool,
+    show_split_demo: bool,
+    show_line_clamp_demo: bool,
+    show_measured_widget_demo: bool,
+    show_scroll_fade_demo: bool,
+    show_rtl_demo: bool,
+    show_spacer_demo: bool,
+    show_multi_select_demo: bool,
+    show_streaming_log_demo: bool,
+    show_auto_sized_grid_demo: bool,
+    show_floating_button_demo: bool,
+    show_combo_box_demo: bool,
+    show_node_graph_demo: bool,
+    show_collapsing_header_demo: bool,
+    show_notes_demo: bool,
+    show_pull_to_refresh_demo: bool,
+    show_virtual_2d_grid_demo: bool,
+    show_pill_button_demo: bool,
+    show_virtual_list_demo: bool,
+    pixel_rounding: bool,
+    /// Text scale of zoomable preview region
+    preview_zoom: Option<f32>,
+    chat_messages: Vec<String>,
+    chat_input: String,
+    /// Onboarding step of highlight demo
+    highlight_step: usize,
+    sidebar_selected: usize,
+    touch_scroll_disabled: bool,
+    touch_scroll_values: Vec<f32>,
+    vector_icon_size: Option<f32>,
+    /// Navigation item count in footer demo sidebar
+    footer_demo_items: Option<usize>,
+    /// Is clamped text expanded in overflow indicator demo
+    overflow_indicator_expanded: bool,
+    sortable_items: Vec<&'static str>,
+    /// Are details shown in auto size window demo
+    auto_size_window_details: bool,
+    /// Cells selected in rubber band selection demo
+    rubber_band_selected: Vec<egui::Id>,
+    tag_filter: Vec<String>,
+    tag_filter_text: String,
+    /// Current step of checkout wizard demo
+    checkout_step: usize,
+    /// Is pixel snapping enabled in pixel snap demo
+    pixel_snap: bool,
+    selected_tab: usize,
+    command_palette_query: String,
+    /// Last command run in command palette demo
+    command_palette_last: Option<&'static str>,
+    /// Number of cards in appear animation demo
+    appear_cards: usize,
+    /// Last panel resize events of resize events demo
+    resize_log: Vec<String>,
+    /// Expanded directories (top level index, subdirectory index) of virtual tree demo
+    virtual_tree_expanded: HashSet<(usize, Option<usize>)>,
+    tab_order_fields: [String; 6],
+    tab_order_visual: bool,
+    scroll_overflow_max_height: Option<f32>,
+    empty_state_items: Vec<String>,
+    checkbox_wrap_checked: [bool; 3],
+    checkbox_wrap_selected: usize,
+    checkbox_wrap_width: Option<f32>,
+    thumbnail_cards: Option<usize>,
+    thumbnail_scale: Option<f32>,
+    reveal_card: bool,
+    reveal_from_center: Option<bool>,
+    multi_select_selected: HashSet<usize>,
+    multi_select_anchor: Option<usize>,
+    streaming_log: Vec<String>,
+    streaming_log_count: usize,
+    streaming_log_debounce: Option<bool>,
+    auto_sized_grid_rows: Option<usize>,
+    floating_button_items: Option<usize>,
+    combo_box_font: usize,
+    combo_box_size: usize,
+    node_graph_transform: egui::emath::TSTransform,
+    node_graph_positions: Vec<egui::Pos2>,
+    notes_title: String,
+    notes_text: String,
+    pull_to_refresh_count: usize,
+    /// How many times expensive collapsing section body was built
+    collapsing_build_count: usize,
+    image_texture: Option<egui::TextureHandle>,
 }
 
 impl App for MyApp {
@@ -64,58 +203,288 @@ impl App for MyApp {
         grid_sticky(ctx, state);
 
         virtual_grid_demo(ctx, state);
+
+        virtual_grid_columns_demo(ctx, state);
+
+        form_demo(ctx, state);
+
+        image_demo(ctx, state);
+
+        collapsing_demo(ctx, state);
+
+        terminal_demo(ctx, state);
+
+        pixel_rounding_demo(ctx, state);
+
+        baseline_demo(ctx, state);
+
+        scroll_spy_demo(ctx, state);
+
+        text_scale_demo(ctx, state);
+
+        chat_demo(ctx, state);
+
+        highlight_demo(ctx, state);
+
+        hover_card_demo(ctx, state);
+
+        sidebar_demo(ctx, state);
+
+        touch_scroll_demo(ctx, state);
+
+        vector_image_demo(ctx, state);
+
+        spreadsheet_demo(ctx, state);
+
+        sticky_headers_demo(ctx, state);
+
+        responsive_toolbar_demo(ctx, state);
+
+        footer_demo(ctx, state);
+
+        overflow_indicator_demo(ctx, state);
+
+        sortable_list_demo(ctx, state);
+
+        auto_size_window_demo(ctx, state);
+
+        rubber_band_demo(ctx, state);
+
+        tag_filter_demo(ctx, state);
+
+        nested_sticky_demo(ctx, state);
+
+        checkout_wizard_demo(ctx, state);
+
+        file_tree_demo(ctx, state);
+
+        pixel_snap_demo(ctx, state);
+
+        tab_bar_demo(ctx, state);
+
+        responsive_dashboard_demo(ctx, state);
+
+        command_palette_demo(ctx, state);
+
+        ripple_demo(ctx, state);
+
+        custom_scrollbar_demo(ctx, state);
+
+        appear_demo(ctx, state);
+
+        resize_events_demo(ctx, state);
+
+        virtual_tree_demo(ctx, state);
+
+        tab_order_demo(ctx, state);
+
+        scroll_overflow_demo(ctx, state);
+
+        decimal_table_demo(ctx, state);
+
+        empty_state_demo(ctx, state);
+
+        checkbox_wrap_demo(ctx, state);
+
+        thumbnail_demo(ctx, state);
+
+        reveal_demo(ctx, state);
+
+        scroll_velocity_demo(ctx, state);
+
+        split_demo(ctx, state);
+
+        line_clamp_demo(ctx, state);
+
+        measured_widget_demo(ctx, state);
+
+        scroll_fade_demo(ctx, state);
+
+        rtl_demo(ctx, state);
+
+        spacer_demo(ctx, state);
+
+        multi_select_demo(ctx, state);
+
+        streaming_log_demo(ctx, state);
+
+        auto_sized_grid_demo(ctx, state);
+
+        floating_button_demo(ctx, state);
+
+        combo_box_demo(ctx, state);
+
+        node_graph_demo(ctx, state);
+
+        collapsing_header_demo(ctx, state);
+
+        notes_demo(ctx, state);
+
+        pull_to_refresh_demo(ctx, state);
+
+        virtual_2d_grid_demo(ctx, state);
+
+        pill_button_demo(ctx, state);
+
+        virtual_list_demo(ctx, state);
     }
 }
 
 fn ui_side_panel(ctx: &egui::Context, state: &mut State) {
-    egui::SidePanel::new(egui::panel::Side::Left, "panel").show(ctx, |ui| {
-        tui(ui, ui.id().with("side_panel"))
-            .reserve_available_space()
+    let tui_id = egui::Id::new("side_panel");
+    let style = || taffy::Style {
+        flex_direction: taffy::FlexDirection::Column,
+        align_items: Some(taffy::AlignItems::Stretch),
+        padding: length(4.),
+        gap: length(2.),
+        ..Default::default()
+    };
+
+    // Measure menu first to size side panel exactly to its content
+    let size = tui(&mut measure_ui(ctx, "side_panel_measure"), tui_id)
+        .style(style())
+        .measure_only(|tui| side_panel_menu(tui, state));
+
+    let frame = egui::Frame::side_top_panel(&ctx.style());
+    egui::SidePanel::new(egui::panel::Side::Left, "panel")
+        .frame(frame)
+        .resizable(false)
+        .exact_width(size.x + frame.inner_margin.sum().x)
+        .show(ctx, |ui| {
+            // Menu scrolls when it does not fit in the panel height
+            let mut style = style();
+            style.overflow.y = taffy::Overflow::Scroll;
+            style.max_size.height = length(ui.available_height());
+
+            tui(ui, tui_id)
+                .reserve_available_space()
+                .style(style)
+                .show(|tui| side_panel_menu(tui, state));
+        });
+}
+
+fn side_panel_menu(tui: &mut Tui, state: &mut State) {
+    tui.heading("Egui Taffy");
+    tui.ui(|ui| {
+        ui.hyperlink("https://github.com/PPakalns/egui_taffy");
+    });
+
+    tui.separator();
+
+    tui.heading("Demos:");
+
+    for (label, show) in [
+        ("Grid demo", &mut state.show_flex_grid_demo),
+        ("Flex demo", &mut state.show_flex_demo),
+        ("Flex wrap demo", &mut state.show_flex_wrap_demo),
+        ("Grow demo", &mut state.show_grow_demo),
+        ("Button demo", &mut state.show_button_demo),
+        ("Overflow demo", &mut state.show_overflow_demo),
+        (
+            "Sticky header and column in grid",
+            &mut state.show_grid_sticky_demo,
+        ),
+        ("Virtual grid row demo", &mut state.show_virtual_grid_demo),
+        (
+            "Virtual grid column alignment demo",
+            &mut state.show_virtual_grid_columns_demo,
+        ),
+        ("Form demo", &mut state.show_form_demo),
+        ("Image demo", &mut state.show_image_demo),
+        ("Collapsing demo", &mut state.show_collapsing_demo),
+        ("Terminal demo", &mut state.show_terminal_demo),
+        ("Pixel rounding demo", &mut state.show_pixel_rounding_demo),
+        ("Baseline demo", &mut state.show_baseline_demo),
+        ("Scroll spy demo", &mut state.show_scroll_spy_demo),
+        ("Text scale demo", &mut state.show_text_scale_demo),
+        ("Chat demo", &mut state.show_chat_demo),
+        ("Highlight demo", &mut state.show_highlight_demo),
+        ("Hover card demo", &mut state.show_hover_card_demo),
+        ("Sidebar demo", &mut state.show_sidebar_demo),
+        ("Touch scroll demo", &mut state.show_touch_scroll_demo),
+        ("Vector image demo", &mut state.show_vector_image_demo),
+        ("Spreadsheet demo", &mut state.show_spreadsheet_demo),
+        ("Sticky headers demo", &mut state.show_sticky_headers_demo),
+        (
+            "Responsive toolbar demo",
+            &mut state.show_responsive_toolbar_demo,
+        ),
+        ("Footer demo", &mut state.show_footer_demo),
+        (
+            "Overflow indicator demo",
+            &mut state.show_overflow_indicator_demo,
+        ),
+        ("Sortable list demo", &mut state.show_sortable_list_demo),
+        (
+            "Auto size window demo",
+            &mut state.show_auto_size_window_demo,
+        ),
+        (
+            "Rubber band selection demo",
+            &mut state.show_rubber_band_demo,
+        ),
+        ("Tag filter demo", &mut state.show_tag_filter_demo),
+        ("Nested sticky demo", &mut state.show_nested_sticky_demo),
+        ("Checkout wizard demo", &mut state.show_checkout_wizard_demo),
+        ("File tree demo", &mut state.show_file_tree_demo),
+        ("Pixel snap demo", &mut state.show_pixel_snap_demo),
+        ("Tab bar demo", &mut state.show_tab_bar_demo),
+        (
+            "Responsive dashboard demo",
+            &mut state.show_responsive_dashboard_demo,
+        ),
+        ("Command palette demo", &mut state.show_command_palette_demo),
+        ("Ripple demo", &mut state.show_ripple_demo),
+        (
+            "Custom scrollbar demo",
+            &mut state.show_custom_scrollbar_demo,
+        ),
+        ("Appear animation demo", &mut state.show_appear_demo),
+        ("Resize events demo", &mut state.show_resize_events_demo),
+        ("Virtual tree demo", &mut state.show_virtual_tree_demo),
+        ("Tab order demo", &mut state.show_tab_order_demo),
+        ("Scroll overflow demo", &mut state.show_scroll_overflow_demo),
+        ("Decimal table demo", &mut state.show_decimal_table_demo),
+        ("Empty state demo", &mut state.show_empty_state_demo),
+        ("Checkbox wrap demo", &mut state.show_checkbox_wrap_demo),
+        ("Thumbnail demo", &mut state.show_thumbnail_demo),
+        ("Reveal demo", &mut state.show_reveal_demo),
+        ("Scroll velocity demo", &mut state.show_scroll_velocity_demo),
+        ("Split demo", &mut state.show_split_demo),
+        ("Line clamp demo", &mut state.show_line_clamp_demo),
+        ("Measured widget demo", &mut state.show_measured_widget_demo),
+        ("Scroll fade demo", &mut state.show_scroll_fade_demo),
+        ("RTL text demo", &mut state.show_rtl_demo),
+        ("Spacer demo", &mut state.show_spacer_demo),
+        ("Multi select demo", &mut state.show_multi_select_demo),
+        ("Streaming log demo", &mut state.show_streaming_log_demo),
+        ("Auto sized grid demo", &mut state.show_auto_sized_grid_demo),
+        ("Floating button demo", &mut state.show_floating_button_demo),
+        ("Combo box demo", &mut state.show_combo_box_demo),
+        ("Node graph demo", &mut state.show_node_graph_demo),
+        (
+            "Collapsing header demo",
+            &mut state.show_collapsing_header_demo,
+        ),
+        ("Notes demo", &mut state.show_notes_demo),
+        ("Pull to refresh demo", &mut state.show_pull_to_refresh_demo),
+        ("Virtual 2D grid demo", &mut state.show_virtual_2d_grid_demo),
+        ("Pill button demo", &mut state.show_pill_button_demo),
+        ("Virtual list demo", &mut state.show_virtual_list_demo),
+    ] {
+        if tui
             .style(taffy::Style {
-                flex_direction: taffy::FlexDirection::Column,
-                align_items: Some(taffy::AlignItems::Stretch),
                 padding: length(4.),
-                gap: length(2.),
                 ..Default::default()
             })
-            .show(|tui| {
-                tui.heading("Egui Taffy");
-                tui.ui(|ui| {
-                    ui.hyperlink("https://github.com/PPakalns/egui_taffy");
-                });
-
-                tui.separator();
-
-                tui.heading("Demos:");
-
-                for (label, show) in [
-                    ("Grid demo", &mut state.show_flex_grid_demo),
-                    ("Flex demo", &mut state.show_flex_demo),
-                    ("Flex wrap demo", &mut state.show_flex_wrap_demo),
-                    ("Grow demo", &mut state.show_grow_demo),
-                    ("Button demo", &mut state.show_button_demo),
-                    ("Overflow demo", &mut state.show_overflow_demo),
-                    (
-                        "Sticky header and column in grid",
-                        &mut state.show_grid_sticky_demo,
-                    ),
-                    ("Virtual grid row demo", &mut state.show_virtual_grid_demo),
-                ] {
-                    if tui
-                        .style(taffy::Style {
-                            padding: length(4.),
-                            ..Default::default()
-                        })
-                        .selectable(*show, |tui| {
-                            tui.label(label);
-                        })
-                        .clicked()
-                    {
-                        *show = !*show;
-                    }
-                }
-            });
-    });
+            .selectable(*show, |tui| {
+                tui.label(label);
+            })
+            .clicked()
+        {
+            *show = !*show;
+        }
+    }
 }
 
 fn flex_wrap_demo(ctx: &egui::Context, state: &mut State) {
@@ -193,6 +562,27 @@ fn flex_wrap_demo(ctx: &egui::Context, state: &mut State) {
                             });
                         }
                     });
+
+                    tui.separator();
+
+                    // Inline content wraps like text
+                    TuiInlineFlow::show(tui, |flow| {
+                        flow.text("Inline content with");
+                        let _ = flow.tui().ui_add(egui::Button::new("buttons"));
+                        flow.text("and");
+                        flow.tui().ui_add(egui::Hyperlink::from_label_and_url(
+                            "links",
+                            "https://github.com/PPakalns/egui_taffy",
+                        ));
+                        flow.text("wraps at available width together with");
+                        flow.rich_text("formatted text", |text| text.strong());
+                        flow.text("like a sentence.");
+                        flow.wrapped_text(
+                            egui::RichText::new("Side note block keeps its wrapped width.").weak(),
+                            120.,
+                        );
+                        flow.text("Flow continues after the note.");
+                    });
                 });
         });
 }
@@ -498,6 +888,8 @@ const FLEX_ITEMS: [&str; 18] = [
 struct ButtonParams {
     counter: u32,
     selected: bool,
+    card_double_clicks: u32,
+    card_drag_offset: egui::Vec2,
 }
 
 fn button_demo(ctx: &egui::Context, state: &mut State) {
@@ -506,7 +898,7 @@ fn button_demo(ctx: &egui::Context, state: &mut State) {
         .scroll(Vec2b { x: true, y: true })
         .open(&mut state.show_button_demo)
         .show(ctx, |ui| {
-            tui(ui, ui.id().with("button demo"))
+            let response = tui(ui, ui.id().with("button demo"))
                 .reserve_available_width()
                 .style(Style {
                     flex_direction: taffy::FlexDirection::Column,
@@ -520,7 +912,7 @@ fn button_demo(ctx: &egui::Context, state: &mut State) {
                     padding: length(8.),
                     ..Default::default()
                 })
-                .show(|tui| {
+                .show_with_response(|tui| {
                     let align_list = [
                         taffy::AlignItems::Start,
                         taffy::AlignItems::Center,
@@ -559,7 +951,10 @@ fn button_demo(ctx: &egui::Context, state: &mut State) {
                         params.counter += 1;
                     }
 
-                    tui.label(format!("Button clicked {} times", params.counter));
+                    tui.label(format!(
+                        "Button clicked {} times (Ctrl+R to reset)",
+                        params.counter
+                    ));
 
                     tui.separator();
 
@@ -594,7 +989,93 @@ fn button_demo(ctx: &egui::Context, state: &mut State) {
                     }
 
                     tui.label(format!("Selected: {}", params.selected));
-                });
+
+                    tui.separator();
+
+                    let response = tui
+                        .style(taffy::Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            align_items: Some(taffy::AlignItems::Center),
+                            padding: length(8.),
+                            ..Default::default()
+                        })
+                        .clickable_with_sense(egui::Sense::click_and_drag(), |tui| {
+                            tui.add_with_border(|tui| {
+                                tui.label("Card: double click or drag me");
+                            });
+                        });
+                    if response.double_clicked() {
+                        params.card_double_clicks += 1;
+                    }
+                    if response.dragged() {
+                        params.card_drag_offset += response.drag_delta();
+                    }
+
+                    tui.label(format!(
+                        "Card double clicked {} times, dragged by {:?}",
+                        params.card_double_clicks, params.card_drag_offset
+                    ));
+
+                    tui.separator();
+
+                    // Foreground is drawn above node content
+                    tui.style(taffy::Style {
+                        padding: length(8.),
+                        ..Default::default()
+                    })
+                    .add_with_foreground(
+                        |painter, rect| {
+                            let time = painter.ctx().input(|input| input.time);
+                            let pulse = (time * 3.).sin() as f32 * 0.5 + 0.5;
+                            painter.rect_stroke(
+                                rect.expand(2. + 2. * pulse),
+                                4.,
+                                egui::Stroke::new(
+                                    2.,
+                                    egui::Color32::LIGHT_BLUE.gamma_multiply(pulse),
+                                ),
+                                egui::StrokeKind::Outside,
+                            );
+                            painter.ctx().request_repaint();
+                        },
+                        |tui| {
+                            tui.label("Highlighted element");
+                        },
+                    );
+
+                    tui.separator();
+
+                    // Tooltip is shown after egui tooltip delay, also above child widgets
+                    let _ = tui
+                        .style(taffy::Style {
+                            flex_direction: taffy::FlexDirection::Row,
+                            align_items: Some(taffy::AlignItems::Center),
+                            gap: length(8.),
+                            padding: length(8.),
+                            ..Default::default()
+                        })
+                        .add_with_tooltip(
+                            |ui| {
+                                ui.label("Container tooltip");
+                            },
+                            |tui| {
+                                tui.add_with_border(|tui| {
+                                    tui.label("Hover container");
+                                });
+                                let _ = tui.ui_add(egui::Button::new("Child button"));
+                            },
+                        );
+                })
+                .response;
+
+            // Shortcut is handled only when pointer is above tui
+            let reset_shortcut =
+                egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::R);
+            if response.contains_pointer()
+                && ui.input_mut(|input| input.consume_shortcut(&reset_shortcut))
+            {
+                params.counter = 0;
+            }
         });
 }
 
@@ -676,6 +1157,7 @@ fn grid_sticky(ctx: &egui::Context, state: &mut State) {
                         size: percent(1.),
                         max_size: percent(1.),
                         display: taffy::Display::Grid,
+                        gap: length(2.),
                         align_items: Some(taffy::AlignItems::Stretch),
                         justify_items: Some(taffy::AlignItems::Stretch),
                         grid_template_rows: vec![auto(); (rows + 1) as usize],
@@ -845,6 +1327,3606 @@ fn virtual_grid_demo(ctx: &egui::Context, state: &mut State) {
         });
 }
 
+fn virtual_grid_columns_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Virtual grid column alignment demo")
+        .open(&mut state.show_virtual_grid_columns_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("virtual_grid_columns"))
+                .reserve_available_space()
+                .style(taffy::Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(taffy::Style {
+                        display: taffy::Display::Grid,
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        grid_template_columns: vec![auto(), fr(1.), auto()],
+                        size: taffy::Size {
+                            width: percent(1.),
+                            height: auto(),
+                        },
+                        max_size: percent(1.),
+                        grid_auto_rows: vec![min_content()],
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        // Numeric columns are right aligned
+                        let columns = [
+                            VirtualGridColumnAlign::default().justify_self(taffy::JustifySelf::End),
+                            VirtualGridColumnAlign::default()
+                                .justify_self(taffy::JustifySelf::Start),
+                            VirtualGridColumnAlign::default().justify_self(taffy::JustifySelf::End),
+                        ];
+
+                        VirtualGridRowHelper::show_with_columns(
+                            VirtualGridRowHelperParams {
+                                header_row_count: 1,
+                                row_count: 100000,
+                            },
+                            &columns,
+                            tui,
+                            |tui, info| {
+                                let mut idgen = info.id_gen();
+
+                                tui.id(idgen())
+                                    .mut_style(info.cell_setter(0))
+                                    .label(format!("{}", info.idx));
+                                tui.id(idgen())
+                                    .mut_style(info.cell_setter(1))
+                                    .label(format!("Item {}", info.idx));
+                                tui.id(idgen())
+                                    .mut_style(info.cell_setter(2))
+                                    .label(format!("{:.2}", (info.idx * info.idx) as f32 / 7.));
+                            },
+                        );
+
+                        for (idx, header) in ["#", "Name", "Price"].into_iter().enumerate() {
+                            tui.sticky([false, true].into())
+                                .style(taffy::Style {
+                                    grid_row: style_helpers::line(1),
+                                    padding: length(4.),
+                                    ..Default::default()
+                                })
+                                .id(tid(("header", idx)))
+                                .add_with_background_color(|tui| {
+                                    tui.label(header);
+                                });
+                        }
+                    });
+                });
+        });
+}
+
+#[derive(Default)]
+struct FormParams {
+    name: String,
+    description: String,
+    volume: f32,
+    notifications: bool,
+}
+
+fn form_demo(ctx: &egui::Context, state: &mut State) {
+    let params = &mut state.form_params;
+    egui::Window::new("Form demo")
+        .open(&mut state.show_form_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("form demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    min_size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    // Labels share one column, fields are stretched in the second one
+                    TuiForm::show(
+                        tui.style(Style {
+                            gap: length(8.),
+                            ..Default::default()
+                        }),
+                        |form| {
+                            form.full_row(|tui| {
+                                tui.heading("Settings");
+                            });
+                            form.row("Name", |tui| {
+                                tui.ui_add(egui::TextEdit::singleline(&mut params.name));
+                            });
+                            form.multiline_row("Description", |tui| {
+                                tui.ui_add(egui::TextEdit::multiline(&mut params.description));
+                            });
+                            form.row("Volume", |tui| {
+                                tui.ui_add(egui::Slider::new(&mut params.volume, 0. ..=100.));
+                            });
+                            form.row("Notifications", |tui| {
+                                tui.ui_add(egui::Checkbox::without_text(&mut params.notifications));
+                            });
+                        },
+                    );
+                });
+        });
+}
+
+fn image_demo(ctx: &egui::Context, state: &mut State) {
+    let texture = state
+        .image_texture
+        .get_or_insert_with(|| {
+            ctx.load_texture(
+                "demo image",
+                egui::ColorImage::example(),
+                Default::default(),
+            )
+        })
+        .clone();
+
+    egui::Window::new("Image demo")
+        .open(&mut state.show_image_demo)
+        .show(ctx, |ui| {
+            ui.label("Resize window to see images scale down to fit their cells");
+
+            tui(ui, ui.id().with("image demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    align_items: Some(taffy::AlignItems::Start),
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for _ in 0..3 {
+                        // Images are never scaled above their original size
+                        tui.ui_add(egui::Image::new(&texture));
+                    }
+                });
+        });
+}
+
+fn collapsing_demo(ctx: &egui::Context, state: &mut State) {
+    let build_count = &mut state.collapsing_build_count;
+    egui::Window::new("Collapsing demo")
+        .open(&mut state.show_collapsing_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("collapsing demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    min_size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    TuiCollapsing::new("Simple section")
+                        .default_open(true)
+                        .show(tui.id("simple"), |tui| {
+                            tui.label("Collapsed body is laid out, but hidden");
+                        });
+
+                    // Body closure is not executed while section is collapsed
+                    TuiCollapsing::new("Expensive section (lazy)")
+                        .lazy(true)
+                        .show(tui.id("lazy"), |tui| {
+                            *build_count += 1;
+                            tui.style(Style {
+                                flex_wrap: taffy::FlexWrap::Wrap,
+                                gap: length(4.),
+                                ..Default::default()
+                            })
+                            .add(|tui| {
+                                for idx in 0..500 {
+                                    tui.label(format!("{idx}"));
+                                }
+                            });
+                        });
+
+                    tui.label(format!(
+                        "Expensive section body was built {} times",
+                        build_count
+                    ));
+                });
+        });
+}
+
+fn terminal_demo(ctx: &egui::Context, state: &mut State) {
+    const COLS: u16 = 40;
+    const ROWS: u16 = 12;
+
+    egui::Window::new("Terminal demo")
+        .open(&mut state.show_terminal_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("terminal demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Start),
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.label(format!("Fixed {COLS}x{ROWS} character grid"));
+
+                    // Text area is sized to exact number of monospace character cells
+                    tui.style(Style {
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Hidden,
+                            y: taffy::Overflow::Hidden,
+                        },
+                        padding: length(4.),
+                        ..Default::default()
+                    })
+                    .monospace_grid_size(COLS, ROWS)
+                    .add_with_background_color(|tui| {
+                        let mut text = String::new();
+                        for row in 0..ROWS {
+                            if row > 0 {
+                                text.push('\n');
+                            }
+                            let line = format!("{row:>2}| {}", "0123456789".repeat(4));
+                            text.extend(line.chars().take(COLS as usize));
+                        }
+                        tui.label(egui::RichText::new(text).monospace());
+                    });
+                });
+        });
+}
+
+fn pixel_rounding_demo(ctx: &egui::Context, state: &mut State) {
+    let pixel_rounding = &mut state.pixel_rounding;
+
+    egui::Window::new("Pixel rounding demo")
+        .open(&mut state.show_pixel_rounding_demo)
+        .show(ctx, |ui| {
+            ui.checkbox(pixel_rounding, "Round layout to whole points");
+            ui.label("Resize window to see gaps between cells when rounding is enabled");
+
+            tui(ui, ui.id().with("pixel rounding demo"))
+                .reserve_available_width()
+                // Disable rounding for seamless cell borders
+                .pixel_rounding(*pixel_rounding)
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![fr(1.); 7],
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for idx in 0..28 {
+                        let fill = match (idx + idx / 7) % 2 {
+                            0 => egui::Color32::from_rgb(40, 60, 90),
+                            _ => egui::Color32::from_rgb(90, 60, 40),
+                        };
+
+                        tui.style(Style {
+                            padding: length(4.),
+                            justify_content: Some(taffy::AlignContent::Center),
+                            ..Default::default()
+                        })
+                        .add_with_background_ui(
+                            |ui, container| {
+                                ui.painter()
+                                    .rect_filled(container.full_container(), 0., fill);
+                            },
+                            |tui, _| {
+                                tui.label(format!("{idx}"));
+                            },
+                        );
+                    }
+                });
+        });
+}
+
+fn baseline_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Baseline demo")
+        .open(&mut state.show_baseline_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("baseline demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![auto(), auto(), auto(), fr(1.)],
+                    // Text in every grid row is aligned on the first line baseline
+                    align_items: Some(taffy::AlignItems::Baseline),
+                    gap: length(8.),
+                    padding: length(8.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (title, price, note) in [
+                        ("Apples", "1.20", "Fresh\nfrom local farm"),
+                        ("Pears", "2.45", "Imported"),
+                    ] {
+                        tui.heading(title);
+                        tui.label(price);
+                        tui.small("EUR/kg");
+                        tui.style(Style {
+                            padding: length(4.),
+                            ..Default::default()
+                        })
+                        .label(note);
+                    }
+                });
+        });
+}
+
+fn scroll_spy_demo(ctx: &egui::Context, state: &mut State) {
+    const SECTIONS: [&str; 6] = [
+        "Introduction",
+        "Installation",
+        "Layout",
+        "Widgets",
+        "Styling",
+        "FAQ",
+    ];
+
+    let section_id = |idx: usize| egui::Id::new(("scroll spy section", idx));
+
+    egui::Window::new("Scroll spy demo")
+        .open(&mut state.show_scroll_spy_demo)
+        .default_size([400., 300.])
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("scroll spy demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let scroll_id = egui::Id::new("scroll spy content");
+
+                    // Section at the top of scroll container in the last frame
+                    let active = tui.topmost_visible_child(scroll_id);
+
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Stretch),
+                        flex_shrink: 0.,
+                        gap: length(2.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        for (idx, title) in SECTIONS.iter().enumerate() {
+                            let selected = active == Some(section_id(idx));
+                            let _ = tui
+                                .style(Style {
+                                    padding: length(4.),
+                                    ..Default::default()
+                                })
+                                .selectable(selected, |tui| {
+                                    tui.label(*title);
+                                });
+                        }
+                    });
+
+                    tui.id(TuiId::Unique(scroll_id))
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            align_items: Some(taffy::AlignItems::Stretch),
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Visible,
+                                y: taffy::Overflow::Scroll,
+                            },
+                            flex_grow: 1.,
+                            max_size: percent(1.),
+                            gap: length(8.),
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            for (idx, title) in SECTIONS.iter().enumerate() {
+                                tui.id(TuiId::Unique(section_id(idx)))
+                                    .style(Style {
+                                        flex_direction: taffy::FlexDirection::Column,
+                                        flex_shrink: 0.,
+                                        gap: length(4.),
+                                        padding: length(4.),
+                                        ..Default::default()
+                                    })
+                                    .add_with_border(|tui| {
+                                        tui.heading(*title);
+                                        for line in 0..8 {
+                                            tui.label(format!("{title} paragraph {line}"));
+                                        }
+                                    });
+                            }
+                        });
+                });
+        });
+}
+
+fn text_scale_demo(ctx: &egui::Context, state: &mut State) {
+    let zoom = state.preview_zoom.get_or_insert(1.5);
+
+    egui::Window::new("Text scale demo")
+        .open(&mut state.show_text_scale_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("text scale demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Start),
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.ui(|ui| {
+                        ui.add(egui::Slider::new(zoom, 0.5..=3.).text("Preview zoom"));
+                    });
+
+                    tui.style(Style {
+                        padding: length(8.),
+                        ..Default::default()
+                    })
+                    .add_with_border(|tui| {
+                        // Only text inside preview region is scaled, layout grows with text
+                        tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            gap: length(4.),
+                            ..Default::default()
+                        })
+                        .text_scale(*zoom, |tui| {
+                            tui.heading("Preview");
+                            tui.label("Text in this region is scaled");
+                            tui.small("Small text is scaled too");
+                            tui.ui(|ui| {
+                                let _ = ui.button("Scaled button");
+                            });
+                        });
+                    });
+
+                    tui.label("Text outside preview keeps its size");
+                });
+        });
+}
+
+fn chat_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Chat demo")
+        .open(&mut state.show_chat_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("chat demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    gap: length(8.),
+                    padding: length(8.),
+                    min_size: taffy::Size {
+                        width: length(300.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let mut sent = false;
+
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Row,
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        tui.style(Style {
+                            flex_grow: 1.,
+                            ..Default::default()
+                        })
+                        .ui_add(egui::TextEdit::singleline(&mut state.chat_input));
+
+                        if tui.ui_add(egui::Button::new("Send")).clicked()
+                            && !state.chat_input.is_empty()
+                        {
+                            state
+                                .chat_messages
+                                .push(std::mem::take(&mut state.chat_input));
+                            sent = true;
+                        }
+                    });
+
+                    if state.chat_messages.is_empty() {
+                        tui.label("No messages yet");
+                        return;
+                    }
+
+                    // Chat log takes only the space it needs and scrolls after reaching max height
+                    tui.style(Style {
+                        max_size: taffy::Size {
+                            width: auto(),
+                            height: length(200.),
+                        },
+                        ..Default::default()
+                    })
+                    .add_with_border(|tui| {
+                        tui.ui_scroll_area(|ui| {
+                            for message in &state.chat_messages {
+                                ui.label(message);
+                            }
+                            if sent {
+                                ui.scroll_to_cursor(Some(egui::Align::BOTTOM));
+                            }
+                        });
+                    });
+                });
+        });
+}
+
+fn highlight_demo(ctx: &egui::Context, state: &mut State) {
+    const STEPS: [(&str, &str); 3] = [
+        ("Open", "Open existing document"),
+        ("Save", "Save changes"),
+        ("Share", "Share document with others"),
+    ];
+
+    egui::Window::new("Highlight demo")
+        .open(&mut state.show_highlight_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("highlight demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(16.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let step_id = |idx: usize| egui::Id::new(("highlight demo step", idx));
+
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Row,
+                        gap: length(8.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        for (idx, (label, _)) in STEPS.iter().enumerate() {
+                            tui.id(TuiId::Unique(step_id(idx)))
+                                .ui_add(egui::Button::new(*label));
+                        }
+                    });
+
+                    let (_, hint) = STEPS[state.highlight_step];
+                    tui.label(format!("Step {}: {hint}", state.highlight_step + 1));
+                    if tui.ui_add(egui::Button::new("Next step")).clicked() {
+                        state.highlight_step = (state.highlight_step + 1) % STEPS.len();
+                    }
+
+                    // Draw highlight above all windows around final button rect
+                    if let Some(rect) = tui.node_rect(step_id(state.highlight_step)) {
+                        let painter = tui.egui_ctx().layer_painter(egui::LayerId::new(
+                            egui::Order::Foreground,
+                            egui::Id::new("highlight demo overlay"),
+                        ));
+                        painter.rect_stroke(
+                            rect.expand(3.),
+                            4.,
+                            egui::Stroke::new(2., egui::Color32::ORANGE),
+                            egui::StrokeKind::Outside,
+                        );
+                    }
+                });
+        });
+}
+
+fn hover_card_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Hover card demo")
+        .open(&mut state.show_hover_card_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("hover card demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for title in ["First card", "Second card", "Third card"] {
+                        let _ = tui
+                            .style(Style {
+                                flex_direction: taffy::FlexDirection::Column,
+                                gap: length(4.),
+                                ..Default::default()
+                            })
+                            // Card grows slightly while it is hovered
+                            .mut_style_with_interaction(|style, response| {
+                                let hovered = response.is_some_and(|response| response.hovered());
+                                style.padding = length(if hovered { 12. } else { 8. });
+                            })
+                            .clickable(|tui| {
+                                tui.strong(title);
+                                tui.label("Hover me");
+                            });
+                    }
+                });
+        });
+}
+
+fn sidebar_demo(ctx: &egui::Context, state: &mut State) {
+    const PAGES: [&str; 4] = ["Inbox", "Starred", "Sent", "All conversations"];
+
+    egui::Window::new("Sidebar demo")
+        .open(&mut state.show_sidebar_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("sidebar demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    gap: length(16.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    // Selectable items stretch to the width of the widest item
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Stretch),
+                        gap: length(2.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        for (idx, page) in PAGES.iter().enumerate() {
+                            let selected = state.sidebar_selected == idx;
+                            if tui
+                                .ui_add(TaffySelectableLabel::new(selected, *page))
+                                .clicked()
+                            {
+                                state.sidebar_selected = idx;
+                            }
+                        }
+                    });
+
+                    tui.separator();
+
+                    tui.heading(PAGES[state.sidebar_selected]);
+                });
+        });
+}
+
+fn touch_scroll_demo(ctx: &egui::Context, state: &mut State) {
+    if state.touch_scroll_values.is_empty() {
+        state.touch_scroll_values = (0..50).map(|idx| idx as f32).collect();
+    }
+
+    egui::Window::new("Touch scroll demo")
+        .open(&mut state.show_touch_scroll_demo)
+        .default_size([300., 400.])
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("touch scroll demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(8.),
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.ui(|ui| {
+                        ui.checkbox(&mut state.touch_scroll_disabled, "Disable drag to scroll");
+                    });
+
+                    // Drag list content to scroll, drag values inside items still can be dragged
+                    tui.drag_to_scroll(!state.touch_scroll_disabled)
+                        .mut_egui_style(|style| style.interaction.selectable_labels = false)
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            align_items: Some(taffy::AlignItems::Stretch),
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Visible,
+                                y: taffy::Overflow::Scroll,
+                            },
+                            flex_grow: 1.,
+                            max_size: percent(1.),
+                            gap: length(4.),
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            for (idx, value) in state.touch_scroll_values.iter_mut().enumerate() {
+                                tui.style(Style {
+                                    flex_direction: taffy::FlexDirection::Row,
+                                    justify_content: Some(taffy::JustifyContent::SpaceBetween),
+                                    flex_shrink: 0.,
+                                    padding: length(8.),
+                                    ..Default::default()
+                                })
+                                .add_with_border(|tui| {
+                                    tui.label(format!("Item {idx}"));
+                                    tui.ui_add(egui::DragValue::new(value));
+                                });
+                            }
+                        });
+                });
+        });
+}
+
+/// Image loader that rasterizes procedural ring icon at requested size
+///
+/// Stands in for SVG loader (e.g. `egui_extras::install_image_loaders` with `svg` feature),
+/// which rasterizes SVG files at requested size in the same way.
+struct RingIconLoader;
+
+impl RingIconLoader {
+    const URI: &'static str = "ring://icon";
+}
+
+impl egui::load::ImageLoader for RingIconLoader {
+    fn id(&self) -> &str {
+        "demo::RingIconLoader"
+    }
+
+    fn load(
+        &self,
+        _ctx: &egui::Context,
+        uri: &str,
+        size_hint: egui::SizeHint,
+    ) -> egui::load::ImageLoadResult {
+        if uri != Self::URI {
+            return Err(egui::load::LoadError::NotSupported);
+        }
+
+        let size = match size_hint {
+            egui::SizeHint::Size(width, height) => width.min(height),
+            egui::SizeHint::Width(width) => width,
+            egui::SizeHint::Height(height) => height,
+            egui::SizeHint::Scale(scale) => (24. * scale.into_inner()) as u32,
+        }
+        .max(1) as usize;
+
+        // Anti-aliased ring, edges stay one pixel wide at any size
+        let center = size as f32 / 2.;
+        let (outer, inner) = (center, center * 0.6);
+        let pixels = (0..size * size)
+            .map(|idx| {
+                let x = (idx % size) as f32 + 0.5 - center;
+                let y = (idx / size) as f32 + 0.5 - center;
+                let distance = x.hypot(y);
+                let coverage =
+                    (outer - distance + 0.5).clamp(0., 1.) * (distance - inner + 0.5).clamp(0., 1.);
+                egui::Color32::from_white_alpha((coverage * 255.) as u8)
+            })
+            .collect();
+
+        Ok(egui::load::ImagePoll::Ready {
+            image: std::sync::Arc::new(egui::ColorImage {
+                size: [size, size],
+                pixels,
+            }),
+        })
+    }
+
+    fn forget(&self, _uri: &str) {}
+
+    fn forget_all(&self) {}
+
+    fn byte_size(&self) -> usize {
+        0
+    }
+}
+
+fn vector_image_demo(ctx: &egui::Context, state: &mut State) {
+    let loader = RingIconLoader;
+    if !ctx.is_loader_installed(egui::load::ImageLoader::id(&loader)) {
+        ctx.add_image_loader(std::sync::Arc::new(loader));
+    }
+
+    let icon_size = state.vector_icon_size.get_or_insert(64.);
+
+    egui::Window::new("Vector image demo")
+        .open(&mut state.show_vector_image_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("vector image demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Start),
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.ui(|ui| {
+                        ui.add(egui::Slider::new(icon_size, 8.0..=256.).text("Icon size"));
+                    });
+
+                    tui.label("Vector image is rasterized again after size changes by 10%");
+                    tui.style(Style {
+                        size: length(*icon_size),
+                        ..Default::default()
+                    })
+                    .ui_add(TaffyImage::new(egui::Image::new(RingIconLoader::URI)).vector(true));
+
+                    tui.label("Same icon in nodes of different sizes");
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Row,
+                        align_items: Some(taffy::AlignItems::End),
+                        gap: length(8.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        for size in [16., 32., 64., 128.] {
+                            tui.style(Style {
+                                size: length(size),
+                                ..Default::default()
+                            })
+                            .ui_add(
+                                TaffyImage::new(egui::Image::new(RingIconLoader::URI)).vector(true),
+                            );
+                        }
+                    });
+                });
+        });
+}
+
+fn spreadsheet_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Spreadsheet demo")
+        .open(&mut state.show_spreadsheet_demo)
+        .default_size([400., 300.])
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("spreadsheet demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let stroke = tui.egui_ui().visuals().widgets.noninteractive.bg_stroke;
+
+                    // Gap matches stroke width, lines are painted in gutters between cells
+                    tui.style(Style {
+                        grid_template_columns: vec![min_content(), repeat(6, vec![length(60.)])],
+                        gap: length(stroke.width),
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Scroll,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        flex_grow: 1.,
+                        max_size: percent(1.),
+                        ..Default::default()
+                    })
+                    .add_with_grid_lines(stroke, |tui| {
+                        let cell = || Style {
+                            padding: length(4.),
+                            ..Default::default()
+                        };
+
+                        tui.style(cell()).add_empty();
+                        for column in 'A'..='F' {
+                            tui.style(cell()).strong(column.to_string());
+                        }
+
+                        for row in 1..=50 {
+                            tui.style(cell()).strong(row.to_string());
+                            for column in 'A'..='F' {
+                                tui.style(cell()).label(format!("{column}{row}"));
+                            }
+                        }
+                    });
+                });
+        });
+}
+
+fn sticky_headers_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Sticky headers demo")
+        .open(&mut state.show_sticky_headers_demo)
+        .default_size([250., 300.])
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("sticky headers demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let sections = ["Favorites", "Recent", "Contacts", "Groups", "Archive"];
+                    let items_per_section = 8;
+
+                    tui.style(Style {
+                        display: taffy::Display::Grid,
+                        grid_template_columns: vec![fr(1.)],
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        flex_grow: 1.,
+                        max_size: percent(1.),
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        let section_row =
+                            |section: usize| (section * (items_per_section + 1) + 1) as i16;
+
+                        for (section, name) in sections.iter().enumerate() {
+                            for item in 0..items_per_section {
+                                tui.style(Style {
+                                    grid_row: style_helpers::line(
+                                        section_row(section) + 1 + item as i16,
+                                    ),
+                                    padding: length(4.),
+                                    ..Default::default()
+                                })
+                                .label(format!("{name} item {item}"));
+                            }
+                        }
+
+                        // Headers are added after items to be painted above scrolled items
+                        for (section, name) in sections.iter().enumerate() {
+                            tui.sticky_header()
+                                .style(Style {
+                                    grid_row: style_helpers::line(section_row(section)),
+                                    padding: length(4.),
+                                    ..Default::default()
+                                })
+                                .add_with_background(|tui| {
+                                    tui.strong(*name);
+                                });
+                        }
+                    });
+                });
+        });
+}
+
+fn responsive_toolbar_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Responsive toolbar demo")
+        .open(&mut state.show_responsive_toolbar_demo)
+        .default_width(400.)
+        .show(ctx, |ui| {
+            ui.label("Resize window to see toolbar buttons drop their labels when narrow");
+
+            tui(ui, ui.id().with("responsive toolbar demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(4.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (icon, label) in [
+                        ("💾", "Save"),
+                        ("📂", "Open"),
+                        ("✂", "Cut"),
+                        ("🗑", "Delete"),
+                    ] {
+                        tui.style(Style {
+                            flex_shrink: 1.,
+                            ..Default::default()
+                        })
+                        .ui_responsive(|ui, allotted_width| {
+                            // Show label only if taffy allotted enough space for it
+                            let text = if allotted_width < 70. {
+                                icon.to_string()
+                            } else {
+                                format!("{icon} {label}")
+                            };
+                            ui.add(egui::Button::new(text).wrap_mode(egui::TextWrapMode::Extend))
+                                .on_hover_text(label)
+                        });
+                    }
+                });
+        });
+}
+
+fn footer_demo(ctx: &egui::Context, state: &mut State) {
+    let item_count = state.footer_demo_items.get_or_insert(3);
+
+    egui::Window::new("Footer demo")
+        .open(&mut state.show_footer_demo)
+        .default_size([300., 300.])
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(item_count, 0..=30).text("Navigation items"));
+
+            tui(ui, ui.id().with("footer demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    // Full height sidebar, scrolls when items do not fit
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Stretch),
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        flex_grow: 1.,
+                        max_size: percent(1.),
+                        padding: length(4.),
+                        gap: length(2.),
+                        ..Default::default()
+                    })
+                    .add_with_border(|tui| {
+                        for idx in 0..*item_count {
+                            tui.style(Style {
+                                flex_shrink: 0.,
+                                ..Default::default()
+                            })
+                            .label(format!("Navigation item {idx}"));
+                        }
+
+                        // Footer stays at the bottom when there are few items
+                        tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            flex_shrink: 0.,
+                            padding: length(4.),
+                            ..Default::default()
+                        })
+                        .add_footer(|tui| {
+                            tui.separator();
+                            tui.strong("Settings");
+                            tui.label("Version 1.0");
+                        });
+                    });
+                });
+        });
+}
+
+fn overflow_indicator_demo(ctx: &egui::Context, state: &mut State) {
+    let expanded = &mut state.overflow_indicator_expanded;
+
+    egui::Window::new("Overflow indicator demo")
+        .open(&mut state.show_overflow_indicator_demo)
+        .default_width(300.)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("overflow indicator demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(4.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let text = "Egui taffy lays out egui widgets using flexbox, grid and block \
+                        layout algorithms. This text box is clamped to three lines, \
+                        resize window to see indicator appear and disappear depending on \
+                        whether text fits in the box.";
+
+                    let max_height = match *expanded {
+                        true => auto(),
+                        false => {
+                            length(3. * tui.egui_ui().text_style_height(&egui::TextStyle::Body))
+                        }
+                    };
+
+                    let id = tui
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Visible,
+                                y: taffy::Overflow::Clip,
+                            },
+                            max_size: taffy::Size {
+                                width: auto(),
+                                height: max_height,
+                            },
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            tui.label(text);
+                            tui.current_id()
+                        });
+
+                    // Overflow is known from the last layout pass
+                    let overflow = tui.did_overflow(id).y;
+                    if overflow {
+                        if let Some(rect) = tui.node_rect(id) {
+                            paint_fade_out(tui.egui_ui(), rect);
+                        }
+                    }
+
+                    if overflow || *expanded {
+                        let label = if *expanded {
+                            "Show less"
+                        } else {
+                            "… Show more"
+                        };
+                        if tui.ui(|ui| ui.link(label)).clicked() {
+                            *expanded = !*expanded;
+                        }
+                    }
+                });
+        });
+}
+
+/// Paint gradient that fades out bottom of clamped content to panel background
+fn paint_fade_out(ui: &egui::Ui, rect: egui::Rect) {
+    let fill = ui.visuals().window_fill;
+    let fade = egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.max.y - 16.), rect.max);
+
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(fade.left_top(), egui::Color32::TRANSPARENT);
+    mesh.colored_vertex(fade.right_top(), egui::Color32::TRANSPARENT);
+    mesh.colored_vertex(fade.left_bottom(), fill);
+    mesh.colored_vertex(fade.right_bottom(), fill);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    ui.painter().add(mesh);
+}
+
+fn sortable_list_demo(ctx: &egui::Context, state: &mut State) {
+    if state.sortable_items.is_empty() {
+        state.sortable_items = vec!["Apple", "Banana", "Cherry", "Date", "Elderberry", "Fig"];
+    }
+    let items = &mut state.sortable_items;
+
+    egui::Window::new("Sortable list demo")
+        .open(&mut state.show_sortable_list_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("sortable list demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    gap: length(4.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        if tui.button(|tui| tui.label("Reverse")).clicked() {
+                            items.reverse();
+                        }
+                        if tui.button(|tui| tui.label("Rotate")).clicked() {
+                            items.rotate_left(1);
+                        }
+                        if tui.button(|tui| tui.label("Sort")).clicked() {
+                            items.sort();
+                        }
+                    });
+
+                    // Items keep their ids when reordered and slide to their new positions
+                    let mut swap = None;
+                    let count = items.len();
+                    tui.keyed_children(
+                        |(_, item)| **item,
+                        items.iter().enumerate(),
+                        |tui, (idx, item)| {
+                            tui.animate_position(true)
+                                .style(Style {
+                                    justify_content: Some(taffy::JustifyContent::SpaceBetween),
+                                    align_items: Some(taffy::AlignItems::Center),
+                                    padding: length(4.),
+                                    gap: length(4.),
+                                    ..Default::default()
+                                })
+                                .add_with_background(|tui| {
+                                    tui.label(*item);
+                                    tui.add(|tui| {
+                                        if tui
+                                            .enabled_ui(idx > 0)
+                                            .button(|tui| tui.label("⬆"))
+                                            .clicked()
+                                        {
+                                            swap = Some((idx, idx - 1));
+                                        }
+                                        if tui
+                                            .enabled_ui(idx + 1 < count)
+                                            .button(|tui| tui.label("⬇"))
+                                            .clicked()
+                                        {
+                                            swap = Some((idx, idx + 1));
+                                        }
+                                    });
+                                });
+                        },
+                    );
+                    if let Some((a, b)) = swap {
+                        items.swap(a, b);
+                    }
+                });
+        });
+}
+
+fn auto_size_window_demo(ctx: &egui::Context, state: &mut State) {
+    let details = &mut state.auto_size_window_details;
+
+    egui::Window::new("Auto size window demo")
+        .open(&mut state.show_auto_size_window_demo)
+        .resizable(false)
+        .show(ctx, |ui| {
+            // Window follows content size, it shrinks when details are hidden
+            tui(ui, ui.id().with("auto size window demo"))
+                .fit_content()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.ui_add(egui::Checkbox::new(details, "Show details"));
+
+                    if *details {
+                        tui.style(Style {
+                            display: taffy::Display::Grid,
+                            grid_template_columns: vec![auto(), auto()],
+                            gap: length(8.),
+                            ..Default::default()
+                        })
+                        .add_with_border(|tui| {
+                            for (key, value) in [
+                                ("Name", "egui_taffy"),
+                                ("Layout", "Flexbox, grid and block layouts using taffy"),
+                                ("Window", "Sized to content of this tui"),
+                            ] {
+                                tui.strong(key);
+                                tui.label(value);
+                            }
+                        });
+                    }
+                });
+        });
+}
+
+fn rubber_band_demo(ctx: &egui::Context, state: &mut State) {
+    let selected = &mut state.rubber_band_selected;
+
+    egui::Window::new("Rubber band selection demo")
+        .open(&mut state.show_rubber_band_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("rubber band demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.label(format!(
+                        "Drag over cells to select them ({} selected)",
+                        selected.len()
+                    ));
+
+                    tui.style(Style {
+                        display: taffy::Display::Grid,
+                        grid_template_columns: vec![length(48.); 6],
+                        grid_auto_rows: vec![length(32.)],
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        // Sense drag before adding cells, selection is updated while dragging
+                        if let Some((_rect, ids)) = tui.rubber_band_selection() {
+                            *selected = ids;
+                        }
+
+                        for row in 0..5 {
+                            for column in 0..6 {
+                                let id = egui::Id::new(("rubber band demo cell", row, column));
+                                let fill = if selected.contains(&id) {
+                                    tui.egui_ui().visuals().selection.bg_fill
+                                } else {
+                                    tui.egui_ui().visuals().faint_bg_color
+                                };
+
+                                tui.id(TuiId::Unique(id))
+                                    .style(Style {
+                                        justify_content: Some(taffy::AlignContent::Center),
+                                        align_items: Some(taffy::AlignItems::Center),
+                                        ..Default::default()
+                                    })
+                                    .add_with_background_ui(
+                                        |ui, container| {
+                                            ui.painter().rect_filled(
+                                                container.full_container(),
+                                                2.,
+                                                fill,
+                                            );
+                                        },
+                                        |tui, _| {
+                                            // Non selectable labels do not capture drag
+                                            tui.ui_add(
+                                                egui::Label::new(format!("{row}:{column}"))
+                                                    .selectable(false),
+                                            );
+                                        },
+                                    );
+                            }
+                        }
+                    });
+                });
+        });
+}
+
+fn tag_filter_demo(ctx: &egui::Context, state: &mut State) {
+    const CRATES: &[(&str, &[&str])] = &[
+        ("egui", &["gui", "immediate"]),
+        ("egui_taffy", &["gui", "layout", "flexbox", "grid"]),
+        ("taffy", &["layout", "flexbox", "grid"]),
+        ("serde", &["serialization"]),
+        ("iced", &["gui", "retained"]),
+        ("morphorm", &["layout"]),
+    ];
+
+    let tags = &mut state.tag_filter;
+    let text = &mut state.tag_filter_text;
+
+    egui::Window::new("Tag filter demo")
+        .open(&mut state.show_tag_filter_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("tag filter demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.label("Type tag and press enter, backspace removes last tag");
+
+                    let response = TuiChipInput::new(text)
+                        .hint_text("Filter by tag")
+                        .show(tui.id("tag input"), tags.iter().map(String::as_str));
+                    for event in response.inner {
+                        match event {
+                            ChipInputEvent::Add(tag) => {
+                                if !tags.contains(&tag) {
+                                    tags.push(tag);
+                                }
+                            }
+                            ChipInputEvent::Remove(idx) => {
+                                tags.remove(idx);
+                            }
+                        }
+                    }
+
+                    tui.separator();
+
+                    for (name, crate_tags) in CRATES {
+                        if tags.iter().all(|tag| crate_tags.contains(&tag.as_str())) {
+                            tui.label(format!("{name} ({})", crate_tags.join(", ")));
+                        }
+                    }
+                });
+        });
+}
+
+fn nested_sticky_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Nested sticky demo")
+        .scroll(Vec2b::FALSE)
+        .default_size([400., 300.])
+        .open(&mut state.show_nested_sticky_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("nested sticky demo"))
+                .reserve_available_space()
+                .style(Style {
+                    size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let vertical_scroll = egui::Id::new("nested sticky demo vertical");
+                    let horizontal_scroll = egui::Id::new("nested sticky demo horizontal");
+
+                    let cell_style = Style {
+                        padding: length(8.),
+                        ..Default::default()
+                    };
+                    let columns = 12i16;
+                    let rows = 30i16;
+
+                    // Outer node scrolls only vertically
+                    tui.id(TuiId::Unique(vertical_scroll))
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Visible,
+                                y: taffy::Overflow::Scroll,
+                            },
+                            size: percent(1.),
+                            max_size: percent(1.),
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            // Inner node scrolls only horizontally and has full content height
+                            tui.id(TuiId::Unique(horizontal_scroll))
+                                .style(Style {
+                                    overflow: taffy::Point {
+                                        x: taffy::Overflow::Scroll,
+                                        y: taffy::Overflow::Visible,
+                                    },
+                                    flex_shrink: 0.,
+                                    ..Default::default()
+                                })
+                                .add(|tui| {
+                                    tui.style(Style {
+                                        display: taffy::Display::Grid,
+                                        grid_template_columns: vec![auto(); (columns + 1) as usize],
+                                        align_items: Some(taffy::AlignItems::Stretch),
+                                        justify_items: Some(taffy::AlignItems::Stretch),
+                                        ..Default::default()
+                                    })
+                                    .add(|tui| {
+                                        for i in 1..=rows {
+                                            for j in 1..=columns {
+                                                tui.style(Style {
+                                                    grid_column: style_helpers::line(j + 1),
+                                                    grid_row: style_helpers::line(i + 1),
+                                                    ..cell_style.clone()
+                                                })
+                                                .add_with_border(|tui| {
+                                                    tui.label(format!("Cell {i} {j}"));
+                                                });
+                                            }
+                                        }
+
+                                        // Headers are added after cells to be painted above them
+                                        let header = |column: i16, row: i16| Style {
+                                            grid_column: style_helpers::line(column),
+                                            grid_row: style_helpers::line(row),
+                                            ..cell_style.clone()
+                                        };
+
+                                        // Column headers stick to the top of outer viewport
+                                        for j in 1..=columns {
+                                            tui.sticky_to(None, Some(vertical_scroll))
+                                                .style(header(j + 1, 1))
+                                                .add_with_background(|tui| {
+                                                    tui.strong(format!("Column {j}"));
+                                                });
+                                        }
+
+                                        // Row headers stick to the left of inner viewport
+                                        for i in 1..=rows {
+                                            tui.sticky_to(Some(horizontal_scroll), None)
+                                                .style(header(1, i + 1))
+                                                .add_with_background(|tui| {
+                                                    tui.strong(format!("Row {i}"));
+                                                });
+                                        }
+
+                                        // Corner cell is sticky to both scroll nodes
+                                        tui.sticky_to(
+                                            Some(horizontal_scroll),
+                                            Some(vertical_scroll),
+                                        )
+                                        .style(header(1, 1))
+                                        .add_with_background(|tui| {
+                                            tui.strong("Corner");
+                                        });
+                                    });
+                                });
+                        });
+                });
+        });
+}
+
+fn checkout_wizard_demo(ctx: &egui::Context, state: &mut State) {
+    const STEPS: [&str; 4] = ["Cart", "Shipping", "Payment", "Confirm"];
+
+    let step = &mut state.checkout_step;
+
+    egui::Window::new("Checkout wizard demo")
+        .default_width(500.)
+        .open(&mut state.show_checkout_wizard_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("checkout wizard demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    if let Some(clicked) = TuiStepper::new(STEPS, *step).show(tui.id("stepper")) {
+                        // Allow to go back to already completed steps only
+                        if clicked < *step {
+                            *step = clicked;
+                        }
+                    }
+
+                    tui.separator();
+
+                    match STEPS.get(*step) {
+                        Some(&"Cart") => {
+                            tui.label("2 items in the cart");
+                        }
+                        Some(&"Shipping") => {
+                            tui.label("Enter shipping address");
+                        }
+                        Some(&"Payment") => {
+                            tui.label("Choose payment method");
+                        }
+                        _ => {
+                            tui.label("Review and confirm your order");
+                        }
+                    }
+
+                    tui.style(Style {
+                        justify_content: Some(taffy::AlignContent::SpaceBetween),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        let back_enabled = *step > 0;
+                        if tui
+                            .ui(|ui| ui.add_enabled(back_enabled, egui::Button::new("Back")))
+                            .clicked()
+                        {
+                            *step -= 1;
+                        }
+
+                        let next = match *step + 1 < STEPS.len() {
+                            true => "Next",
+                            false => "Start over",
+                        };
+                        if tui.ui_add(egui::Button::new(next)).clicked() {
+                            *step = (*step + 1) % STEPS.len();
+                        }
+                    });
+                });
+        });
+}
+
+fn file_tree_demo(ctx: &egui::Context, state: &mut State) {
+    enum Entry {
+        File(&'static str),
+        Dir(&'static str, Vec<Entry>),
+    }
+
+    fn show_entries(tui: &mut Tui, entries: &[Entry]) {
+        for entry in entries {
+            match entry {
+                Entry::File(name) => {
+                    tui.label(format!("🗋 {name}"));
+                }
+                Entry::Dir(name, children) => {
+                    TuiCollapsing::new(format!("🗀 {name}")).show(tui.id(*name), |tui| {
+                        tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            padding: taffy::Rect {
+                                left: length(16.),
+                                right: length(0.),
+                                top: length(0.),
+                                bottom: length(0.),
+                            },
+                            ..Default::default()
+                        })
+                        .add(|tui| show_entries(tui, children));
+                    });
+                }
+            }
+        }
+    }
+
+    let tree = [
+        Entry::Dir(
+            "src",
+            vec![
+                Entry::File("lib.rs"),
+                Entry::File("widgets.rs"),
+                Entry::Dir("bin", vec![Entry::File("main.rs")]),
+            ],
+        ),
+        Entry::Dir(
+            "examples",
+            vec![Entry::File("demo.rs"), Entry::File("virtual_grid.rs")],
+        ),
+        Entry::Dir("assets", vec![Entry::File("icon.png")]),
+        Entry::File("Cargo.toml"),
+        Entry::File("README.md"),
+    ];
+
+    egui::Window::new("File tree demo")
+        .open(&mut state.show_file_tree_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("file tree demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(4.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let ctx = tui.egui_ui().ctx().clone();
+                    let taffy_id = tui.main_taffy_id();
+
+                    tui.style(Style {
+                        gap: length(8.),
+                        align_items: Some(taffy::AlignItems::Center),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        if tui.button(|tui| tui.label("Expand all")).response.clicked() {
+                            TuiCollapsing::set_all_open(&ctx, taffy_id, true);
+                        }
+                        if tui
+                            .button(|tui| tui.label("Collapse all"))
+                            .response
+                            .clicked()
+                        {
+                            TuiCollapsing::set_all_open(&ctx, taffy_id, false);
+                        }
+
+                        let states = TuiCollapsing::states(&ctx, taffy_id);
+                        let open = states.iter().filter(|(_, open)| *open).count();
+                        tui.label(format!("{open}/{} folders open", states.len()));
+                    });
+
+                    tui.separator();
+
+                    show_entries(tui, &tree);
+                });
+        });
+}
+
+fn pixel_snap_demo(ctx: &egui::Context, state: &mut State) {
+    let snap = &mut state.pixel_snap;
+
+    egui::Window::new("Pixel snap demo")
+        .open(&mut state.show_pixel_snap_demo)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(snap, "Snap to pixels");
+                ui.separator();
+                ui.label("Zoom:");
+                let zoom = ui.ctx().zoom_factor();
+                for factor in [1., 1.25, 1.5, 1.75] {
+                    if ui
+                        .selectable_label(zoom == factor, format!("{factor}"))
+                        .clicked()
+                    {
+                        ui.ctx().set_zoom_factor(factor);
+                    }
+                }
+            });
+            ui.label("Fractional cell sizes without layout rounding, compare border sharpness.");
+            ui.separator();
+
+            tui(ui, ui.id().with("pixel snap demo"))
+                .reserve_available_width()
+                .pixel_rounding(false)
+                .snap_to_pixels(*snap)
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![length(33.3); 8],
+                    grid_auto_rows: vec![length(20.7)],
+                    gap: length(3.4),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for idx in 0..32 {
+                        tui.add_with_border(|tui| {
+                            tui.small(idx.to_string());
+                        });
+                    }
+                });
+        });
+}
+
+fn tab_bar_demo(ctx: &egui::Context, state: &mut State) {
+    let selected = &mut state.selected_tab;
+
+    egui::Window::new("Tab bar demo")
+        .default_width(400.)
+        .open(&mut state.show_tab_bar_demo)
+        .show(ctx, |ui| {
+            ui.label("Resize window to see arrow buttons of overflowing tab bar.");
+            ui.separator();
+
+            tui(ui, ui.id().with("tab bar demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let tabs = (1..=24).map(|idx| format!("Document {idx}"));
+                    *selected = TuiTabBar::new(tabs, *selected).show(tui.id("tabs"));
+
+                    tui.label(format!("Content of document {}", *selected + 1));
+                });
+        });
+}
+
+fn responsive_dashboard_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Responsive dashboard demo")
+        .default_width(700.)
+        .open(&mut state.show_responsive_dashboard_demo)
+        .show(ctx, |ui| {
+            ui.label("Resize window below 500px width to collapse cards to a single column");
+            ui.separator();
+
+            tui(ui, ui.id().with("responsive dashboard demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        gap: length(8.),
+                        ..Default::default()
+                    })
+                    .add_responsive_grid(500., 3, |tui| {
+                        for (title, value, trend) in [
+                            ("Revenue", "$12 400", "+8%"),
+                            ("Orders", "318", "+3%"),
+                            ("Visitors", "9 120", "-2%"),
+                            ("Conversion", "3.5%", "+0.4%"),
+                            ("Refunds", "7", "-1"),
+                            ("Support tickets", "24", "+5"),
+                        ] {
+                            tui.style(Style {
+                                flex_direction: taffy::FlexDirection::Column,
+                                padding: length(8.),
+                                gap: length(4.),
+                                ..Default::default()
+                            })
+                            .add_with_border(|tui| {
+                                tui.label(title);
+                                tui.heading(value);
+                                tui.small(trend);
+                            });
+                        }
+                    });
+                });
+        });
+}
+
+fn command_palette_demo(ctx: &egui::Context, state: &mut State) {
+    const COMMANDS: [&str; 10] = [
+        "New file",
+        "Open file",
+        "Save",
+        "Save as",
+        "Close editor",
+        "Toggle sidebar",
+        "Toggle terminal",
+        "Go to line",
+        "Find in files",
+        "Reload window",
+    ];
+
+    let query = &mut state.command_palette_query;
+    let last = &mut state.command_palette_last;
+
+    egui::Window::new("Command palette demo")
+        .open(&mut state.show_command_palette_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("command palette demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    gap: length(4.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    // Enter is handled by menu, text field keeps focus
+                    let search = tui.ui_add(
+                        egui::TextEdit::singleline(query)
+                            .hint_text("Type to search commands, use ↑ ↓ and Enter")
+                            .return_key(None),
+                    );
+
+                    let lowercase_query = query.to_lowercase();
+                    let commands: Vec<&str> = COMMANDS
+                        .into_iter()
+                        .filter(|command| command.to_lowercase().contains(&lowercase_query))
+                        .collect();
+
+                    tui.style(Style {
+                        max_size: taffy::Size {
+                            width: auto(),
+                            height: length(150.),
+                        },
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        let chosen = TuiMenu::new(commands.iter().copied())
+                            .keyboard_focus(search.has_focus())
+                            .show(tui.id("commands"))
+                            .inner;
+                        if let Some(idx) = chosen {
+                            *last = Some(commands[idx]);
+                            query.clear();
+                        }
+                    });
+
+                    tui.separator();
+                    tui.label(format!("Last command: {}", last.unwrap_or("-")));
+                });
+        });
+}
+
+fn ripple_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Ripple demo")
+        .open(&mut state.show_ripple_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("ripple demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let _ = tui
+                        .ripple(true)
+                        .style(Style {
+                            padding: length(16.),
+                            justify_content: Some(taffy::AlignContent::Center),
+                            ..Default::default()
+                        })
+                        .button(|tui| tui.label("Press me"));
+
+                    let _ = tui
+                        .ripple(true)
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            padding: length(16.),
+                            ..Default::default()
+                        })
+                        .clickable(|tui| {
+                            tui.strong("Clickable card");
+                            tui.label("Ripple spreads from the press position");
+                        });
+                });
+        });
+}
+
+fn custom_scrollbar_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Custom scrollbar demo")
+        .open(&mut state.show_custom_scrollbar_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("custom scrollbar demo"))
+                .reserve_available_width()
+                .style(Style {
+                    gap: length(4.),
+                    size: taffy::Size {
+                        width: length(250.),
+                        height: length(250.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let scroll_id = egui::Id::new("custom scrollbar demo content");
+
+                    // Scroll node with hidden egui scroll bars
+                    tui.id(TuiId::Unique(scroll_id))
+                        .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden)
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            flex_grow: 1.,
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Hidden,
+                                y: taffy::Overflow::Scroll,
+                            },
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            for idx in 1..=40 {
+                                tui.label(format!("Scrollable line {idx}"));
+                            }
+                        });
+
+                    let overflow = tui.overflow_amount(scroll_id).y;
+                    let offset = tui.scroll_offset(scroll_id).y.clamp(0., overflow);
+
+                    // Custom scroll bar track and thumb matched to taffy content size
+                    let new_offset = tui
+                        .style(Style {
+                            size: taffy::Size {
+                                width: length(10.),
+                                height: auto(),
+                            },
+                            align_self: Some(taffy::AlignItems::Stretch),
+                            ..Default::default()
+                        })
+                        .ui(|ui| {
+                            let track = ui.max_rect();
+                            let response = ui.allocate_rect(track, egui::Sense::drag());
+                            if overflow <= 0. {
+                                return None;
+                            }
+
+                            let visible = track.height();
+                            let thumb_height = (visible * visible / (visible + overflow)).max(20.);
+                            let thumb_range = (track.height() - thumb_height).max(1.);
+                            let thumb = egui::Rect::from_min_size(
+                                track.left_top() + egui::vec2(0., thumb_range * offset / overflow),
+                                egui::vec2(track.width(), thumb_height),
+                            );
+
+                            let visuals = ui.style().interact(&response);
+                            let painter = ui.painter();
+                            painter.rect_filled(track, 5., ui.visuals().extreme_bg_color);
+                            painter.rect_filled(thumb, 5., visuals.fg_stroke.color);
+
+                            (response.dragged() && response.drag_delta().y != 0.).then(|| {
+                                (offset + response.drag_delta().y * overflow / thumb_range)
+                                    .clamp(0., overflow)
+                            })
+                        });
+
+                    if let Some(offset) = new_offset {
+                        tui.set_scroll_offset(scroll_id, egui::vec2(0., offset));
+                    }
+                });
+        });
+}
+
+fn appear_demo(ctx: &egui::Context, state: &mut State) {
+    let cards = &mut state.appear_cards;
+
+    egui::Window::new("Appear animation demo")
+        .open(&mut state.show_appear_demo)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Add card").clicked() {
+                    *cards += 1;
+                }
+                if ui.button("Remove card").clicked() {
+                    *cards = cards.saturating_sub(1);
+                }
+            });
+            ui.separator();
+
+            tui(ui, ui.id().with("appear demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_wrap: taffy::FlexWrap::Wrap,
+                    gap: length(8.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for idx in 0..*cards {
+                        // New cards fade in and slide up once their size is known
+                        tui.id(tid(idx))
+                            .appear_animation(AppearAnimation::Slide(egui::vec2(0., 16.)))
+                            .style(Style {
+                                flex_direction: taffy::FlexDirection::Column,
+                                padding: length(8.),
+                                min_size: taffy::Size {
+                                    width: length(100.),
+                                    height: auto(),
+                                },
+                                ..Default::default()
+                            })
+                            .add_with_background(|tui| {
+                                tui.strong(format!("Card {}", idx + 1));
+                                tui.label("Faded in");
+                            });
+                    }
+                });
+        });
+}
+
+fn resize_events_demo(ctx: &egui::Context, state: &mut State) {
+    let log = &mut state.resize_log;
+
+    egui::Window::new("Resize events demo")
+        .scroll(Vec2b::FALSE)
+        .default_size([400., 300.])
+        .open(&mut state.show_resize_events_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("resize events demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.label("Resize window, panel size changes are logged below");
+
+                    let panel_id = egui::Id::new("resize events demo panel");
+                    tui.id(TuiId::Unique(panel_id))
+                        .style(Style {
+                            flex_grow: 1.,
+                            padding: length(8.),
+                            justify_content: Some(taffy::AlignContent::Center),
+                            align_items: Some(taffy::AlignItems::Center),
+                            ..Default::default()
+                        })
+                        .add_with_border(|tui| {
+                            tui.label("Panel");
+                        });
+
+                    tui.on_resize(panel_id, |size| {
+                        log.push(format!("Panel resized to {:.0} x {:.0}", size.x, size.y));
+                        if log.len() > 5 {
+                            log.remove(0);
+                        }
+                    });
+
+                    for entry in log.iter() {
+                        tui.small(entry);
+                    }
+                });
+        });
+}
+
+fn virtual_tree_demo(ctx: &egui::Context, state: &mut State) {
+    /// Visible row of file tree: top level directory, subdirectory and file indices
+    struct TreeRow {
+        path: (usize, Option<usize>, Option<usize>),
+        depth: usize,
+    }
+
+    const DIRS: usize = 10;
+    const SUBDIRS: usize = 100;
+    const FILES: usize = 100;
+
+    let expanded = &mut state.virtual_tree_expanded;
+
+    egui::Window::new("Virtual tree demo")
+        .default_size([300., 400.])
+        .open(&mut state.show_virtual_tree_demo)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Expand all").clicked() {
+                    for dir in 0..DIRS {
+                        expanded.insert((dir, None));
+                        for subdir in 0..SUBDIRS {
+                            expanded.insert((dir, Some(subdir)));
+                        }
+                    }
+                }
+                if ui.button("Collapse all").clicked() {
+                    expanded.clear();
+                }
+            });
+
+            // Flatten tree skipping children of collapsed directories
+            let mut rows = Vec::new();
+            for dir in 0..DIRS {
+                rows.push(TreeRow {
+                    path: (dir, None, None),
+                    depth: 0,
+                });
+                if !expanded.contains(&(dir, None)) {
+                    continue;
+                }
+                for subdir in 0..SUBDIRS {
+                    rows.push(TreeRow {
+                        path: (dir, Some(subdir), None),
+                        depth: 1,
+                    });
+                    if !expanded.contains(&(dir, Some(subdir))) {
+                        continue;
+                    }
+                    rows.extend((0..FILES).map(|file| TreeRow {
+                        path: (dir, Some(subdir), Some(file)),
+                        depth: 2,
+                    }));
+                }
+            }
+            ui.label(format!(
+                "{} of {} tree rows visible",
+                rows.len(),
+                DIRS * (1 + SUBDIRS * (1 + FILES))
+            ));
+
+            tui(ui, ui.id().with("virtual tree"))
+                .reserve_available_space()
+                .style(taffy::Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    max_size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(taffy::Style {
+                        display: taffy::Display::Grid,
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        grid_template_columns: vec![fr(1.)],
+                        size: taffy::Size {
+                            width: percent(1.),
+                            height: auto(),
+                        },
+                        max_size: percent(1.),
+                        grid_auto_rows: vec![min_content()],
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        VirtualTreeHelper::show(
+                            &rows,
+                            |row| row.depth,
+                            |row| row.path,
+                            16.,
+                            tui,
+                            |tui, info, row| {
+                                let (dir, subdir, file) = row.path;
+                                let text = match (subdir, file) {
+                                    (None, _) => format!("🗀 Directory {dir}"),
+                                    (Some(subdir), None) => format!("🗀 Subdirectory {subdir}"),
+                                    (Some(_), Some(file)) => format!("🗋 File {file}.txt"),
+                                };
+                                let key = (dir, subdir);
+                                let is_dir = file.is_none();
+                                let is_open = is_dir && expanded.contains(&key);
+
+                                let clicked = tui
+                                    .id(info.id())
+                                    .mut_style(info.row_setter())
+                                    .selectable(is_open, |tui| {
+                                        tui.label(text);
+                                    })
+                                    .response
+                                    .clicked();
+
+                                if clicked && is_dir && !expanded.remove(&key) {
+                                    expanded.insert(key);
+                                }
+                            },
+                        );
+                    });
+                });
+        });
+}
+
+fn tab_order_demo(ctx: &egui::Context, state: &mut State) {
+    let fields = &mut state.tab_order_fields;
+    let visual_order = &mut state.tab_order_visual;
+
+    egui::Window::new("Tab order demo")
+        .open(&mut state.show_tab_order_demo)
+        .show(ctx, |ui| {
+            ui.checkbox(visual_order, "Tab follows visual (row by row) order");
+            ui.label("Fields are created column by column, press Tab to move focus.");
+            ui.separator();
+
+            const HINTS: [&str; 6] = ["First name", "Email", "City", "Last name", "Phone", "Zip"];
+            const ROWS: usize = 3;
+
+            tui(ui, ui.id().with("tab order demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_auto_flow: taffy::GridAutoFlow::Column,
+                    grid_template_columns: vec![fr(1.), fr(1.)],
+                    grid_template_rows: vec![auto(); ROWS],
+                    gap: length(4.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (idx, (field, hint)) in fields.iter_mut().zip(HINTS).enumerate() {
+                        let (column, row) = (idx / ROWS, idx % ROWS);
+                        let text_edit = egui::TextEdit::singleline(field).hint_text(hint);
+
+                        if *visual_order {
+                            tui.tab_index((row * 2 + column) as i32).ui_add(text_edit);
+                        } else {
+                            tui.ui_add(text_edit);
+                        }
+                    }
+                });
+        });
+}
+
+fn scroll_overflow_demo(ctx: &egui::Context, state: &mut State) {
+    let max_height = state.scroll_overflow_max_height.get_or_insert(200.);
+
+    egui::Window::new("Scroll overflow demo")
+        .open(&mut state.show_scroll_overflow_demo)
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(max_height, 50.0..=400.).text("Max height"));
+            ui.label("Whole tui is capped to max size and scrolls when content exceeds it.");
+            ui.separator();
+
+            tui(ui, ui.id().with("scroll overflow demo"))
+                .max_size(egui::vec2(300., *max_height))
+                .scroll_overflow()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(4.),
+                    size: taffy::Size {
+                        width: length(280.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for idx in 0..15 {
+                        tui.style(Style {
+                            padding: length(6.),
+                            ..Default::default()
+                        })
+                        .add_with_border(|tui| {
+                            tui.label(format!("Card {idx}"));
+                        });
+                    }
+                });
+        });
+}
+
+fn decimal_table_demo(ctx: &egui::Context, state: &mut State) {
+    const ENTRIES: [(&str, f64); 6] = [
+        ("Salary", 4250.),
+        ("Rent", -1325.5),
+        ("Groceries", -312.47),
+        ("Coffee", -3.5),
+        ("Interest", 0.125),
+        ("Car insurance", -98.),
+    ];
+
+    egui::Window::new("Decimal table demo")
+        .open(&mut state.show_decimal_table_demo)
+        .show(ctx, |ui| {
+            ui.label("Amount columns are aligned on decimal point.");
+            ui.separator();
+
+            tui(ui, ui.id().with("decimal table demo"))
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![fr(1.), auto(), auto(), auto(), auto()],
+                    gap: taffy::Size {
+                        width: length(0.),
+                        height: length(4.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let header = |tui: &mut Tui, text: &str| {
+                        tui.style(Style {
+                            grid_column: span(2),
+                            justify_self: Some(taffy::JustifySelf::End),
+                            padding: taffy::Rect {
+                                left: length(16.),
+                                right: length(0.),
+                                top: length(0.),
+                                bottom: length(0.),
+                            },
+                            ..Default::default()
+                        })
+                        .strong(text);
+                    };
+                    tui.strong("Item");
+                    header(tui, "Amount");
+                    header(tui, "Balance");
+
+                    let mut balance = 0.;
+                    for (item, amount) in ENTRIES {
+                        balance += amount;
+                        tui.label(item);
+                        // Values are formatted with different precision
+                        TuiDecimal::new(format!("${amount}")).show(tui);
+                        TuiDecimal::new(format!("${balance:.2}")).show(tui);
+                    }
+                });
+        });
+}
+
+fn empty_state_demo(ctx: &egui::Context, state: &mut State) {
+    let items = &mut state.empty_state_items;
+
+    egui::Window::new("Empty state demo")
+        .default_size([300., 300.])
+        .open(&mut state.show_empty_state_demo)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Add item").clicked() {
+                    items.push(format!("Item {}", items.len() + 1));
+                }
+                if ui.button("Clear").clicked() {
+                    items.clear();
+                }
+            });
+            ui.separator();
+
+            tui(ui, ui.id().with("empty state demo"))
+                .reserve_available_space()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: percent(1.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        flex_grow: 1.,
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .empty_state(
+                        items.is_empty(),
+                        |tui| {
+                            tui.heading("📭");
+                            tui.label("No items yet");
+                            tui.label(
+                                egui::RichText::new("Press \"Add item\" to create one").weak(),
+                            );
+                        },
+                        |tui| {
+                            for item in items.iter() {
+                                tui.style(Style {
+                                    padding: length(6.),
+                                    ..Default::default()
+                                })
+                                .add_with_border(|tui| {
+                                    tui.label(item);
+                                });
+                            }
+                        },
+                    );
+                });
+        });
+}
+
+fn checkbox_wrap_demo(ctx: &egui::Context, state: &mut State) {
+    const OPTIONS: [&str; 3] = [
+        "Send me a weekly summary of account activity and upcoming payments",
+        "Notify me when a large transaction is made from any of my accounts",
+        "Share anonymous usage statistics",
+    ];
+    let checked = &mut state.checkbox_wrap_checked;
+    let selected = &mut state.checkbox_wrap_selected;
+    let width = state.checkbox_wrap_width.get_or_insert(200.);
+
+    egui::Window::new("Checkbox wrap demo")
+        .open(&mut state.show_checkbox_wrap_demo)
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(width, 80.0..=600.).text("Column width"));
+            ui.label("Checkbox and radio button labels wrap in the narrow column.");
+            ui.separator();
+
+            tui(ui, ui.id().with("checkbox wrap demo"))
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![length(*width), auto()],
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add_with_border(|tui| {
+                        for (value, text) in checked.iter_mut().zip(OPTIONS) {
+                            tui.ui_add(egui::Checkbox::new(value, text));
+                        }
+                        for (idx, text) in OPTIONS.into_iter().enumerate() {
+                            if tui
+                                .ui_add(egui::RadioButton::new(*selected == idx, text))
+                                .clicked()
+                            {
+                                *selected = idx;
+                            }
+                        }
+                    });
+                    tui.label("Next column");
+                });
+        });
+}
+
+fn thumbnail_demo(ctx: &egui::Context, state: &mut State) {
+    let cards = state.thumbnail_cards.get_or_insert(3);
+    let scale = state.thumbnail_scale.get_or_insert(0.5);
+
+    egui::Window::new("Thumbnail demo")
+        .open(&mut state.show_thumbnail_demo)
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(cards, 1..=8).text("Cards"));
+            ui.add(egui::Slider::new(scale, 0.25..=1.).text("Thumbnail scale"));
+            ui.label("Layout shapes are painted scaled down every frame.");
+            ui.separator();
+
+            let thumbnail = tui(ui, ui.id().with("thumbnail layout"))
+                .fit_content()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![repeat(2, vec![length(120.)])],
+                    gap: length(8.),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .render_to_shapes(|tui| {
+                    tui.style(Style {
+                        grid_column: span(2),
+                        ..Default::default()
+                    })
+                    .heading("Dashboard");
+                    for idx in 0..*cards {
+                        tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            padding: length(8.),
+                            ..Default::default()
+                        })
+                        .add_with_background(|tui| {
+                            tui.strong(format!("Card {}", idx + 1));
+                            tui.label(format!("Value: {}", (idx + 1) * 42));
+                        });
+                    }
+                });
+
+            ui.horizontal(|ui| {
+                ui.label("Thumbnail:");
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    let size = thumbnail.size * *scale;
+                    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                    thumbnail.paint(ui.painter(), rect.min, *scale);
+                });
+            });
+        });
+}
+
+fn reveal_demo(ctx: &egui::Context, state: &mut State) {
+    let from_center = state.reveal_from_center.get_or_insert(true);
+    let revealed = &mut state.reveal_card;
+
+    egui::Window::new("Reveal demo")
+        .open(&mut state.show_reveal_demo)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.toggle_value(revealed, "Reveal");
+                ui.radio_value(from_center, true, "Wipe from center");
+                ui.radio_value(from_center, false, "Wipe from left");
+            });
+            ui.separator();
+
+            let origin = if *from_center {
+                egui::vec2(0.5, 0.5)
+            } else {
+                egui::vec2(0., 0.5)
+            };
+
+            tui(ui, ui.id().with("reveal demo"))
+                .reserve_available_width()
+                .style(Style {
+                    justify_content: Some(taffy::JustifyContent::Center),
+                    padding: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.reveal(origin, *revealed)
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            size: taffy::Size {
+                                width: length(240.),
+                                height: length(140.),
+                            },
+                            padding: length(12.),
+                            gap: length(6.),
+                            ..Default::default()
+                        })
+                        .add_with_background(|tui| {
+                            tui.heading("Hidden card");
+                            tui.label("Content is revealed with animated clip rect.");
+                            tui.ui(|ui| {
+                                let _ = ui.button("Action");
+                            });
+                        });
+                });
+        });
+}
+
+fn scroll_velocity_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Scroll velocity demo")
+        .open(&mut state.show_scroll_velocity_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("scroll velocity demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(4.),
+                    size: taffy::Size {
+                        width: length(280.),
+                        height: length(300.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let scroll_id = egui::Id::new("scroll velocity demo content");
+
+                    // Velocity from the last frame, cards tilt in the direction of scrolling
+                    let velocity = tui.scroll_velocity(scroll_id).y;
+                    let tilt = (velocity / 2000.).clamp(-0.3, 0.3);
+                    tui.label(format!("Scroll velocity: {velocity:.0} pt/s"));
+
+                    tui.id(TuiId::Unique(scroll_id))
+                        .style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            flex_grow: 1.,
+                            gap: length(8.),
+                            padding: length(8.),
+                            overflow: taffy::Point {
+                                x: taffy::Overflow::Hidden,
+                                y: taffy::Overflow::Scroll,
+                            },
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            for idx in 1..=30 {
+                                tui.style(Style {
+                                    size: taffy::Size {
+                                        width: percent(1.),
+                                        height: length(48.),
+                                    },
+                                    flex_shrink: 0.,
+                                    ..Default::default()
+                                })
+                                .ui(|ui| {
+                                    let rect = ui.max_rect();
+                                    ui.allocate_rect(rect, egui::Sense::hover());
+
+                                    // Skew card horizontally, top edge lags behind scrolling
+                                    let skew = egui::vec2(tilt * rect.height() / 2., 0.);
+                                    let points = vec![
+                                        rect.left_top() - skew,
+                                        rect.right_top() - skew,
+                                        rect.right_bottom() + skew,
+                                        rect.left_bottom() + skew,
+                                    ];
+                                    let visuals = ui.visuals();
+                                    ui.painter().add(egui::Shape::convex_polygon(
+                                        points,
+                                        visuals.faint_bg_color,
+                                        visuals.widgets.noninteractive.bg_stroke,
+                                    ));
+                                    ui.painter().text(
+                                        rect.center(),
+                                        egui::Align2::CENTER_CENTER,
+                                        format!("Card {idx}"),
+                                        egui::FontId::proportional(14.),
+                                        visuals.text_color(),
+                                    );
+                                });
+                            }
+                        });
+                });
+        });
+}
+
+fn split_demo(ctx: &egui::Context, state: &mut State) {
+    // Ratio is owned by application and persisted in egui memory (saved with app state
+    // when egui `persistence` feature is enabled)
+    let ratio_id = egui::Id::new("split demo ratio");
+    let mut ratio = ctx.data_mut(|data| *data.get_persisted_mut_or(ratio_id, 0.3f32));
+
+    egui::Window::new("Split demo")
+        .open(&mut state.show_split_demo)
+        .show(ctx, |ui| {
+            ui.add(egui::Slider::new(&mut ratio, 0.0..=1.).text("Ratio"));
+            ui.label("Both splits are bound to the same ratio. Handle snaps to the center.");
+            ui.separator();
+
+            tui(ui, ui.id().with("split demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    gap: length(8.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: length(300.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    let pane = |tui: &mut Tui, text: &str| {
+                        tui.style(Style {
+                            flex_grow: 1.,
+                            padding: length(8.),
+                            ..Default::default()
+                        })
+                        .add_with_background(|tui| {
+                            tui.label(text);
+                        });
+                    };
+
+                    TuiSplit::new(&mut ratio).min_sizes(80., 120.).show(
+                        tui.style(Style {
+                            flex_grow: 1.,
+                            ..Default::default()
+                        }),
+                        |tui| pane(tui, "Sidebar (min 80)"),
+                        |tui| pane(tui, "Content (min 120)"),
+                    );
+
+                    TuiSplit::new(&mut ratio).vertical(true).show(
+                        tui.style(Style {
+                            flex_grow: 1.,
+                            ..Default::default()
+                        }),
+                        |tui| pane(tui, "Top"),
+                        |tui| pane(tui, "Bottom"),
+                    );
+                });
+        });
+
+    ctx.data_mut(|data| data.insert_persisted(ratio_id, ratio));
+}
+
+fn line_clamp_demo(ctx: &egui::Context, state: &mut State) {
+    let cards = [
+        (
+            "Mountains",
+            "Hiking trails with views over valleys and lakes, cabins for overnight stays.",
+        ),
+        ("City", "Museums, galleries and restaurants."),
+        (
+            "Coast",
+            "Sandy beaches, small fishing villages, boat trips to nearby islands and fresh \
+             seafood in every harbour along the way.",
+        ),
+        (
+            "Forest",
+            "Quiet walks between old trees with birdsong and mushroom picking in autumn.",
+        ),
+        ("Desert", "Dunes at sunset."),
+        (
+            "Islands",
+            "Ferries between islands, snorkeling in clear water and long evenings at the shore.",
+        ),
+    ];
+
+    egui::Window::new("Line clamp demo")
+        .open(&mut state.show_line_clamp_demo)
+        .show(ctx, |ui| {
+            ui.label("Descriptions are clamped to 2 lines, resize window to rewrap them.");
+            ui.separator();
+
+            tui(ui, ui.id().with("line clamp demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![repeat(3, vec![fr(1.)])],
+                    gap: length(8.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (title, description) in cards {
+                        tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            gap: length(4.),
+                            padding: length(8.),
+                            min_size: taffy::Size {
+                                width: length(0.),
+                                height: auto(),
+                            },
+                            ..Default::default()
+                        })
+                        .add_with_background(|tui| {
+                            tui.strong(title);
+                            tui.label_clamped(description, 2);
+                        });
+                    }
+                });
+        });
+}
+
+/// Line chart widget that fills available width
+struct Sparkline<'a>(&'a [f32]);
+
+impl egui::Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let size = egui::vec2(ui.available_width().max(40.), 20.);
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+
+        let (min, max) = self
+            .0
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        let points = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                let x = idx as f32 / (self.0.len() - 1).max(1) as f32;
+                let y = (value - min) / (max - min).max(f32::EPSILON);
+                rect.lerp_inside(egui::vec2(x, 1. - y))
+            })
+            .collect();
+        ui.painter().add(egui::Shape::line(
+            points,
+            ui.visuals().widgets.active.fg_stroke,
+        ));
+        response
+    }
+}
+
+// Measurement defaults are registered once, `ui_add` uses them in every call
+impl TuiMeasuredWidget for Sparkline<'_> {
+    fn measure(
+        mut value: TuiContainerResponse<egui::Response>,
+        _ui: &egui::Ui,
+    ) -> TuiContainerResponse<egui::Response> {
+        // Chart can shrink to 40 points and grow to any width
+        value.min_size.x = 40.;
+        value.intrinsic_size = None;
+        value.infinite.x = true;
+        value
+    }
+}
+
+fn measured_widget_demo(ctx: &egui::Context, state: &mut State) {
+    let series: [(&str, [f32; 8]); 3] = [
+        ("CPU", [12., 30., 25., 60., 45., 80., 55., 40.]),
+        ("Memory", [40., 42., 45., 44., 50., 52., 51., 55.]),
+        ("Network", [5., 50., 10., 70., 20., 5., 60., 30.]),
+    ];
+
+    egui::Window::new("Measured widget demo")
+        .open(&mut state.show_measured_widget_demo)
+        .show(ctx, |ui| {
+            ui.label("Sparkline widget implements TuiMeasuredWidget, charts fill grid column.");
+            ui.separator();
+
+            tui(ui, ui.id().with("measured widget demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![auto(), fr(1.)],
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(8.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (name, values) in &series {
+                        tui.label(*name);
+                        tui.ui_add(Sparkline(values));
+                    }
+                });
+        });
+}
+
+fn scroll_fade_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Scroll fade demo")
+        .open(&mut state.show_scroll_fade_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("scroll fade demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: taffy::Size {
+                        width: length(300.),
+                        height: length(300.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        flex_grow: 1.,
+                        gap: length(8.),
+                        padding: length(8.),
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Hidden,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        // Large title that scrolls away
+                        tui.style(Style {
+                            padding: length(16.),
+                            ..Default::default()
+                        })
+                        .add_with_background(|tui| {
+                            tui.heading("Article title");
+                        });
+
+                        for idx in 1..=30 {
+                            tui.label(format!("Paragraph {idx}"));
+                        }
+
+                        // Compact header fades in after large title is scrolled away.
+                        // It is added last to be painted above scrolled content.
+                        tui.sticky([false, true].into())
+                            .scroll_fade(40., 70.)
+                            .style(Style {
+                                position: taffy::Position::Absolute,
+                                inset: taffy::Rect {
+                                    left: length(0.),
+                                    right: length(0.),
+                                    top: length(0.),
+                                    bottom: auto(),
+                                },
+                                padding: length(8.),
+                                ..Default::default()
+                            })
+                            .add_with_background(|tui| {
+                                tui.strong("Article title");
+                            });
+                    });
+                });
+        });
+}
+
+fn rtl_demo(ctx: &egui::Context, state: &mut State) {
+    // Default egui fonts have no Arabic or Hebrew glyphs, load a font covering the script
+    // to display real right-to-left text
+    let rows = [
+        (
+            "English",
+            false,
+            "Welcome to the application, please sign in to continue.",
+        ),
+        (
+            "Arabic",
+            true,
+            "Marhaban bika fi al-tatbiq (right-to-left cell)",
+        ),
+        ("German", false, "Willkommen in der Anwendung."),
+        ("Hebrew", true, "Bruchim haba'im (right-to-left cell)"),
+    ];
+
+    egui::Window::new("RTL text demo")
+        .open(&mut state.show_rtl_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("rtl demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![auto(), fr(1.)],
+                    gap: length(8.),
+                    size: taffy::Size {
+                        width: length(400.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (language, rtl, text) in rows {
+                        tui.strong(language);
+                        let cell = tui.style(Style {
+                            flex_direction: taffy::FlexDirection::Column,
+                            gap: length(4.),
+                            ..Default::default()
+                        });
+                        let cell = match rtl {
+                            true => cell.rtl_text(),
+                            false => cell,
+                        };
+                        cell.add(|tui| {
+                            tui.label(text);
+                            tui.ui(|ui| {
+                                let _ = ui.button("Reply");
+                                let _ = ui.button("Translate");
+                            });
+                        });
+                    }
+                });
+        });
+}
+
+fn spacer_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Spacer demo")
+        .open(&mut state.show_spacer_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("spacer demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(4.),
+                    padding: length(4.),
+                    size: taffy::Size {
+                        width: percent(1.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.strong("Document.txt");
+                    tui.spacer_fixed(16.);
+                    let _ = tui.button(|tui| tui.label("Undo"));
+                    let _ = tui.button(|tui| tui.label("Redo"));
+
+                    // Push following actions to the right edge
+                    tui.spacer();
+
+                    let _ = tui.button(|tui| tui.label("Share"));
+                    let _ = tui.button(|tui| tui.label("Save"));
+                });
+        });
+}
+
+fn multi_select_demo(ctx: &egui::Context, state: &mut State) {
+    let selected = &mut state.multi_select_selected;
+    let anchor = &mut state.multi_select_anchor;
+
+    egui::Window::new("Multi select demo")
+        .open(&mut state.show_multi_select_demo)
+        .show(ctx, |ui| {
+            ui.label("Click to select, Ctrl+click to toggle, Shift+click to select range.");
+            ui.label(format!("Selected: {} items", selected.len()));
+            ui.separator();
+
+            tui(ui, ui.id().with("multi select demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    align_items: Some(taffy::AlignItems::Stretch),
+                    gap: length(2.),
+                    size: taffy::Size {
+                        width: length(250.),
+                        height: auto(),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for idx in 0..12 {
+                        let response = tui
+                            .style(Style {
+                                padding: length(4.),
+                                ..Default::default()
+                            })
+                            .selectable(selected.contains(&idx), |tui| {
+                                tui.label(format!("File {}.txt", idx + 1));
+                            });
+
+                        let Some(modifiers) = response.clicked_with_modifiers() else {
+                            continue;
+                        };
+                        match (modifiers.shift, *anchor) {
+                            (true, Some(anchor)) => {
+                                // Range from the last clicked item, Ctrl adds range to selection
+                                if !modifiers.command {
+                                    selected.clear();
+                                }
+                                selected.extend(anchor.min(idx)..=anchor.max(idx));
+                            }
+                            _ => {
+                                if modifiers.command {
+                                    if !selected.remove(&idx) {
+                                        selected.insert(idx);
+                                    }
+                                } else {
+                                    selected.clear();
+                                    selected.insert(idx);
+                                }
+                                *anchor = Some(idx);
+                            }
+                        }
+                    }
+                });
+        });
+}
+
+fn streaming_log_demo(ctx: &egui::Context, state: &mut State) {
+    const MAX_LINES: usize = 200;
+
+    let log = &mut state.streaming_log;
+    let count = &mut state.streaming_log_count;
+    let debounce = state.streaming_log_debounce.get_or_insert(true);
+
+    egui::Window::new("Streaming log demo")
+        .open(&mut state.show_streaming_log_demo)
+        .show(ctx, |ui| {
+            // New line every frame, layout would be recalculated every frame
+            *count += 1;
+            log.push(format!(
+                "[{:>6}] {}",
+                *count,
+                "received chunk ".repeat(1 + *count % 4)
+            ));
+            if log.len() > MAX_LINES {
+                log.remove(0);
+            }
+            ui.ctx().request_repaint();
+
+            ui.checkbox(debounce, "Debounce relayout (100 ms)");
+            ui.label("Latest lines are painted every frame, layout is updated in steps.");
+            ui.separator();
+
+            let mut tui = tui(ui, ui.id().with("streaming log demo")).reserve_available_width();
+            if *debounce {
+                tui = tui.relayout_debounce(std::time::Duration::from_millis(100));
+            }
+            tui.style(Style {
+                flex_direction: taffy::FlexDirection::Column,
+                size: taffy::Size {
+                    width: length(300.),
+                    height: length(250.),
+                },
+                overflow: taffy::Point {
+                    x: taffy::Overflow::Hidden,
+                    y: taffy::Overflow::Scroll,
+                },
+                ..Default::default()
+            })
+            .show(|tui| {
+                for line in log.iter() {
+                    tui.wrap_mode(egui::TextWrapMode::Truncate)
+                        .label(egui::RichText::new(line).monospace());
+                }
+            });
+        });
+}
+
+fn auto_sized_grid_demo(ctx: &egui::Context, state: &mut State) {
+    const VIRTUALIZE_AFTER: usize = 200;
+
+    let row_count = state.auto_sized_grid_rows.get_or_insert(10);
+
+    egui::Window::new("Auto sized grid demo")
+        .open(&mut state.show_auto_sized_grid_demo)
+        .show(ctx, |ui| {
+            ui.add(
+                egui::Slider::new(row_count, 10..=100_000)
+                    .logarithmic(true)
+                    .text("Rows"),
+            );
+            ui.label(if *row_count > VIRTUALIZE_AFTER {
+                "Virtualized: rows have height of the first row, notes are truncated"
+            } else {
+                "Auto sized: every row is as tall as its tallest cell"
+            });
+            ui.separator();
+
+            tui(ui, ui.id().with("auto sized grid demo"))
+                .reserve_available_width()
+                .style(Style {
+                    flex_direction: taffy::FlexDirection::Column,
+                    size: taffy::Size {
+                        width: length(320.),
+                        height: length(300.),
+                    },
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    tui.style(Style {
+                        display: taffy::Display::Grid,
+                        grid_template_columns: vec![length(60.), fr(1.)],
+                        grid_auto_rows: vec![min_content()],
+                        gap: length(4.),
+                        flex_grow: 1.,
+                        min_size: taffy::Size {
+                            width: auto(),
+                            height: length(0.),
+                        },
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Hidden,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        for (column, header) in ["Row", "Note"].into_iter().enumerate() {
+                            tui.sticky([false, true].into())
+                                .id(tid(("header", column)))
+                                .style(Style {
+                                    grid_row: style_helpers::line(1),
+                                    padding: length(2.),
+                                    ..Default::default()
+                                })
+                                .add_with_background_color(|tui| {
+                                    tui.strong(header);
+                                });
+                        }
+
+                        VirtualGridRowHelper::show_auto_sized(
+                            VirtualGridRowHelperParams {
+                                header_row_count: 1,
+                                row_count: *row_count,
+                            },
+                            VIRTUALIZE_AFTER,
+                            &[],
+                            tui,
+                            |tui, row| {
+                                let mut id_gen = row.id_gen();
+                                let note = "Longer note that wraps into multiple lines. "
+                                    .repeat(row.idx % 3);
+
+                                tui.id(id_gen())
+                                    .mut_style(row.cell_setter(0))
+                                    .label(format!("{}", row.idx + 1));
+
+                                let wrap_mode = if row.virtualized {
+                                    egui::TextWrapMode::Truncate
+                                } else {
+                                    egui::TextWrapMode::Wrap
+                                };
+                                tui.id(id_gen())
+                                    .mut_style(row.cell_setter(1))
+                                    .wrap_mode(wrap_mode)
+                                    .label(format!("Item {}. {}", row.idx + 1, note));
+                            },
+                        );
+                    });
+                });
+        });
+}
+
+fn floating_button_demo(ctx: &egui::Context, state: &mut State) {
+    let items = state.floating_button_items.get_or_insert(20);
+
+    // Styles are built once and reused by all list items
+    let list_style = StyleBuilder::new()
+        .flex_column()
+        .gap(4.)
+        .padding(4.)
+        .grow(1.)
+        .min_height(0.)
+        .overflow(taffy::Overflow::Hidden, taffy::Overflow::Scroll)
+        .build();
+    let item_style = StyleBuilder::new().padding(8.).build();
+
+    egui::Window::new("Floating button demo")
+        .open(&mut state.show_floating_button_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("floating button demo"))
+                .reserve_available_width()
+                .style(
+                    StyleBuilder::new()
+                        .flex_column()
+                        .width(260.)
+                        .height(320.)
+                        .build(),
+                )
+                .show(|tui| {
+                    tui.style(list_style).add(|tui| {
+                        for idx in 1..=*items {
+                            tui.style(item_style.clone()).add_with_border(|tui| {
+                                tui.label(format!("Message {idx}"));
+                            });
+                        }
+
+                        // Added last to be painted above the list
+                        let response = tui
+                            .style(StyleBuilder::new().margin(12.).build())
+                            .floating_button(egui::Align2::RIGHT_BOTTOM, |tui| {
+                                tui.heading("+");
+                            });
+                        if response.clicked() {
+                            *items += 1;
+                        }
+                    });
+                });
+        });
+}
+
+fn combo_box_demo(ctx: &egui::Context, state: &mut State) {
+    const FONTS: [&str; 3] = ["Proportional", "Monospace", "Proportional (large headings)"];
+    const SIZES: [&str; 4] = ["S", "M", "L", "XL"];
+
+    let font = &mut state.combo_box_font;
+    let size = &mut state.combo_box_size;
+
+    egui::Window::new("Combo box demo")
+        .open(&mut state.show_combo_box_demo)
+        .show(ctx, |ui| {
+            ui.label("Combo box width fits the widest item, popup does not affect layout.");
+            ui.separator();
+
+            tui(ui, ui.id().with("combo box demo"))
+                .reserve_available_width()
+                .style(Style {
+                    display: taffy::Display::Grid,
+                    grid_template_columns: vec![auto(), auto(), fr(1.)],
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(8.),
+                    ..Default::default()
+                })
+                .show(|tui| {
+                    for (name, options, selected) in
+                        [("Font", &FONTS[..], font), ("Size", &SIZES[..], size)]
+                    {
+                        tui.label(name);
+                        tui.ui_add(TuiComboBox::new(name, options[*selected], |ui| {
+                            for (idx, option) in options.iter().enumerate() {
+                                ui.selectable_value(selected, idx, *option);
+                            }
+                        }));
+                        tui.label(format!("Selected: {}", options[*selected]));
+                    }
+                });
+        });
+}
+
+fn node_graph_demo(ctx: &egui::Context, state: &mut State) {
+    const NODES: [(&str, &[&str]); 4] = [
+        ("Texture", &["Color", "Alpha"]),
+        ("Multiply", &["A", "B", "Result"]),
+        ("Noise", &["Scale", "Value"]),
+        ("Output", &["Surface"]),
+    ];
+    const LINKS: [(usize, usize); 3] = [(0, 1), (2, 1), (1, 3)];
+
+    let transform = &mut state.node_graph_transform;
+    let positions = &mut state.node_graph_positions;
+    if positions.is_empty() {
+        positions.extend([
+            egui::pos2(20., 20.),
+            egui::pos2(220., 60.),
+            egui::pos2(20., 160.),
+            egui::pos2(420., 100.),
+        ]);
+    }
+
+    let node_style = StyleBuilder::new()
+        .flex_column()
+        .gap(4.)
+        .padding(6.)
+        .width(140.)
+        .with(|style| style.position = taffy::Position::Absolute);
+
+    egui::Window::new("Node graph demo")
+        .open(&mut state.show_node_graph_demo)
+        .show(ctx, |ui| {
+            ui.label("Drag background to pan, ctrl + scroll to zoom, drag node title to move it.");
+            if ui.button("Reset view").clicked() {
+                *transform = Default::default();
+            }
+            ui.separator();
+
+            tui(ui, ui.id().with("node graph demo"))
+                .reserve_available_width()
+                .style(StyleBuilder::new().flex_column().height(360.).build())
+                .show(|tui| {
+                    let canvas = tui.style(StyleBuilder::new().grow(1.).build());
+                    TuiCanvas::new(transform).show(canvas, |tui| {
+                        // Links are painted in logical coordinates, canvas transform is applied
+                        let stroke = tui.egui_ui().visuals().widgets.noninteractive.fg_stroke;
+                        for (from, to) in LINKS {
+                            let from = positions[from] + egui::vec2(140., 20.);
+                            let to = positions[to] + egui::vec2(0., 20.);
+                            tui.egui_ui().painter().line_segment([from, to], stroke);
+                        }
+
+                        for ((title, ports), position) in NODES.iter().zip(positions.iter_mut()) {
+                            let style = node_style
+                                .clone()
+                                .with(|style| {
+                                    style.inset = taffy::Rect {
+                                        left: length(position.x),
+                                        top: length(position.y),
+                                        right: auto(),
+                                        bottom: auto(),
+                                    }
+                                })
+                                .build();
+
+                            tui.id(tid(title)).style(style).add_with_background(|tui| {
+                                let title = tui.ui_add(
+                                    egui::Label::new(egui::RichText::new(*title).strong())
+                                        .sense(egui::Sense::drag()),
+                                );
+                                // Drag delta is in logical coordinates of canvas
+                                *position += title.drag_delta();
+
+                                for port in ports.iter() {
+                                    tui.label(*port);
+                                }
+                            });
+                        }
+                    });
+                });
+        });
+}
+
+fn collapsing_header_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Collapsing header demo")
+        .open(&mut state.show_collapsing_header_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("collapsing header demo"))
+                .reserve_available_width()
+                .style(StyleBuilder::new().flex_column().gap(4.).build())
+                .show(|tui| {
+                    // Headers and bodies are direct children of the column, closed body
+                    // is removed from the layout
+                    for (section, settings) in [
+                        ("General", &["Language", "Theme"][..]),
+                        ("Editor", &["Font size", "Tab width", "Line numbers"][..]),
+                        ("Advanced", &["Log level"][..]),
+                    ] {
+                        let response = TuiCollapsingHeader::new(section)
+                            .default_open(section == "General")
+                            .show(tui, |tui| {
+                                tui.style(
+                                    StyleBuilder::new()
+                                        .flex_column()
+                                        .padding_xy(16., 2.)
+                                        .build(),
+                                )
+                                .add(|tui| {
+                                    for setting in settings {
+                                        tui.label(*setting);
+                                    }
+                                });
+                            });
+                        if !response.body_shown {
+                            let color = tui.egui_ui().visuals().weak_text_color();
+                            tui.colored_label(color, format!("{} hidden settings", settings.len()));
+                        }
+                    }
+                });
+        });
+}
+
+fn notes_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Notes demo")
+        .open(&mut state.show_notes_demo)
+        .show(ctx, |ui| {
+            tui(ui, ui.id().with("notes demo"))
+                .reserve_available_width()
+                .style(
+                    StyleBuilder::new()
+                        .flex_column()
+                        .align_items(taffy::AlignItems::Stretch)
+                        .gap(4.)
+                        .width(300.)
+                        .build(),
+                )
+                .show(|tui| {
+                    tui.ui_add(
+                        egui::TextEdit::singleline(&mut state.notes_title).hint_text("Title"),
+                    );
+                    // Notes field grows while typing and pushes content below it down
+                    tui.ui_add(
+                        TuiTextEdit::multiline(&mut state.notes_text)
+                            .hint_text("Notes")
+                            .min_rows(2),
+                    );
+                    let lines = state.notes_text.lines().count();
+                    tui.label(format!("{lines} lines"));
+                });
+        });
+}
+
+fn pull_to_refresh_demo(ctx: &egui::Context, state: &mut State) {
+    let refresh_count = &mut state.pull_to_refresh_count;
+
+    egui::Window::new("Pull to refresh demo")
+        .open(&mut state.show_pull_to_refresh_demo)
+        .show(ctx, |ui| {
+            ui.label("Drag list down while it is scrolled to the top and release to refresh.");
+            ui.separator();
+
+            tui(ui, ui.id().with("pull to refresh demo"))
+                .reserve_available_width()
+                .style(StyleBuilder::new().flex_column().height(300.).build())
+                .show(|tui| {
+                    let list_style = StyleBuilder::new()
+                        .flex_column()
+                        .gap(4.)
+                        .grow(1.)
+                        .min_height(0.)
+                        .overflow(taffy::Overflow::Hidden, taffy::Overflow::Scroll)
+                        .build();
+
+                    tui.style(list_style).pull_to_refresh(60.).add(|tui| {
+                        if tui.refresh_requested(tui.current_id()) {
+                            *refresh_count += 1;
+                        }
+
+                        // Newest messages are at the top
+                        for idx in (1..=20 + *refresh_count).rev() {
+                            tui.style(StyleBuilder::new().padding(8.).build())
+                                .add_with_border(|tui| {
+                                    tui.label(format!("Message {idx}"));
+                                });
+                        }
+                    });
+                });
+        });
+}
+
+fn virtual_2d_grid_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Virtual 2D grid demo")
+        .open(&mut state.show_virtual_2d_grid_demo)
+        .show(ctx, |ui| {
+            ui.label("Only visible cells of 100000 x 10000 grid are added to the layout.");
+            ui.separator();
+
+            tui(ui, ui.id().with("virtual 2d grid demo"))
+                .reserve_available_width()
+                .style(StyleBuilder::new().flex_column().height(400.).build())
+                .show(|tui| {
+                    let grid_style = StyleBuilder::new()
+                        .display(taffy::Display::Grid)
+                        .gap(4.)
+                        .grow(1.)
+                        .min_height(0.)
+                        .overflow(taffy::Overflow::Scroll, taffy::Overflow::Scroll)
+                        .build();
+
+                    tui.style(grid_style).add(|tui| {
+                        let columns = VirtualGridColumnHelper::visible_columns(
+                            VirtualGridColumnHelperParams {
+                                header_column_count: 0,
+                                column_count: 10000,
+                            },
+                            tui,
+                        );
+                        columns.add_spacers(tui);
+
+                        VirtualGridRowHelper::show(
+                            VirtualGridRowHelperParams {
+                                header_row_count: 0,
+                                row_count: 100000,
+                            },
+                            tui,
+                            |tui, row| {
+                                for column in columns.iter() {
+                                    tui.id(tid(("cell", row.idx, column.idx)))
+                                        .style(StyleBuilder::new().width(80.).padding(2.).build())
+                                        .mut_style(row.grid_row_setter())
+                                        .mut_style(column.grid_column_setter())
+                                        .add_with_border(|tui| {
+                                            tui.label(format!("{} : {}", row.idx, column.idx));
+                                        });
+                                }
+                            },
+                        );
+                    });
+                });
+        });
+}
+
+fn pill_button_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Pill button demo")
+        .open(&mut state.show_pill_button_demo)
+        .show(ctx, |ui| {
+            ui.label("Corner radius is half of button height, buttons stay pill shaped.");
+            ui.separator();
+
+            tui(ui, ui.id().with("pill button demo"))
+                .reserve_available_width()
+                .style(
+                    StyleBuilder::new()
+                        .flex_row()
+                        .wrap()
+                        .gap(8.)
+                        .align_items(taffy::AlignItems::Center)
+                        .build(),
+                )
+                .show(|tui| {
+                    for (label, padding) in [("Small", 2.), ("Medium", 8.), ("Large", 16.)] {
+                        let _ = tui
+                            .style(
+                                StyleBuilder::new()
+                                    .padding_xy(2. * padding, padding)
+                                    .build(),
+                            )
+                            .corner_radius_fraction(0.5)
+                            .button(|tui| tui.label(label));
+                    }
+
+                    // Button grows with wrapped text, rounding follows its height
+                    let _ = tui
+                        .style(
+                            StyleBuilder::new()
+                                .padding_xy(16., 8.)
+                                .max_width(120.)
+                                .build(),
+                        )
+                        .corner_radius_fraction(0.5)
+                        .button(|tui| tui.label("Multiline text wraps inside of pill button"));
+                });
+        });
+}
+
+fn virtual_list_demo(ctx: &egui::Context, state: &mut State) {
+    egui::Window::new("Virtual list demo")
+        .open(&mut state.show_virtual_list_demo)
+        .show(ctx, |ui| {
+            ui.label("Only visible items of 100000 item flex column are added to the layout.");
+            ui.separator();
+
+            tui(ui, ui.id().with("virtual list demo"))
+                .reserve_available_width()
+                .style(StyleBuilder::new().flex_column().height(400.).build())
+                .show(|tui| {
+                    let list_style = StyleBuilder::new()
+                        .flex_column()
+                        .gap(4.)
+                        .grow(1.)
+                        .min_height(0.)
+                        .overflow(taffy::Overflow::Hidden, taffy::Overflow::Scroll)
+                        .build();
+
+                    tui.style(list_style).add(|tui| {
+                        VirtualListHelper::show(
+                            VirtualListHelperParams { item_count: 100000 },
+                            tui,
+                            |tui, item| {
+                                tui.id(item.id())
+                                    .style(StyleBuilder::new().padding(8.).shrink(0.).build())
+                                    .add_with_border(|tui| {
+                                        tui.label(format!("Item {}", item.idx));
+                                    });
+                            },
+                        );
+                    });
+                });
+        });
+}
+
 /// Native example
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result {
@@ -0,0 +1,64 @@
+use eframe::egui;
+use egui_taffy::{taffy, tui, TuiBuilderLogic};
+use taffy::prelude::{length, Style};
+
+/// Window can not be resized smaller than tui min-content size
+#[derive(Default)]
+struct MyApp {
+    /// Last minimal window size sent to the viewport
+    min_inner_size: Option<egui::Vec2>,
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let frame = egui::Frame::central_panel(&ctx.style());
+
+        let min_size = egui::CentralPanel::default()
+            .frame(frame)
+            .show(ctx, |ui| {
+                tui(ui, ui.id().with("min window size"))
+                    .reserve_available_space()
+                    .style(Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        gap: length(8.),
+                        ..Default::default()
+                    })
+                    .show(|tui| {
+                        tui.heading("Minimal window size");
+                        tui.label(
+                            "Window can not be resized smaller than content of this tui \
+                            needs. Long text wraps at every word before the limit is reached.",
+                        );
+
+                        tui.style(Style {
+                            flex_wrap: taffy::FlexWrap::Wrap,
+                            gap: length(8.),
+                            ..Default::default()
+                        })
+                        .add(|tui| {
+                            for label in ["First", "Second", "Third"] {
+                                let _ = tui.button(|tui| tui.label(label));
+                            }
+                        });
+
+                        // Measure after all content is added
+                        tui.min_content_size()
+                    })
+            })
+            .inner;
+
+        let min_size = (min_size + frame.total_margin().sum()).ceil();
+        if self.min_inner_size != Some(min_size) {
+            self.min_inner_size = Some(min_size);
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(min_size));
+        }
+    }
+}
+
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "Minimal window size",
+        Default::default(),
+        Box::new(|_cc| Ok(Box::new(MyApp::default()))),
+    )
+}
@@ -1,31 +1,22 @@
-use crate::{TuiBuilderLogic, TuiContainerResponse};
+use crate::{widgets::TaffyImage, TuiBuilderLogic, TuiContainerResponse, TuiMeasuredWidget};
 
 use super::{TuiBuilder, TuiWidget};
 
-/// Implement egui widgets for taffy ui
+/// Implement egui widgets for taffy ui with default measurement (size of widget response)
 ///
 /// Idea taken from egui_flex
 macro_rules! impl_widget {
     ($($widget:ty),*) => {
         $(
-            impl TuiWidget for $widget {
-                type Response = egui::Response;
-
-                fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
-                    tuib.ui_add_manual(|ui| ui.add(self), identity_transform)
-                }
-            }
+            impl TuiMeasuredWidget for $widget {}
         )*
     };
 }
 impl_widget!(
     egui::Label,
-    egui::Checkbox<'_>,
-    egui::Image<'_>,
     egui::DragValue<'_>,
     egui::Hyperlink,
     egui::ImageButton<'_>,
-    egui::RadioButton,
     egui::Link,
     egui::SelectableLabel,
     egui::Slider<'_>,
@@ -58,6 +49,116 @@ impl TuiWidget for egui::ProgressBar {
     }
 }
 
+impl TuiWidget for egui::Checkbox<'_> {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        add_icon_label(tuib, self)
+    }
+}
+
+impl TuiWidget for egui::RadioButton {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        add_icon_label(tuib, self)
+    }
+}
+
+/// Add widget drawn as icon followed by wrapping label (checkbox, radio button)
+///
+/// Node can shrink down to icon and the longest label word (min content size) and grow up
+/// to label width on a single line (max content size). Height is measured at the current
+/// node width. Label text is not accessible, therefore text widths are measured from text
+/// painted by the widget. Text is not painted in sizing passes, widths measured in
+/// the previous pass are used instead.
+fn add_icon_label(tuib: TuiBuilder, widget: impl egui::Widget) -> egui::Response {
+    tuib.ui_manual(|ui, _container| {
+        let spacing = ui.spacing();
+        let icon_width = spacing.icon_width + spacing.icon_spacing;
+
+        let layer_id = ui.layer_id();
+        let first_shape = ui
+            .ctx()
+            .graphics(|graphics| graphics.get(layer_id).map_or(0, |list| list.next_idx().0));
+        let response = ui.add(widget);
+        let size = response.rect.size();
+
+        let text_widths_id = ui.id().with("text_widths");
+        let (word_width, line_width) = match painted_text_widths(ui, layer_id, first_shape) {
+            Some(widths) => {
+                ui.data_mut(|data| data.insert_temp(text_widths_id, widths));
+                widths
+            }
+            None => ui
+                .data(|data| data.get_temp(text_widths_id))
+                .unwrap_or((0., (size.x - icon_width).max(0.))),
+        };
+
+        let max_width = icon_width + line_width;
+        let min_width = match ui.wrap_mode() {
+            egui::TextWrapMode::Wrap => icon_width + word_width,
+            egui::TextWrapMode::Truncate => icon_width,
+            egui::TextWrapMode::Extend => max_width,
+        };
+
+        TuiContainerResponse {
+            min_size: egui::vec2(min_width.min(max_width), size.y),
+            // Intrinsic size is the wrapped size, it would limit min content width
+            intrinsic_size: None,
+            max_size: egui::vec2(max_width.max(size.x), size.y),
+            infinite: egui::Vec2b::FALSE,
+            first_baseline: None,
+            inner: response,
+        }
+    })
+}
+
+/// Widths of the longest word and of the single line layout of the first text painted
+/// to the layer after `first_shape` shapes
+fn painted_text_widths(
+    ui: &egui::Ui,
+    layer_id: egui::LayerId,
+    first_shape: usize,
+) -> Option<(f32, f32)> {
+    let job = ui.ctx().graphics(|graphics| {
+        graphics
+            .get(layer_id)?
+            .all_entries()
+            .skip(first_shape)
+            .find_map(|clipped| match &clipped.shape {
+                egui::Shape::Text(text) => Some(text.galley.job.clone()),
+                _ => None,
+            })
+    })?;
+
+    let mut job = (*job).clone();
+    job.wrap = Default::default();
+    let galley = ui.fonts(|fonts| fonts.layout_job(job));
+
+    let mut word_width: f32 = 0.;
+    for row in &galley.rows {
+        let mut word_start = None;
+        for glyph in &row.glyphs {
+            if glyph.chr.is_whitespace() {
+                word_start = None;
+                continue;
+            }
+            let start = *word_start.get_or_insert(glyph.pos.x);
+            word_width = word_width.max(glyph.max_x() - start);
+        }
+    }
+    Some((word_width, galley.size().x))
+}
+
+impl TuiWidget for egui::Image<'_> {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        TaffyImage::new(self).taffy_ui(tuib)
+    }
+}
+
 impl TuiWidget for egui::Button<'_> {
     type Response = egui::Response;
 
@@ -73,12 +174,3 @@ impl TuiWidget for egui::Button<'_> {
         )
     }
 }
-
-/// Helper function
-#[inline]
-pub fn identity_transform<T>(
-    value: TuiContainerResponse<T>,
-    _ui: &egui::Ui,
-) -> TuiContainerResponse<T> {
-    value
-}
@@ -2,10 +2,11 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
+use egui::emath::GuiRounding;
 use egui::util::IdTypeMap;
 use egui::{Pos2, Response, Ui, UiBuilder};
 use parking_lot::{ArcMutexGuard, RawMutex};
@@ -25,6 +26,12 @@ mod egui_widgets;
 /// Helper functionality for virtual elements
 pub mod virtual_tui;
 
+/// Terse string syntax for taffy styles
+pub mod style_dsl;
+
+/// Chainable builder of taffy styles
+pub mod style_builder;
+
 /// Helper function to initialize taffy layout
 pub fn tui(ui: &mut egui::Ui, id: impl Into<egui::Id>) -> TuiInitializer<'_> {
     TuiInitializer {
@@ -40,9 +47,41 @@ pub fn tui(ui: &mut egui::Ui, id: impl Into<egui::Id>) -> TuiInitializer<'_> {
             width: None,
             height: None,
         },
+        sense: egui::Sense::hover(),
+        pixel_rounding: true,
+        snap_to_pixels: false,
+        relayout_debounce: None,
+        safe_area: Rect::zero(),
+        wrap_mode: None,
+        max_size: None,
+        scroll_overflow: false,
     }
 }
 
+/// Create detached invisible ui for measuring tui size before placing it in the layout
+///
+/// Useful to size egui panels to tui content, panel size has to be known before panel is shown.
+/// Measured tui keeps its own layout state (see [`TuiInitializer::measure_only`]), therefore
+/// the same tui id is used for measuring and showing:
+///
+/// ```ignore
+/// let size = tui(&mut measure_ui(ctx, "menu measure"), "menu").measure_only(|tui| menu(tui));
+/// egui::SidePanel::left("menu panel")
+///     .resizable(false)
+///     .exact_width(size.x + frame.inner_margin.sum().x)
+///     .show(ctx, |ui| tui(ui, "menu").show(|tui| menu(tui)));
+/// ```
+pub fn measure_ui(ctx: &egui::Context, id: impl Into<egui::Id>) -> egui::Ui {
+    egui::Ui::new(
+        ctx.clone(),
+        id.into(),
+        UiBuilder::new()
+            .max_rect(ctx.screen_rect())
+            .sizing_pass()
+            .invisible(),
+    )
+}
+
 /// Egui tui initialization helper to reserve/allocate necessary space
 #[must_use]
 pub struct TuiInitializer<'a> {
@@ -52,6 +91,14 @@ pub struct TuiInitializer<'a> {
     known_size: Size<Option<f32>>,
     style: taffy::Style,
     id: egui::Id,
+    sense: egui::Sense,
+    pixel_rounding: bool,
+    snap_to_pixels: bool,
+    relayout_debounce: Option<std::time::Duration>,
+    safe_area: Rect<f32>,
+    wrap_mode: Option<egui::TextWrapMode>,
+    max_size: Option<egui::Vec2>,
+    scroll_overflow: bool,
 }
 
 impl<'a> TuiInitializer<'a> {
@@ -72,6 +119,9 @@ impl<'a> TuiInitializer<'a> {
 
     /// Reserve specific width for tui
     pub fn reserve_width(mut self, width: f32) -> TuiInitializer<'a> {
+        let width = self
+            .max_size
+            .map_or(width, |max_size| width.min(max_size.x));
         self.ui.set_min_width(width);
         self.available_space.width = AvailableSpace::Definite(width);
         self.known_size.width = Some(width);
@@ -80,6 +130,9 @@ impl<'a> TuiInitializer<'a> {
 
     /// Reserve specific height for tui
     pub fn reserve_height(mut self, height: f32) -> TuiInitializer<'a> {
+        let height = self
+            .max_size
+            .map_or(height, |max_size| height.min(max_size.y));
         self.ui.set_min_height(height);
         self.available_space.height = AvailableSpace::Definite(height);
         self.known_size.height = Some(height);
@@ -103,6 +156,86 @@ impl<'a> TuiInitializer<'a> {
         self.reserve_height(height)
     }
 
+    /// Size tui to its content, e.g. to let `egui::Window` shrink and grow with its content
+    ///
+    /// Layout is calculated using max content available space and text is not wrapped,
+    /// only content size is allocated in parent ui. Nodes can still opt in to wrapping
+    /// with [`TuiBuilderLogic::wrap_mode`] if their width is set in style.
+    ///
+    /// Recommended patterns:
+    /// * Windows: use `fit_content` without reserving space. Window follows content size,
+    ///   disable resizing (`egui::Window::resizable(false)`) as manual resizing has no effect.
+    /// * Panels: panel size is decided before content is shown, use
+    ///   [`TuiInitializer::reserve_available_space`] to fill the panel or size the panel to
+    ///   content using [`measure_ui`] and [`TuiInitializer::measure_only`].
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ctx: &egui::Context) {
+    /// egui::Window::new("Auto sized").resizable(false).show(ctx, |ui| {
+    ///     tui(ui, ui.id().with("auto sized")).fit_content().show(|tui| {
+    ///         tui.label("Window is as large as its content");
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    pub fn fit_content(mut self) -> TuiInitializer<'a> {
+        self.available_space = Size {
+            width: AvailableSpace::MaxContent,
+            height: AvailableSpace::MaxContent,
+        };
+        self.wrap_mode = Some(egui::TextWrapMode::Extend);
+        self
+    }
+
+    /// Limit size of the whole tui
+    ///
+    /// Root node size is limited to `max_size`, overflowing content is drawn outside of it.
+    /// Use [`TuiInitializer::scroll_overflow`] to scroll the whole tui instead.
+    /// Space reserved afterwards (e.g. [`TuiInitializer::reserve_available_space`])
+    /// is clamped to `max_size`, therefore call this method before reserving space.
+    pub fn max_size(mut self, max_size: egui::Vec2) -> TuiInitializer<'a> {
+        fn clamp(space: AvailableSpace, max: f32) -> AvailableSpace {
+            match space {
+                AvailableSpace::Definite(space) => AvailableSpace::Definite(space.min(max)),
+                space => space,
+            }
+        }
+
+        self.max_size = Some(max_size);
+        self.available_space = Size {
+            width: clamp(self.available_space.width, max_size.x),
+            height: clamp(self.available_space.height, max_size.y),
+        };
+        self
+    }
+
+    /// Show the whole tui in a scroll area limited to [`TuiInitializer::max_size`]
+    ///
+    /// Root node is laid out with its full size, scroll area shrinks to root size and scrolls
+    /// when it exceeds `max_size`. Simpler than wrapping content in a node with
+    /// `overflow: Scroll` style.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "capped")
+    ///     .max_size(egui::vec2(200., 100.))
+    ///     .scroll_overflow()
+    ///     .show(|tui| {
+    ///         for idx in 0..20 {
+    ///             tui.label(format!("Line {idx}"));
+    ///         }
+    ///     });
+    /// # }
+    /// ```
+    pub fn scroll_overflow(mut self) -> TuiInitializer<'a> {
+        self.scroll_overflow = true;
+        self
+    }
+
     /// Set custom sizing constraints for taffy layouting algorithm for available space
     pub fn with_available_space(
         mut self,
@@ -118,32 +251,336 @@ impl<'a> TuiInitializer<'a> {
         self
     }
 
+    /// Keep root node content out of unsafe screen regions (e.g. notches, rounded corners)
+    ///
+    /// Insets are added to root node padding when tui is shown.
+    /// Root node padding set in percent is replaced by insets.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// // Keep content below camera notch
+    /// tui(ui, "screen")
+    ///     .safe_area(taffy::Rect { left: 0., right: 0., top: 30., bottom: 0. })
+    ///     .show(|tui| {
+    ///         tui.label("Content");
+    ///     });
+    /// # }
+    /// ```
+    pub fn safe_area(mut self, insets: Rect<f32>) -> TuiInitializer<'a> {
+        self.safe_area = insets;
+        self
+    }
+
+    /// Root node style with safe area insets added to padding
+    fn root_style(style: taffy::Style, insets: Rect<f32>) -> taffy::Style {
+        fn add_inset(padding: LengthPercentage, inset: f32) -> LengthPercentage {
+            match padding {
+                LengthPercentage::Length(length) => LengthPercentage::Length(length + inset),
+                LengthPercentage::Percent(_) => LengthPercentage::Length(inset),
+            }
+        }
+
+        if insets == Rect::zero() {
+            return style;
+        }
+
+        let mut style = style;
+        style.padding = Rect {
+            left: add_inset(style.padding.left, insets.left),
+            right: add_inset(style.padding.right, insets.right),
+            top: add_inset(style.padding.top, insets.top),
+            bottom: add_inset(style.padding.bottom, insets.bottom),
+        };
+        style
+    }
+
+    /// Root node style with safe area insets and size limit applied
+    fn root_node_style(&self) -> taffy::Style {
+        let mut style = Self::root_style(self.style.clone(), self.safe_area);
+        if let (Some(max_size), false) = (self.max_size, self.scroll_overflow) {
+            style.max_size = Size {
+                width: length(max_size.x),
+                height: length(max_size.y),
+            };
+        }
+        style
+    }
+
+    /// Size allocated for tui with root node content size
+    fn allocated_size(content_size: Size<f32>, max_size: Option<egui::Vec2>) -> egui::Vec2 {
+        let size = egui::Vec2 {
+            x: content_size.width,
+            y: content_size.height,
+        };
+        match max_size {
+            Some(max_size) => size.min(max_size),
+            None => size,
+        }
+    }
+
+    /// Show tui in scroll area, see [`TuiInitializer::scroll_overflow`]
+    fn show_scrolled<T>(self, f: impl FnOnce(&mut Tui) -> T) -> TuiInnerResponse<T> {
+        let max_size = self.max_size.unwrap_or(egui::Vec2::INFINITY);
+        let scroll_area = egui::ScrollArea::both()
+            .id_salt(self.id.with("scroll_overflow"))
+            .max_width(max_size.x)
+            .max_height(max_size.y);
+        let ui = self.ui;
+        scroll_area
+            .show(ui, |ui| {
+                TuiInitializer {
+                    ui,
+                    allocated_rect: None,
+                    scroll_overflow: false,
+                    max_size: None,
+                    ..self
+                }
+                .show_with_response(f)
+            })
+            .inner
+    }
+
+    /// Set sense of the whole tui area response
+    ///
+    /// See [`TuiInitializer::show_with_response`]. Default: [`egui::Sense::hover`]
+    pub fn sense(mut self, sense: egui::Sense) -> TuiInitializer<'a> {
+        self.sense = sense;
+        self
+    }
+
+    /// Enable or disable rounding of taffy layout to whole points (default: true)
+    ///
+    /// Rounding keeps text and strokes sharp, but adjacent nodes with fractional sizes
+    /// can have visible 1px gaps or overlaps between their borders and backgrounds.
+    /// Disable rounding for seamless borders (e.g. grid cells with adjacent backgrounds),
+    /// content can then be placed at fractional positions and look slightly blurry.
+    pub fn pixel_rounding(mut self, pixel_rounding: bool) -> TuiInitializer<'a> {
+        self.pixel_rounding = pixel_rounding;
+        self
+    }
+
+    /// Snap node rects to physical pixel grid before painting (default: false)
+    ///
+    /// Taffy rounds layout to whole points, with fractional `pixels_per_point` (DPI scaling)
+    /// or disabled [`TuiInitializer::pixel_rounding`] node edges can still land between
+    /// physical pixels and borders look blurry. Snapping is applied to rects returned by
+    /// [`TaffyContainerUi`], layout itself is not changed.
+    pub fn snap_to_pixels(mut self, snap_to_pixels: bool) -> TuiInitializer<'a> {
+        self.snap_to_pixels = snap_to_pixels;
+        self
+    }
+
+    /// Recalculate layout after content change at most once per `interval` (default: every change)
+    ///
+    /// Useful for rapidly changing content (e.g. streaming log) where layout is recalculated
+    /// almost every frame. Changes made during the interval are coalesced into one layout
+    /// recalculation at the end of the interval.
+    ///
+    /// Content is always painted with its latest state, but until the next recalculation it is
+    /// placed using the previous layout: grown text can overflow its node and newly added
+    /// nodes have empty rects. Change of tui size is applied immediately.
+    pub fn relayout_debounce(mut self, interval: std::time::Duration) -> TuiInitializer<'a> {
+        self.relayout_debounce = Some(interval);
+        self
+    }
+
     /// Show tui
+    ///
+    /// Layout change requests egui to discard the pass and draw it again with the new layout.
+    /// Discards are limited to 8 layout recalculations in one frame, layout that does not
+    /// converge (e.g. node size changes on every pass) is drawn as is.
     pub fn show<T>(self, f: impl FnOnce(&mut Tui) -> T) -> T {
-        let ui = self.ui;
-        let output = Tui::create(
+        self.show_with_response(f).inner
+    }
+
+    /// Show tui and retrieve response of the whole tui area
+    ///
+    /// Response is registered before tui content using last frame tui area,
+    /// therefore it does not block interaction with tui content.
+    /// Useful to handle input (e.g. keyboard shortcuts) only when tui is hovered.
+    ///
+    /// Use [`egui::Response::contains_pointer`] to check if pointer is above tui area,
+    /// [`egui::Response::hovered`] is false while child widget is hovered.
+    pub fn show_with_response<T>(self, f: impl FnOnce(&mut Tui) -> T) -> TuiInnerResponse<T> {
+        if self.scroll_overflow {
+            return self.show_scrolled(f);
+        }
+
+        let mut root_ui = self.ui.new_child(UiBuilder::new().sense(self.sense));
+        let output = self.create_root(&mut root_ui, self.id, f);
+
+        if self.allocated_rect.is_none() {
+            // Space was not allocated yet, allocate used space
+            self.ui.allocate_space(Self::allocated_size(
+                output.container.layout.content_size,
+                self.max_size,
+            ));
+        }
+
+        root_ui.expand_to_include_rect(output.container.full_container());
+        TuiInnerResponse {
+            inner: output.inner,
+            response: root_ui.response(),
+        }
+    }
+
+    /// Build tui and calculate layout without painting it, returns size that
+    /// [`TuiInitializer::show`] would allocate
+    ///
+    /// All nodes are laid out in invisible sizing pass and no space is allocated in parent ui.
+    /// Useful to size containers (windows, popups) before showing content.
+    ///
+    /// ```
+    /// use egui_taffy::{measure_ui, tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ctx: &egui::Context) {
+    /// let menu = |tui: &mut egui_taffy::Tui| {
+    ///     tui.label("First");
+    ///     tui.label("Second");
+    /// };
+    /// // Size side panel to its content
+    /// let size = tui(&mut measure_ui(ctx, "menu measure"), "menu").measure_only(menu);
+    /// egui::SidePanel::left("menu panel")
+    ///     .resizable(false)
+    ///     .exact_width(size.x)
+    ///     .show(ctx, |ui| {
+    ///         tui(ui, "menu").reserve_available_space().show(menu);
+    ///     });
+    /// # }
+    /// ```
+    ///
+    /// Layout state is stored separately from [`TuiInitializer::show`] called with the same id,
+    /// therefore measured and shown tui with the same id do not recalculate each others layout.
+    pub fn measure_only(self, f: impl FnOnce(&mut Tui)) -> egui::Vec2 {
+        let mut ui = self
+            .ui
+            .new_child(UiBuilder::new().sizing_pass().invisible());
+        // Measured layout differs from shown one (root rect, sizing pass)
+        let output = self.create_root(&mut ui, self.id.with("measure"), f);
+
+        Self::allocated_size(output.container.layout.content_size, self.max_size)
+    }
+
+    /// Build root node in `ui` (root ui of shown or measured tui) with initializer settings
+    fn create_root<T>(
+        &self,
+        ui: &mut Ui,
+        id: egui::Id,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TaffyReturn<T> {
+        if let Some(wrap_mode) = self.wrap_mode {
+            ui.style_mut().wrap_mode = Some(wrap_mode);
+        }
+        let pixel_rounding = self.pixel_rounding;
+        let snap_to_pixels = self.snap_to_pixels;
+        let relayout_debounce = self.relayout_debounce;
+        let root_rect = ui.available_rect_before_wrap();
+        Tui::create(
             ui,
-            self.id,
-            ui.available_rect_before_wrap(),
+            id,
+            root_rect,
             Some(self.available_space),
-            self.style,
+            self.root_node_style(),
             |tui| {
                 // Temporary scroll area size limitation
                 tui.set_limit_scroll_area_size(Some(0.7));
+                tui.set_pixel_rounding(pixel_rounding);
+                tui.set_snap_to_pixels(snap_to_pixels);
+                tui.set_relayout_debounce(relayout_debounce);
 
                 f(tui)
             },
-        );
+        )
+    }
 
-        if self.allocated_rect.is_none() {
-            // Space was not allocated yet, allocate used space
-            let size = output.container.layout.content_size;
-            ui.allocate_space(egui::Vec2 {
-                x: size.width,
-                y: size.height,
-            });
+    /// Lay out tui without painting it and return its shapes (e.g. for thumbnails)
+    ///
+    /// Tui is shown on a separate layer outside of the screen and its shapes are removed from
+    /// the layer before they are painted. Returned shapes can be painted elsewhere with
+    /// [`TuiShapes::paint`] (rendering is left to egui backend, images and paint callbacks
+    /// are kept) or inspected in tests.
+    ///
+    /// Constraints:
+    /// * Tui is not interactive. Use id that differs from on screen tui with the same content.
+    /// * Space reserved with `reserve_*` methods is reserved in parent ui, set tui size using
+    ///   root style or [`TuiInitializer::with_available_space`] instead.
+    /// * Nodes shown for the first time are laid out in invisible sizing pass, shapes
+    ///   returned in the first frame are empty.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// let preview = tui(ui, "preview").render_to_shapes(|tui| {
+    ///     tui.label("Preview");
+    /// });
+    /// // Paint preview at half size
+    /// let (rect, _) = ui.allocate_exact_size(preview.size * 0.5, egui::Sense::hover());
+    /// preview.paint(ui.painter(), rect.min, 0.5);
+    /// # }
+    /// ```
+    pub fn render_to_shapes(self, f: impl FnOnce(&mut Tui)) -> TuiShapes {
+        let ctx = self.ui.ctx().clone();
+        let layer_id = egui::LayerId::new(egui::Order::Background, self.id.with("offscreen"));
+        let mut max_size = self.ui.available_size();
+        if !max_size.is_finite() {
+            max_size = ctx.screen_rect().size();
+        }
+        // Pointer does not reach content placed outside of the screen
+        let max_rect = egui::Rect::from_min_size(ctx.screen_rect().right_bottom(), max_size);
+
+        let mut ui = self
+            .ui
+            .new_child(UiBuilder::new().layer_id(layer_id).max_rect(max_rect));
+        ui.set_clip_rect(max_rect);
+        TuiInitializer {
+            ui: &mut ui,
+            allocated_rect: None,
+            ..self
+        }
+        .show(f);
+
+        let shapes = ctx.graphics_mut(|graphics| std::mem::take(graphics.entry(layer_id)));
+        let to_local = egui::emath::TSTransform::from_translation(-max_rect.min.to_vec2());
+        let shapes = shapes
+            .all_entries()
+            .map(|clipped| {
+                let mut shape = clipped.shape.clone();
+                shape.transform(to_local);
+                egui::epaint::ClippedShape {
+                    clip_rect: to_local * clipped.clip_rect,
+                    shape,
+                }
+            })
+            .collect();
+        TuiShapes {
+            size: ui.min_size(),
+            shapes,
+        }
+    }
+}
+
+/// Shapes of tui laid out with [`TuiInitializer::render_to_shapes`]
+#[derive(Clone, Debug, Default)]
+pub struct TuiShapes {
+    /// Size of tui
+    pub size: egui::Vec2,
+    /// Painted shapes relative to left top corner of tui
+    pub shapes: Vec<egui::epaint::ClippedShape>,
+}
+
+impl TuiShapes {
+    /// Paint shapes with left top corner at `pos` scaled by `scale`
+    pub fn paint(&self, painter: &egui::Painter, pos: Pos2, scale: f32) {
+        let transform = egui::emath::TSTransform::new(pos.to_vec2(), scale);
+        for clipped in &self.shapes {
+            let mut shape = clipped.shape.clone();
+            shape.transform(transform);
+            let clip_rect = painter.clip_rect().intersect(transform * clipped.clip_rect);
+            painter.with_clip_rect(clip_rect).add(shape);
         }
-        output.inner
     }
 }
 
@@ -162,6 +599,14 @@ pub struct Tui {
     taffy_container: TaffyContainerUi,
 
     last_scroll_offset: egui::Vec2,
+    /// Pixels per point used to snap node rects to physical pixels
+    pixel_snap: Option<f32>,
+    /// Minimal interval between layout recalculations caused by content change
+    relayout_debounce: Option<std::time::Duration>,
+    /// Ids and scroll offsets of scroll ancestors of current node, nearest is the last
+    scroll_ancestors: Vec<(egui::Id, egui::Vec2)>,
+    /// Tab indices and focusable widget ids of nodes with tab index
+    tab_stops: Vec<(i32, egui::Id)>,
 
     root_rect: egui::Rect,
     available_space: Option<Size<AvailableSpace>>,
@@ -205,10 +650,20 @@ impl Tui {
                 .clone();
             state
         });
-        let state = state
+        let mut state = state
             .try_lock_arc()
             .expect("Each egui_taffy instance should have unique id");
 
+        // Taffy tree can be inconsistent after error or modification through `taffy_tree_mut`
+        // (node ids known to tui can be removed), rebuild layout from scratch
+        if state.layout_error || state.tree_modified {
+            log::warn!(
+                "Resetting egui_taffy {:?} layout state after taffy error or tree modification",
+                id
+            );
+            *state = TaffyState::new();
+        }
+
         let mut this = Self {
             main_id: id,
             ui,
@@ -223,10 +678,16 @@ impl Tui {
             current_id: id,
             limit_scroll_area_size: None,
             last_scroll_offset: egui::Vec2::ZERO,
+            pixel_snap: None,
+            relayout_debounce: None,
+            scroll_ancestors: Vec::new(),
+            tab_stops: Vec::new(),
             state,
             interactive_container_inactive_style_cache: Default::default(),
         };
 
+        let tab_focus = this.intercept_tab();
+
         let res = this.tui().id(id).style(style).add(|state| {
             let resp = f(state);
             let container = state.recalculate();
@@ -236,11 +697,18 @@ impl Tui {
             }
         });
 
+        this.finish_tab_stops(tab_focus);
+
         log::trace!(
             "Cached {} interactive styles!",
             this.interactive_container_inactive_style_cache.len()
         );
 
+        if this.state.layout_error {
+            // Layout state is reset in the next pass
+            this.ui.ctx().request_discard("Taffy layout error");
+        }
+
         res
     }
 
@@ -254,19 +722,61 @@ impl Tui {
         self.limit_scroll_area_size = size;
     }
 
+    /// Enable or disable rounding of taffy layout to whole points
+    ///
+    /// See [`TuiInitializer::pixel_rounding`].
+    pub fn set_pixel_rounding(&mut self, pixel_rounding: bool) {
+        let state = self.state.deref_mut();
+        if state.pixel_rounding == pixel_rounding {
+            return;
+        }
+        state.pixel_rounding = pixel_rounding;
+        if pixel_rounding {
+            state.taffy_tree.enable_rounding();
+        } else {
+            state.taffy_tree.disable_rounding();
+        }
+    }
+
+    /// Enable or disable snapping of node rects to physical pixel grid
+    ///
+    /// See [`TuiInitializer::snap_to_pixels`].
+    pub fn set_snap_to_pixels(&mut self, snap_to_pixels: bool) {
+        self.pixel_snap = snap_to_pixels.then(|| self.ui.ctx().pixels_per_point());
+    }
+
+    /// Set minimal interval between layout recalculations caused by content change
+    ///
+    /// See [`TuiInitializer::relayout_debounce`].
+    pub fn set_relayout_debounce(&mut self, interval: Option<std::time::Duration>) {
+        self.relayout_debounce = interval;
+    }
+
     /// Add taffy child node, correctly update taffy tree state
     fn add_child_node(
         &mut self,
         id: egui::Id,
         style: taffy::Style,
         sticky: egui::Vec2b,
+        sticky_to: taffy::Point<Option<egui::Id>>,
+        sticky_header: bool,
+        animate_position: bool,
     ) -> (NodeId, TaffyContainerUi) {
         let child_idx = self.current_node_index;
         self.current_node_index += 1;
 
         let mut first_frame = false;
+        let mut first_frame_width = 0.;
+        let mut reorder_time = f64::NEG_INFINITY;
+        let time = self.ui.input(|input| input.time);
+        let parent_node = self.current_node;
+        let parent_width = match parent_node {
+            Some(_) => self.taffy_container.available_width(),
+            None => self.root_rect.width(),
+        };
 
         let state: &mut TaffyState = &mut self.state;
+        let node_sticky = sticky.or([sticky_to.x.is_some(), sticky_to.y.is_some()]);
 
         let node_id = match state.id_to_node_id.entry(id) {
             std::collections::hash_map::Entry::Occupied(mut occupied_entry) => {
@@ -276,19 +786,50 @@ impl Tui {
                     log::error!("Taffy layout id collision!");
                 }
                 val.keep = true;
+                val.sticky = node_sticky;
+                val.sticky_header = sticky_header;
+                if val.child_index != child_idx {
+                    val.child_index = child_idx;
+                    val.reorder_time = time;
+                }
+                reorder_time = val.reorder_time;
 
                 let node_id = val.node_id;
-                if state.taffy_tree.style(node_id).unwrap() != &style {
-                    state.taffy_tree.set_style(node_id, style).unwrap();
+                let current_style = taffy_result(
+                    state.taffy_tree.style(node_id),
+                    &mut state.layout_error,
+                    "style",
+                    id,
+                );
+                if current_style != Some(&style) {
+                    taffy_result(
+                        state.taffy_tree.set_style(node_id, style),
+                        &mut state.layout_error,
+                        "set_style",
+                        id,
+                    );
                 }
                 node_id
             }
             std::collections::hash_map::Entry::Vacant(vacant_entry) => {
                 first_frame = true;
-                let node_id = state.taffy_tree.new_leaf(style).unwrap();
+                let parent_style = parent_node.and_then(|node| state.taffy_tree.style(node).ok());
+                first_frame_width = estimate_content_width(&style, parent_style, parent_width);
+                let node_id = state
+                    .taffy_tree
+                    .new_leaf(style)
+                    .expect("Taffy leaf creation is infallible");
+                state.node_id_to_id.insert(node_id, id);
                 vacant_entry.insert(NodeData {
                     node_id,
                     keep: true,
+                    scroll_offset: egui::Vec2::ZERO,
+                    scroll_state_id: None,
+                    rect: egui::Rect::NOTHING,
+                    sticky: node_sticky,
+                    sticky_header,
+                    child_index: child_idx,
+                    reorder_time,
                 });
                 node_id
             }
@@ -297,42 +838,186 @@ impl Tui {
         if let Some(current_node) = self.current_node {
             if child_idx < state.taffy_tree.child_count(current_node) {
                 // Check if child at position matches
-                if state
-                    .taffy_tree
-                    .child_at_index(current_node, child_idx)
-                    .unwrap()
-                    != node_id
-                {
+                let child = taffy_result(
+                    state.taffy_tree.child_at_index(current_node, child_idx),
+                    &mut state.layout_error,
+                    "child_at_index",
+                    id,
+                );
+                if child != Some(node_id) {
                     // Layout has changed, remove all following children
                     //
                     // Because node one by one removal is slow if items have changed their location.
                     // Faster is to remove whole tail.
                     let count = state.taffy_tree.child_count(current_node);
-                    state
-                        .taffy_tree
-                        .remove_children_range(current_node, child_idx..count)
-                        .unwrap();
+                    taffy_result(
+                        state
+                            .taffy_tree
+                            .remove_children_range(current_node, child_idx..count),
+                        &mut state.layout_error,
+                        "remove_children_range",
+                        id,
+                    );
 
                     // Add element to the end
-                    state.taffy_tree.add_child(current_node, node_id).unwrap();
+                    taffy_result(
+                        state.taffy_tree.add_child(current_node, node_id),
+                        &mut state.layout_error,
+                        "add_child",
+                        id,
+                    );
                 }
             } else {
                 // Add element to the end
-                state.taffy_tree.add_child(current_node, node_id).unwrap();
+                taffy_result(
+                    state.taffy_tree.add_child(current_node, node_id),
+                    &mut state.layout_error,
+                    "add_child",
+                    id,
+                );
             }
         }
 
+        let layout = state.layout(node_id);
+        let sticky_header_shift = match sticky_header {
+            true => self.sticky_header_shift(node_id, &layout),
+            false => 0.,
+        };
+
+        let animation_offset = match animate_position {
+            true => {
+                // Animate only position changes caused by changed node order. Other changes
+                // (e.g. sibling resize, layout of new node) snap to the new position.
+                let mut animation_time = self.ui.style().animation_time;
+                if time - reorder_time > animation_time as f64 {
+                    animation_time = 0.;
+                }
+
+                let ctx = self.ui.ctx();
+                let location = layout.location;
+                let x =
+                    ctx.animate_value_with_time(id.with("animate_x"), location.x, animation_time);
+                let y =
+                    ctx.animate_value_with_time(id.with("animate_y"), location.y, animation_time);
+                match animation_time > 0. {
+                    true => egui::Vec2::new(x - location.x, y - location.y),
+                    false => egui::Vec2::ZERO,
+                }
+            }
+            false => egui::Vec2::ZERO,
+        };
+
+        // Ancestor offset includes offset of the nearest scroll ancestor, therefore `sticky`
+        // is not applied again in axes that are sticky to ancestor
+        let sticky_to_x = self
+            .ancestor_scroll_offset(sticky_to.x)
+            .map(|offset| offset.x);
+        let sticky_to_y = self
+            .ancestor_scroll_offset(sticky_to.y)
+            .map(|offset| offset.y);
+        let sticky = egui::Vec2b::new(
+            sticky.x && sticky_to_x.is_none(),
+            sticky.y && sticky_to_y.is_none(),
+        );
+        let sticky_to_offset =
+            egui::Vec2::new(sticky_to_x.unwrap_or(0.), sticky_to_y.unwrap_or(0.));
+
         let container = TaffyContainerUi {
-            layout: *state.layout(node_id),
+            layout,
             parent_rect: self.current_rect,
             first_frame,
+            first_frame_width,
             sticky,
+            sticky_to_offset,
+            sticky_header_shift,
+            animation_offset,
             last_scroll_offset: self.last_scroll_offset,
+            pixel_snap: self.pixel_snap,
         };
 
         (node_id, container)
     }
 
+    /// Sum of scroll offsets of scroll ancestors from given ancestor to the nearest one
+    ///
+    /// `None` if ancestor is not set or is not a scroll ancestor of current node.
+    fn ancestor_scroll_offset(&self, ancestor: Option<egui::Id>) -> Option<egui::Vec2> {
+        let idx = self
+            .scroll_ancestors
+            .iter()
+            .rposition(|(id, _)| Some(*id) == ancestor)?;
+        Some(
+            self.scroll_ancestors[idx..]
+                .iter()
+                .fold(egui::Vec2::ZERO, |sum, (_, offset)| sum + *offset),
+        )
+    }
+
+    /// Vertical shift that keeps sticky header node at the top edge of visible area
+    ///
+    /// Header is pushed out by the next sticky header sibling (the closest one below it)
+    /// or by the bottom edge of parent node. Uses layout from the last layout pass.
+    fn sticky_header_shift(&self, node_id: NodeId, layout: &Layout) -> f32 {
+        let Some(parent) = self.current_node else {
+            return 0.;
+        };
+        let state = self.state.deref();
+
+        let top = self.ui.clip_rect().min.y;
+        let origin = self.current_rect.min.y;
+        let header_top = origin + layout.location.y;
+
+        let mut limit = self.current_rect.max.y;
+        for sibling in state.taffy_tree.children(parent).unwrap_or_default() {
+            let is_sticky_header = sibling != node_id
+                && state
+                    .egui_id_of(sibling)
+                    .and_then(|id| state.id_to_node_id.get(&id))
+                    .is_some_and(|node_data| node_data.sticky_header);
+            if !is_sticky_header {
+                continue;
+            }
+            if let Ok(sibling_layout) = state.taffy_tree.layout(sibling) {
+                if sibling_layout.location.y > layout.location.y {
+                    limit = limit.min(origin + sibling_layout.location.y);
+                }
+            }
+        }
+
+        (top - header_top)
+            .min(limit - layout.size.height - header_top)
+            .max(0.)
+    }
+
+    /// Layer above the current one for sticky nodes
+    ///
+    /// Nodes sticky in both directions (e.g. grid corner cell) are placed above nodes
+    /// sticky in one direction.
+    fn sticky_layer(&self, both_directions: bool) -> egui::LayerId {
+        let ctx = self.ui.ctx();
+        let layer_id = self.ui.layer_id();
+        // egui supports only one level of sublayers
+        let parent = ctx
+            .memory(|memory| memory.areas().parent_layer(layer_id))
+            .unwrap_or(layer_id);
+        let transform = ctx.layer_transform_to_global(layer_id);
+
+        let layers = [false, true].map(|both_directions| {
+            egui::LayerId::new(
+                parent.order,
+                self.main_id.with(("sticky_layer", both_directions)),
+            )
+        });
+        // Registration order determines order of sublayers
+        for layer in layers {
+            ctx.set_sublayer(parent, layer);
+            if let Some(transform) = transform {
+                ctx.set_transform_layer(layer, transform);
+            }
+        }
+        layers[both_directions as usize]
+    }
+
     /// Add child taffy node to the layout with optional function to draw background
     #[inline]
     fn add_child<FR, B>(
@@ -354,6 +1039,112 @@ impl Tui {
         )
     }
 
+    /// Clip node ui to animated reveal rect, see [`TuiBuilderLogic::reveal`]
+    fn reveal_clip(&self, child_ui: &mut Ui, id: egui::Id, origin: egui::Vec2, revealed: bool) {
+        let progress = self.ui.ctx().animate_bool_with_time(
+            id.with("reveal"),
+            revealed,
+            REVEAL_ANIMATION_TIME,
+        );
+        if progress >= 1. {
+            return;
+        }
+        if progress <= 0. {
+            child_ui.set_invisible();
+            return;
+        }
+
+        let progress = egui::emath::easing::cubic_in_out(progress);
+        let rect = self.current_rect;
+        let origin = rect.lerp_inside(origin);
+        child_ui.shrink_clip_rect(egui::Rect::from_min_max(
+            origin + (rect.min - origin) * progress,
+            origin + (rect.max - origin) * progress,
+        ));
+    }
+
+    /// Register first focusable widget added to the layer after `widgets_before` widgets
+    /// as tab stop of current node
+    fn register_tab_stop(&mut self, tab_index: i32, widgets_before: usize) {
+        let layer_id = self.ui.layer_id();
+        let widget = self.ui.ctx().viewport(|viewport| {
+            viewport
+                .this_pass
+                .widgets
+                .get_layer(layer_id)
+                .skip(widgets_before)
+                .find(|widget| widget.sense.is_focusable())
+                .map(|widget| widget.id)
+        });
+        if let Some(widget) = widget {
+            self.tab_stops.push((tab_index, widget));
+        }
+    }
+
+    /// Intercept Tab (Shift+Tab) pressed while one of tab stops of the previous pass is focused
+    ///
+    /// Focused widget surrenders focus before any widget is added, therefore egui does not move
+    /// focus in widget creation order (first widget interested in focus takes it and resets
+    /// focus direction). Returns the next (previous) tab stop, it is focused after all widgets
+    /// are added.
+    fn intercept_tab(&mut self) -> Option<egui::Id> {
+        let ctx = self.ui.ctx().clone();
+        let focused = ctx.memory(|memory| memory.focused())?;
+        let tab_stops = &self.state.tab_stops;
+        let position = tab_stops.iter().position(|(_, id)| *id == focused)?;
+
+        let count = tab_stops.len();
+        let next = if ctx
+            .input_mut(|input| input.consume_key(egui::Modifiers::SHIFT, egui::Key::Tab))
+        {
+            (position + count - 1) % count
+        } else if ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::Tab)) {
+            (position + 1) % count
+        } else {
+            return None;
+        };
+        ctx.memory_mut(|memory| memory.surrender_focus(focused));
+        Some(tab_stops[next].1)
+    }
+
+    /// Store tab stops of this pass for the next pass and focus intercepted tab stop
+    fn finish_tab_stops(&mut self, tab_focus: Option<egui::Id>) {
+        let mut tab_stops = std::mem::take(&mut self.tab_stops);
+        // Stable sort keeps creation order of nodes with equal tab index
+        tab_stops.sort_by_key(|(tab_index, _)| *tab_index);
+        self.state.tab_stops = tab_stops;
+
+        if let Some(id) = tab_focus {
+            self.ui.ctx().memory_mut(|memory| memory.request_focus(id));
+        }
+    }
+
+    /// Add child node and run content function only if node is visible in the current viewport
+    fn add_child_culled<T>(
+        &mut self,
+        params: TuiBuilderParams,
+        overscan: f32,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> Option<T> {
+        self.add_child(params, (), |tui, _| {
+            let rect = tui.current_rect;
+            let visible = tui.taffy_container.first_frame
+                || !rect.is_positive()
+                || rect.intersects(tui.current_viewport.expand(overscan));
+            if visible {
+                return Some(f(tui));
+            }
+
+            // Keep children of culled node with their last measured sizes
+            let node_id = tui.current_node.unwrap();
+            let state = tui.state.deref_mut();
+            tui.current_node_index = state.taffy_tree.child_count(node_id);
+            state.culled_nodes.insert(node_id);
+            None
+        })
+        .main
+    }
+
     fn add_child_dyn<FR, BR>(
         &mut self,
         params: TuiBuilderParams,
@@ -368,15 +1159,54 @@ impl Tui {
             egui_style,
             layout,
             sticky,
+            sticky_header,
+            sticky_to,
+            animate_position,
+            drag_to_scroll,
+            scroll_bar_visibility,
+            limit_growth_to_viewport: _,
+            ripple: _,
+            appear_animation,
+            tab_index,
+            reveal,
+            scroll_fade,
+            pull_to_refresh,
+            corner_radius_fraction,
+            hidden,
         } = params;
 
-        let style = style.unwrap_or_default();
+        let mut style = style.unwrap_or_default();
+        if hidden {
+            style.display = taffy::Display::None;
+        }
 
         let id = id.resolve(self);
 
         let overflow_style = style.overflow;
+        let hidden = style.display == taffy::Display::None;
+
+        let (node_id, mut current_taffy_container) = self.add_child_node(
+            id,
+            style,
+            sticky,
+            sticky_to,
+            sticky_header,
+            animate_position,
+        );
 
-        let (node_id, mut current_taffy_container) = self.add_child_node(id, style, sticky);
+        let appear_progress = appear_animation.map(|animation| {
+            // Animation starts from hidden state in the first (invisible) frame
+            let progress = self.ui.ctx().animate_bool_with_time(
+                id.with("appear"),
+                !current_taffy_container.first_frame,
+                APPEAR_ANIMATION_TIME,
+            );
+            let progress = egui::emath::easing::cubic_out(progress);
+            if let AppearAnimation::Slide(offset) = animation {
+                current_taffy_container.animation_offset += offset * (1. - progress);
+            }
+            progress
+        });
 
         let stored_id = self.current_id;
         let stored_node = self.current_node;
@@ -399,6 +1229,10 @@ impl Tui {
         self.current_node_index = 0;
         self.current_rect = self.taffy_container.full_container();
 
+        if let Some(node_data) = self.state.id_to_node_id.get_mut(&id) {
+            node_data.rect = self.current_rect;
+        }
+
         let mut ui_builder = egui::UiBuilder::new()
             .id_salt(id.with("_ui"))
             // This does not set clipping, therefore we can still paint outside child ui
@@ -409,8 +1243,36 @@ impl Tui {
         ui_builder.layout = layout;
         ui_builder.disabled = disabled;
 
+        let sticky_directions = sticky.or([sticky_to.x.is_some(), sticky_to.y.is_some()]);
+        if sticky_directions.any() || sticky_header {
+            // Sticky nodes cover scrolled content, they must be painted and hit tested above it
+            ui_builder = ui_builder.layer_id(self.sticky_layer(sticky_directions.all()));
+        }
+
         let mut child_ui = self.ui.new_child(ui_builder);
         child_ui.expand_to_include_rect(full_container_without_border);
+        if hidden {
+            // Node without display takes no space, hide its content
+            child_ui.set_invisible();
+        }
+        if let Some(progress) = appear_progress {
+            child_ui.multiply_opacity(progress);
+        }
+        if let Some((from, to)) = scroll_fade {
+            // Scroll offset of the nearest scroll ancestor
+            let offset = -self.last_scroll_offset.y;
+            let opacity = match from == to {
+                true => (offset >= to) as u8 as f32,
+                false => egui::remap_clamp(offset, from..=to, 0.0..=1.),
+            };
+            if opacity <= 0. {
+                child_ui.set_invisible();
+            }
+            child_ui.multiply_opacity(opacity);
+        }
+        if let Some((origin, revealed)) = reveal {
+            self.reveal_clip(&mut child_ui, id, origin, revealed);
+        }
 
         if let Some(wrap_mode) = wrap_mode {
             if child_ui.style().wrap_mode != Some(wrap_mode) {
@@ -418,11 +1280,36 @@ impl Tui {
             }
         }
 
+        // Widgets registered before node content, see `register_tab_stop`
+        let widgets_before = tab_index.map(|_| layer_widget_count(&child_ui));
+
+        // Corner radius is scaled only for background, node content keeps egui visuals
+        let content_style = corner_radius_fraction.map(|fraction| {
+            let content_style = child_ui.style().clone();
+            let size = self.taffy_container.full_container().size().min_elem();
+            let corner_radius = egui::CornerRadius::from((fraction * size).max(0.));
+            let widgets = &mut child_ui.style_mut().visuals.widgets;
+            for visuals in [
+                &mut widgets.noninteractive,
+                &mut widgets.inactive,
+                &mut widgets.hovered,
+                &mut widgets.active,
+                &mut widgets.open,
+            ] {
+                visuals.corner_radius = corner_radius;
+            }
+            content_style
+        });
+
         let mut bg = match background_draw.simulate_execution_dyn() {
             Some(val) => val,
             None => background_draw.draw_dyn(&mut child_ui, &self.taffy_container),
         };
 
+        if let Some(content_style) = content_style {
+            child_ui.set_style(content_style);
+        }
+
         let fg = {
             let mut scroll_in_directions = egui::Vec2b::FALSE;
             match overflow_style.y {
@@ -442,7 +1329,7 @@ impl Tui {
                 }
             }
 
-            match overflow_style.y {
+            match overflow_style.x {
                 taffy::Overflow::Visible => {
                     // Do nothing
                 }
@@ -460,28 +1347,81 @@ impl Tui {
                 }
             }
 
+            let pull = pull_to_refresh
+                .filter(|_| scroll_in_directions.y)
+                .map(|threshold| {
+                    // Content can be pulled down only while it is scrolled to the top
+                    let at_top = self
+                        .state
+                        .id_to_node_id
+                        .get(&id)
+                        .map_or(true, |node_data| node_data.scroll_offset.y >= -0.5);
+                    let rect = full_container_without_border;
+                    let pull = update_pull_to_refresh(&child_ui, id, rect, at_top, threshold);
+                    (pull, threshold)
+                });
+
             if scroll_in_directions.any() {
-                let scroll = egui::ScrollArea::new(scroll_in_directions)
+                // Same as egui default, keeps scroll positions stored by earlier versions
+                let scroll_salt = "scroll_area";
+                if let Some(node_data) = self.state.id_to_node_id.get_mut(&id) {
+                    node_data.scroll_state_id =
+                        Some(child_ui.make_persistent_id(egui::Id::new(scroll_salt)));
+                }
+
+                let mut scroll_area = egui::ScrollArea::new(scroll_in_directions)
+                    .id_salt(scroll_salt)
+                    .drag_to_scroll(drag_to_scroll)
+                    .scroll_bar_visibility(scroll_bar_visibility);
+                let requested_offset = child_ui.ctx().data_mut(|data| {
+                    data.remove_temp::<egui::Vec2>(id.with("requested_scroll_offset"))
+                });
+                if let Some(offset) = requested_offset {
+                    // Offset of not scrolled direction is not clamped by scroll area
+                    if scroll_in_directions.x {
+                        scroll_area = scroll_area.horizontal_scroll_offset(offset.x);
+                    }
+                    if scroll_in_directions.y {
+                        scroll_area = scroll_area.vertical_scroll_offset(offset.y);
+                    }
+                }
+                let scroll = scroll_area
                     .min_scrolled_width(full_container_without_border.width())
                     .max_width(full_container_without_border.width())
                     .min_scrolled_height(full_container_without_border.height())
                     .max_height(full_container_without_border.height())
                     .show(&mut child_ui, |ui| {
+                        // Taffy content size and child locations are relative to node border box,
+                        // scroll area content starts inside of the border
+                        let layout = &self.taffy_container.layout;
+                        let border = egui::Vec2::new(layout.border.left, layout.border.top);
+
                         // Allocate expected size for scroll area to correctly calculate inner size
-                        let content_size = self.taffy_container.layout.content_size;
+                        let content_size = layout.content_size;
                         ui.set_min_size(
-                            egui::Vec2::new(content_size.width, content_size.height)
+                            (egui::Vec2::new(content_size.width, content_size.height) - border)
                                 .max(egui::Vec2::ZERO),
                         );
 
                         let mut rect = ui.min_rect();
+                        rect.min -= border;
                         let mut offset = rect.min - self.current_rect.min;
+                        if let Some(node_data) = self.state.id_to_node_id.get_mut(&id) {
+                            node_data.scroll_offset = offset;
+                        }
+                        update_scroll_velocity(ui.ctx(), id, -offset);
+
+                        // Pulled content is moved down past the top edge
+                        if let Some((pull, _)) = pull {
+                            rect = rect.translate(egui::vec2(0., pull));
+                        }
 
                         let stored_viewport = self.current_viewport;
                         let stored_viewport_content = self.current_viewport_content;
 
                         self.current_viewport = self.current_rect;
                         self.current_viewport_content = rect;
+                        self.scroll_ancestors.push((id, offset));
                         std::mem::swap(&mut self.last_scroll_offset, &mut offset);
                         std::mem::swap(&mut self.current_rect, &mut rect);
                         std::mem::swap(ui, &mut self.ui);
@@ -491,11 +1431,20 @@ impl Tui {
                         std::mem::swap(ui, &mut self.ui);
                         std::mem::swap(&mut self.current_rect, &mut rect);
                         std::mem::swap(&mut self.last_scroll_offset, &mut offset);
+                        self.scroll_ancestors.pop();
                         self.current_viewport_content = stored_viewport_content;
                         self.current_viewport = stored_viewport;
 
                         resp
                     });
+                if let Some((pull, threshold)) = pull {
+                    paint_pull_to_refresh(
+                        &mut child_ui,
+                        full_container_without_border,
+                        pull,
+                        threshold,
+                    );
+                }
                 scroll.inner
             } else {
                 std::mem::swap(&mut child_ui, &mut self.ui);
@@ -505,17 +1454,26 @@ impl Tui {
             }
         };
 
-        let current_cnt = self.state.taffy_tree.child_count(node_id);
-        if current_cnt > self.current_node_index {
-            self.state
-                .taffy_tree
-                .remove_children_range(node_id, self.current_node_index..current_cnt)
-                .unwrap();
+        if let (Some(tab_index), Some(widgets_before)) = (tab_index, widgets_before) {
+            self.register_tab_stop(tab_index, widgets_before);
         }
 
-        self.current_id = stored_id;
-        self.current_node = stored_node;
-        self.current_node_index = stored_current_node_index;
+        let state = self.state.deref_mut();
+        let current_cnt = state.taffy_tree.child_count(node_id);
+        if current_cnt > self.current_node_index {
+            taffy_result(
+                state
+                    .taffy_tree
+                    .remove_children_range(node_id, self.current_node_index..current_cnt),
+                &mut state.layout_error,
+                "remove_children_range",
+                self.current_id,
+            );
+        }
+
+        self.current_id = stored_id;
+        self.current_node = stored_node;
+        self.current_node_index = stored_current_node_index;
         self.current_rect = stored_current_rect;
         self.taffy_container = stored_taffy_container;
 
@@ -538,14 +1496,34 @@ impl Tui {
     /// Add egui user interface as child node in the Tui
     fn add_container_dyn<T>(
         &mut self,
-        params: TuiBuilderParams,
+        mut params: TuiBuilderParams,
         content: StackBoxDynFnOnceEguiUiContainer<T>,
     ) -> T {
+        // Baseline of measured leaf can not be reported to taffy, baseline aligned node
+        // is laid out as container of nodes that end at content baseline
+        // (see `Tui::add_baseline_nodes`). Only fields left at their default values are
+        // changed to stack these nodes in a column.
+        let baseline_aligned = self.is_baseline_aligned(&params);
+        if baseline_aligned {
+            let style = params.style.get_or_insert_with(Style::default);
+            if style.flex_direction == taffy::FlexDirection::Row {
+                style.flex_direction = taffy::FlexDirection::Column;
+            }
+            if style.align_items.is_none() {
+                style.align_items = Some(taffy::AlignItems::Stretch);
+            }
+        }
+
+        let limit_growth_to_viewport = params.limit_growth_to_viewport;
         let fg_bg = self.add_child(params, (), |tui, _| {
             let taffy_container = &tui.taffy_container;
 
-            let mut ui_builder = UiBuilder::new()
-                .max_rect(taffy_container.full_container_without_border_and_padding());
+            let mut max_rect = taffy_container.full_container_without_border_and_padding();
+            if taffy_container.first_frame {
+                // Measure content at width that node is expected to get
+                max_rect.set_width(taffy_container.available_width());
+            }
+            let mut ui_builder = UiBuilder::new().max_rect(max_rect);
             if taffy_container.first_frame {
                 ui_builder = ui_builder.sizing_pass().invisible();
             }
@@ -568,12 +1546,20 @@ impl Tui {
                 min_size,
                 max_size,
                 infinite: resp.infinite,
+                infinite_limit: limit_growth_to_viewport.then(|| tui.current_viewport.size()),
             };
-            if tui.state.taffy_tree.get_node_context(nodeid) != Some(&new_content) {
-                tui.state
-                    .taffy_tree
-                    .set_node_context(nodeid, Some(new_content))
-                    .unwrap();
+            let state = tui.state.deref_mut();
+            if state.taffy_tree.get_node_context(nodeid) != Some(&new_content) {
+                taffy_result(
+                    state.taffy_tree.set_node_context(nodeid, Some(new_content)),
+                    &mut state.layout_error,
+                    "set_node_context",
+                    tui.current_id,
+                );
+            }
+
+            if let Some(baseline) = resp.first_baseline.filter(|_| baseline_aligned) {
+                tui.add_baseline_nodes(new_content, baseline);
             }
 
             resp.inner
@@ -582,6 +1568,50 @@ impl Tui {
         fg_bg.main
     }
 
+    /// Is node added with given parameters aligned to baseline
+    /// (`align_self` or parent `align_items` is [`taffy::AlignItems::Baseline`])
+    fn is_baseline_aligned(&self, params: &TuiBuilderParams) -> bool {
+        let own_align = params.style.as_ref().and_then(|style| style.align_self);
+        own_align.or_else(|| self.current_style().align_items) == Some(taffy::AlignItems::Baseline)
+    }
+
+    /// Add children to current node that report content baseline to taffy
+    ///
+    /// Taffy uses bottom edge of measured leaf as its baseline and first child baseline
+    /// as container baseline. Content is represented by node that ends at the baseline
+    /// followed by spacer for the remaining content height.
+    fn add_baseline_nodes(&mut self, content: Context, baseline: f32) {
+        let descent = (content.min_size.y - baseline).max(0.);
+        let mut above = content;
+        above.min_size.y -= descent;
+        above.max_size.y = (above.max_size.y - descent).max(above.min_size.y);
+
+        self.tui()
+            .id(tid("first_baseline"))
+            .ui_manual(|_ui, _params| TuiContainerResponse {
+                inner: (),
+                min_size: above.min_size,
+                intrinsic_size: None,
+                max_size: above.max_size,
+                infinite: above.infinite,
+                first_baseline: None,
+            });
+
+        let size = Size {
+            width: length(0.),
+            height: length(descent),
+        };
+        self.tui()
+            .id(tid("baseline_descent"))
+            .style(taffy::Style {
+                min_size: size,
+                size,
+                max_size: size,
+                ..Default::default()
+            })
+            .add_empty();
+    }
+
     /// Add scroll area egui Ui to the taffy layout
     fn ui_scroll_area_ext<T>(
         &mut self,
@@ -601,26 +1631,22 @@ impl Tui {
             height: Dimension::Length(0.),
         };
         if let Some(limit) = limit {
-            style.max_size.height = Dimension::Length(self.root_rect.height() * limit);
-            style.max_size.width = Dimension::Length(self.root_rect.width() * limit);
+            // Max size provided in the style takes precedence over the limit
+            if style.max_size.height == Dimension::Auto {
+                style.max_size.height = Dimension::Length(self.root_rect.height() * limit);
+            }
+            if style.max_size.width == Dimension::Auto {
+                style.max_size.width = Dimension::Length(self.root_rect.width() * limit);
+            }
         }
 
         self.tui().params(params).add(|tui| {
-            let layout = *tui
-                .state
-                .taffy_tree
-                .layout(tui.current_node.unwrap())
-                .unwrap();
+            let layout = tui.state.layout(tui.current_node.unwrap());
 
             tui.add_container(
                 TuiBuilderParams {
                     id: "inner".into(),
-                    style: None,
-                    disabled: false,
-                    wrap_mode: None,
-                    egui_style: None,
-                    layout: None,
-                    sticky: egui::Vec2b::FALSE,
+                    ..Default::default()
                 },
                 |ui, _params| {
                     let mut real_min_size = None;
@@ -648,18 +1674,54 @@ impl Tui {
                         y: potential_frame_size.y,
                     };
 
-                    TuiContainerResponse {
-                        inner: scroll_area.inner,
-                        min_size: real_min_size.unwrap_or(max_size),
-                        intrinsic_size: None,
+                    TuiContainerResponse::new(
+                        scroll_area.inner,
+                        real_min_size.unwrap_or(max_size),
                         max_size,
-                        infinite: egui::Vec2b::FALSE,
-                    }
+                    )
                 },
             )
         })
     }
 
+    /// Add child node for every item, child node id is based on item key
+    ///
+    /// Unlike [`TuiId::Auto`], ids do not shift when items are filtered or reordered, therefore
+    /// surviving children keep their layout cache and egui state (scroll, expansion, etc.).
+    ///
+    /// Keys must be unique between items.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// struct Item {
+    ///     key: u64,
+    ///     name: String,
+    /// }
+    ///
+    /// # fn example(ui: &mut egui::Ui, items: &[Item]) {
+    /// tui(ui, "items").show(|tui| {
+    ///     tui.keyed_children(|item| item.key, items, |tui, item| {
+    ///         tui.label(&item.name);
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    pub fn keyed_children<I, K>(
+        &mut self,
+        key_fn: impl Fn(&I::Item) -> K,
+        items: I,
+        mut render: impl FnMut(TuiBuilder, I::Item),
+    ) where
+        I: IntoIterator,
+        K: std::hash::Hash,
+    {
+        for item in items {
+            let id = tid(("keyed", key_fn(&item)));
+            render(self.tui().id(id), item);
+        }
+    }
+
     /// Check if tui layout has changed, recalculate if necessary and trigger
     /// request discard for egui to redraw the UI
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
@@ -674,112 +1736,151 @@ impl Tui {
 
         // Remove all unused nodes
         let state = self.state.deref_mut();
-        state.id_to_node_id.retain(|_k, node_data| {
+        let culled_nodes = std::mem::take(&mut state.culled_nodes);
+        state.id_to_node_id.retain(|id, node_data| {
             if node_data.keep {
                 node_data.keep = false;
                 return true;
             }
 
-            // Remove unused node
             let node_id = node_data.node_id;
+
+            // Content of culled nodes was not built in this frame, keep their descendants
+            if !culled_nodes.is_empty() {
+                let mut parent = state.taffy_tree.parent(node_id);
+                while let Some(parent_id) = parent {
+                    if culled_nodes.contains(&parent_id) {
+                        return true;
+                    }
+                    parent = state.taffy_tree.parent(parent_id);
+                }
+            }
+
+            // Remove unused node
+            state.node_id_to_id.remove(&node_id);
             if let Some(parent) = state.taffy_tree.parent(node_id) {
-                state.taffy_tree.remove_child(parent, node_id).unwrap();
+                taffy_result(
+                    state.taffy_tree.remove_child(parent, node_id),
+                    &mut state.layout_error,
+                    "remove_child",
+                    id,
+                );
             }
-            state.taffy_tree.remove(node_id).unwrap();
+            taffy_result(
+                state.taffy_tree.remove(node_id),
+                &mut state.layout_error,
+                "remove",
+                id,
+            );
             false
         });
 
         let taffy = &mut state.taffy_tree;
 
-        if taffy.dirty(current_node).unwrap() || state.last_size != root_rect.size() {
-            // let ctx = self.ui.ctx();
-
-            state.last_size = root_rect.size();
-            taffy
-                .compute_layout_with_measure(
-                    current_node,
-                    available_space,
-                    |_known_size: Size<Option<f32>>,
-                     available_space: Size<AvailableSpace>,
-                     _id,
-                     context,
-                     _style|
-                     -> Size<f32> {
-                        let context = context.copied().unwrap_or(Context {
-                            min_size: egui::Vec2::ZERO,
-                            max_size: egui::Vec2::ZERO,
-                            infinite: egui::Vec2b::FALSE,
-                        });
+        let pass_index = self.ui.ctx().output(|output| output.num_completed_passes);
+        if pass_index == 0 {
+            state.recalculations_in_frame = 0;
+        }
 
-                        let Context {
-                            mut min_size,
-                            mut max_size,
-                            infinite,
-                        } = context;
+        let dirty = taffy_result(
+            taffy.dirty(current_node),
+            &mut state.layout_error,
+            "dirty",
+            self.main_id,
+        );
+        let resized = state.last_size != root_rect.size()
+            || state.last_available_space != Some(available_space)
+            || state.last_pixel_rounding != state.pixel_rounding;
+        let mut dirty = dirty.unwrap_or(true);
+
+        let time = self.ui.ctx().input(|input| input.time);
+        // Passes that converge layout recalculated in this frame are not debounced
+        if dirty && !resized && state.recalculations_in_frame == 0 {
+            if let (Some(debounce), Some(last_layout_time)) =
+                (self.relayout_debounce, state.last_layout_time)
+            {
+                let remaining = last_layout_time + debounce.as_secs_f64() - time;
+                if remaining > 0. {
+                    // Tree stays dirty, changes until then are applied in one recalculation
+                    self.ui.ctx().request_repaint_after_secs(remaining as f32);
+                    dirty = false;
+                }
+            }
+        }
 
-                        // if scroll_area {
-                        //     min_size = egui::Vec2::ZERO;
-                        // }
+        if dirty || resized {
+            // let ctx = self.ui.ctx();
 
-                        if min_size.any_nan() {
-                            min_size = egui::Vec2::ZERO;
-                        }
-                        if max_size.any_nan() {
-                            max_size = root_rect.size();
-                        }
+            state.last_layout_time = Some(time);
+            state.last_size = root_rect.size();
+            state.last_available_space = Some(available_space);
+            state.last_pixel_rounding = state.pixel_rounding;
+
+            state.recalculations_in_frame += 1;
+            let converged = state.recalculations_in_frame <= MAX_RECALCULATIONS_PER_FRAME;
+            if !converged {
+                // Layout changes on every pass, report changed nodes
+                let changed_nodes: Vec<egui::Id> = state
+                    .id_to_node_id
+                    .iter()
+                    .filter(|(_, node_data)| {
+                        let node_id = node_data.node_id;
+                        taffy.dirty(node_id).unwrap_or(false)
+                            && taffy.children(node_id).is_ok_and(|children| {
+                                children
+                                    .iter()
+                                    .all(|child| !taffy.dirty(*child).unwrap_or(false))
+                            })
+                    })
+                    .map(|(id, _)| *id)
+                    .collect();
+
+                log::warn!(
+                    "Taffy layout did not converge after {} recalculations in one frame, \
+                     skipping request discard. Changed nodes: {:?}",
+                    MAX_RECALCULATIONS_PER_FRAME,
+                    changed_nodes
+                );
+            }
 
-                        let max_size = egui::Vec2 {
-                            x: if infinite.x {
-                                root_rect.width()
-                            } else {
-                                max_size.x
-                            },
-                            y: if infinite.y {
-                                root_rect.height()
-                            } else {
-                                max_size.y
-                            },
-                        };
-
-                        let width = match available_space.width {
-                            AvailableSpace::Definite(num) => {
-                                num.clamp(min_size.x, max_size.x.max(min_size.x))
-                            }
-                            AvailableSpace::MinContent => min_size.x,
-                            AvailableSpace::MaxContent => max_size.x,
-                        };
-                        let height = match available_space.height {
-                            AvailableSpace::Definite(num) => {
-                                num.clamp(min_size.y, max_size.y.max(min_size.y))
-                            }
-                            AvailableSpace::MinContent => min_size.y,
-                            AvailableSpace::MaxContent => max_size.y,
-                        };
-
-                        #[allow(clippy::let_and_return)]
-                        let final_size = Size { width, height };
-
-                        // println!(
-                        //     "{:?} {:?} {:?} {:?} {:?} {:?}",
-                        //     _id, min_size, max_size, available_space, final_size, _known_size,
-                        // );
-
-                        final_size
-                    },
-                )
-                .unwrap();
+            let result = taffy.compute_layout_with_measure(
+                current_node,
+                available_space,
+                |known_size, available_space, _id, context, style| {
+                    measure_leaf(
+                        known_size,
+                        available_space,
+                        context,
+                        style,
+                        root_rect.size(),
+                    )
+                },
+            );
+            taffy_result(
+                result,
+                &mut state.layout_error,
+                "compute_layout_with_measure",
+                self.main_id,
+            );
             // taffy.print_tree(current_node);
 
             log::trace!("Taffy recalculation done!");
-            self.ui.ctx().request_discard("Taffy recalculation");
+            if converged {
+                self.ui.ctx().request_discard("Taffy recalculation");
+            }
         }
 
         TaffyContainerUi {
             parent_rect: root_rect,
-            layout: *self.state.layout(current_node),
+            layout: self.state.layout(current_node),
             first_frame: false,
+            first_frame_width: 0.,
             sticky: egui::Vec2b::FALSE,
+            sticky_to_offset: egui::Vec2::ZERO,
+            sticky_header_shift: 0.,
+            animation_offset: egui::Vec2::ZERO,
             last_scroll_offset: egui::Vec2::ZERO,
+            pixel_snap: self.pixel_snap,
         }
     }
 
@@ -846,6 +1947,43 @@ impl Tui {
         self.current_id
     }
 
+    /// Size of one character cell of egui monospace font (glyph width x row height)
+    ///
+    /// Glyph width is rounded to physical pixels the same way as egui text layout
+    /// advances glyphs, therefore `n` cells match width of `n` characters.
+    pub fn monospace_cell_size(&self) -> egui::Vec2 {
+        let font_id = egui::TextStyle::Monospace.resolve(self.ui.style());
+        let (glyph_width, row_height) = self
+            .ui
+            .fonts(|fonts| (fonts.glyph_width(&font_id, 'M'), fonts.row_height(&font_id)));
+        let pixels_per_point = self.ui.ctx().pixels_per_point();
+        let glyph_width = (glyph_width * pixels_per_point).round() / pixels_per_point;
+        egui::Vec2::new(glyph_width, row_height)
+    }
+
+    /// Size of text laid out with wrapping at `max_width` (using body text style by default)
+    ///
+    /// Unlike label measurement during layout, text is always wrapped at given width
+    /// (regardless of egui wrap mode), therefore wrapped height is known ahead of layout.
+    ///
+    /// ```
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let text = "Tooltip text that does not fit on a single line of given width";
+    /// // Height of tooltip is known before it is laid out
+    /// let size = tui.measure_wrapped(text, 100.);
+    /// # }
+    /// ```
+    pub fn measure_wrapped(&self, text: impl Into<egui::WidgetText>, max_width: f32) -> egui::Vec2 {
+        text.into()
+            .into_galley(
+                &self.ui,
+                Some(egui::TextWrapMode::Wrap),
+                max_width,
+                egui::TextStyle::Body,
+            )
+            .size()
+    }
+
     /// Last viewport rect (Full tui layout or last scrollable element)
     #[inline]
     pub fn current_viewport(&self) -> egui::Rect {
@@ -858,6 +1996,593 @@ impl Tui {
         self.current_viewport_content
     }
 
+    /// Read last known response of child node background interaction
+    /// (e.g. [`TuiBuilderLogic::clickable`]) before child node is added
+    fn read_background_response(&self, id: egui::Id) -> Option<egui::Response> {
+        // Matches child ui id in `add_child_dyn` (`UiBuilder::id_salt` hashes salt into id)
+        // and background interaction id
+        let ui_id = self.ui.id().with(egui::Id::new(id.with("_ui")));
+        let bg_id = ui_id.with("bg");
+        self.ui.ctx().read_response(bg_id)
+    }
+
+    /// Retrieve screen rect of node (including border) with given resolved id
+    /// (see [`Tui::current_id`]) after layout
+    ///
+    /// Rect reflects final node position including scroll offsets. If node has not been
+    /// added yet in this frame, rect from the last frame is returned.
+    /// Useful to align overlays to specific widget (e.g. onboarding highlight).
+    pub fn node_rect(&self, id: egui::Id) -> Option<egui::Rect> {
+        self.state
+            .id_to_node_id
+            .get(&id)
+            .map(|node_data| node_data.rect)
+            .filter(|rect| rect.is_finite())
+    }
+
+    /// Call `f` with new node size if size of node with given resolved id
+    /// (see [`Tui::current_id`]) changed since the last call
+    ///
+    /// Size of [`Tui::node_rect`] is compared to size stored in egui memory, `f` is also called
+    /// when node size becomes known for the first time. Call after node is added to react to
+    /// size of the current frame (e.g. to rebuild cached chart geometry).
+    /// Returns `true` if size changed.
+    pub fn on_resize(&mut self, id: egui::Id, f: impl FnOnce(egui::Vec2)) -> bool {
+        let Some(size) = self.node_rect(id).map(|rect| rect.size()) else {
+            return false;
+        };
+
+        let size_id = id.with("on_resize");
+        let last_size = self
+            .ui
+            .ctx()
+            .data(|data| data.get_temp::<egui::Vec2>(size_id));
+        if last_size == Some(size) {
+            return false;
+        }
+
+        self.ui
+            .ctx()
+            .data_mut(|data| data.insert_temp(size_id, size));
+        f(size);
+        true
+    }
+
+    /// Retrieve sizes measured for leaf node with given resolved id (see [`Tui::current_id`])
+    ///
+    /// Returns minimal size (min content), maximal size (max content) and directions in which
+    /// leaf can grow infinitely, as used in taffy layout calculation.
+    /// Returns `None` for unknown nodes and nodes with children.
+    /// Useful to debug why a node has its size.
+    ///
+    /// ```
+    /// use egui_taffy::{TuiBuilderLogic, TuiId};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let id = egui::Id::new("title");
+    /// tui.id(TuiId::Unique(id)).label("Title");
+    /// // Min content, max content size and infinite growth used by taffy
+    /// let sizes = tui.node_context(id);
+    /// # }
+    /// ```
+    pub fn node_context(&self, id: egui::Id) -> Option<(egui::Vec2, egui::Vec2, egui::Vec2b)> {
+        let node_data = self.state.id_to_node_id.get(&id)?;
+        let context = self.state.taffy_tree.get_node_context(node_data.node_id)?;
+        Some((context.min_size, context.max_size, context.infinite))
+    }
+
+    /// Natural aspect ratio (width / height) of node with given resolved id
+    /// (see [`Tui::current_id`]) laid out at min-content size
+    ///
+    /// Subtree is copied and laid out headlessly (see [`TaffyState::compute_layout`]) with
+    /// node styles and leaf sizes measured so far, tui layout is not changed. Call after node
+    /// is added to measure its content of the current frame.
+    /// Useful to fit layout into fixed aspect container (e.g. preview pane).
+    /// Returns `None` if node is not known, can not be measured or has zero height.
+    ///
+    /// ```
+    /// use egui_taffy::{TuiBuilderLogic, TuiId};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let id = egui::Id::new("preview");
+    /// tui.id(TuiId::Unique(id)).add(|tui| {
+    ///     tui.heading("Preview");
+    ///     tui.label("Content");
+    /// });
+    /// // Width / height of preview content
+    /// let aspect = tui.measure_aspect(id);
+    /// # }
+    /// ```
+    pub fn measure_aspect(&mut self, id: egui::Id) -> Option<f32> {
+        let node_id = self.state.id_to_node_id.get(&id)?.node_id;
+
+        match self.measure_min_content(node_id) {
+            Ok(size) => Some(size.width / size.height).filter(|aspect| aspect.is_finite()),
+            Err(err) => {
+                log::warn!("Failed to measure aspect of {:?}: {}", id, err);
+                None
+            }
+        }
+    }
+
+    /// Min-content size of the whole tui (root node laid out with min-content available space)
+    ///
+    /// Smallest size of tui at which content does not overflow (text wraps at every word,
+    /// flexible nodes shrink). Measured like [`Tui::measure_aspect`], call at the end of tui
+    /// closure to measure content of the current frame. Useful to limit window size with
+    /// [`egui::ViewportCommand::MinInnerSize`], see `min_window_size` example.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "window content").reserve_available_space().show(|tui| {
+    ///     tui.label("Content");
+    ///     // Do not let window shrink below content size
+    ///     let min_size = tui.min_content_size();
+    ///     tui.egui_ctx()
+    ///         .send_viewport_cmd(egui::ViewportCommand::MinInnerSize(min_size));
+    /// });
+    /// # }
+    /// ```
+    pub fn min_content_size(&self) -> egui::Vec2 {
+        let taffy = &self.state.taffy_tree;
+        let mut root = self.current_node();
+        while let Some(parent) = taffy.parent(root) {
+            root = parent;
+        }
+
+        match self.measure_min_content(root) {
+            Ok(size) => egui::Vec2::new(size.width, size.height),
+            Err(err) => {
+                log::warn!("Failed to measure min-content size of tui: {}", err);
+                egui::Vec2::ZERO
+            }
+        }
+    }
+
+    /// Size of node laid out headlessly with min-content available space
+    fn measure_min_content(&self, node_id: NodeId) -> taffy::TaffyResult<Size<f32>> {
+        fn copy_subtree(
+            source: &TaffyTree<Context>,
+            target: &mut TaffyTree<Context>,
+            node_id: NodeId,
+        ) -> taffy::TaffyResult<NodeId> {
+            let style = source.style(node_id)?.clone();
+            let children = source
+                .children(node_id)?
+                .into_iter()
+                .map(|child| copy_subtree(source, target, child))
+                .collect::<taffy::TaffyResult<Vec<_>>>()?;
+            let copy = target.new_with_children(style, &children)?;
+            if let Some(context) = source.get_node_context(node_id) {
+                target.set_node_context(copy, Some(*context))?;
+            }
+            Ok(copy)
+        }
+
+        let mut headless = TaffyState::new();
+        let root = copy_subtree(&self.state.taffy_tree, headless.taffy_tree_mut(), node_id)?;
+        headless.compute_layout(root, Size::MIN_CONTENT, self.root_rect.size())?;
+        Ok(headless.taffy_tree().layout(root)?.size)
+    }
+
+    /// Select children of current node by dragging selection rectangle (rubber band) over them
+    ///
+    /// Drag is sensed on the whole current node area. Call this before adding children,
+    /// so that interactive children keep their own interaction and drag starts on empty space.
+    /// Selection rectangle is painted above node content.
+    ///
+    /// While dragging (including the frame when drag is released) returns selection
+    /// rectangle and ids of children (see [`Tui::node_rect`]) whose last layout rect
+    /// intersects it, otherwise returns `None`.
+    pub fn rubber_band_selection(&mut self) -> Option<(egui::Rect, Vec<egui::Id>)> {
+        let id = self.current_id.with("rubber_band");
+        let response = self.ui.interact(
+            self.taffy_container.full_container(),
+            id,
+            egui::Sense::drag(),
+        );
+
+        let ctx = self.ui.ctx().clone();
+        if response.drag_started() {
+            if let Some(origin) = ctx.input(|input| input.pointer.press_origin()) {
+                ctx.data_mut(|data| data.insert_temp(id, origin));
+            }
+        }
+        if !response.dragged() && !response.drag_stopped() {
+            return None;
+        }
+
+        let origin = ctx.data(|data| data.get_temp::<Pos2>(id))?;
+        if response.drag_stopped() {
+            ctx.data_mut(|data| data.remove::<Pos2>(id));
+        }
+        let pointer = response
+            .interact_pointer_pos()
+            .or_else(|| ctx.pointer_latest_pos())?;
+        let selection = egui::Rect::from_two_pos(origin, pointer);
+
+        // Paint in sublayer to stay above node content added after this call
+        let layer_id = egui::LayerId::new(self.ui.layer_id().order, id);
+        ctx.set_sublayer(self.ui.layer_id(), layer_id);
+        let visuals = &self.ui.visuals().selection;
+        ctx.layer_painter(layer_id)
+            .with_clip_rect(self.ui.clip_rect())
+            .rect(
+                selection,
+                0.,
+                visuals.bg_fill.gamma_multiply(0.3),
+                visuals.stroke,
+                egui::StrokeKind::Inside,
+            );
+
+        let children = self
+            .state
+            .taffy_tree
+            .children(self.current_node())
+            .unwrap_or_default();
+        let selected = children
+            .into_iter()
+            .filter_map(|node| self.state.egui_id_of(node))
+            .filter(|id| {
+                self.node_rect(*id)
+                    .is_some_and(|rect| rect.intersects(selection))
+            })
+            .collect();
+
+        Some((selection, selected))
+    }
+
+    /// Paint lines in the middle of gutters between rows and columns of current grid node
+    ///
+    /// Uses grid track sizes from the last layout, lines are painted in the coordinate system
+    /// of node children, therefore they follow scroll offset and are clipped with node content.
+    fn paint_grid_lines(&self, stroke: egui::Stroke) {
+        let taffy::DetailedLayoutInfo::Grid(grid_info) = self
+            .state
+            .taffy_tree
+            .detailed_layout_info(self.current_node())
+        else {
+            // Not a grid node or layout is not calculated yet
+            return;
+        };
+
+        // Tracks are positioned relative to node content box (assumes start track alignment)
+        let layout = &self.taffy_container.layout;
+        let origin = self.current_rect.min
+            + egui::Vec2::new(
+                layout.border.left + layout.padding.left,
+                layout.border.top + layout.padding.top,
+            );
+
+        let (columns, column_lines) = grid_line_positions(
+            &grid_info.columns.gutters,
+            &grid_info.columns.sizes,
+            origin.x,
+        );
+        let (rows, row_lines) =
+            grid_line_positions(&grid_info.rows.gutters, &grid_info.rows.sizes, origin.y);
+
+        // Skip lines outside of visible area (e.g. in large scrolled grids)
+        let painter = self.ui.painter();
+        let clip_rect = painter.clip_rect().expand(stroke.width);
+        for x in column_lines
+            .into_iter()
+            .filter(|x| clip_rect.x_range().contains(*x))
+        {
+            painter.vline(painter.round_to_pixel_center(x), rows, stroke);
+        }
+        for y in row_lines
+            .into_iter()
+            .filter(|y| clip_rect.y_range().contains(*y))
+        {
+            painter.hline(columns, painter.round_to_pixel_center(y), stroke);
+        }
+    }
+
+    /// Did content of node with given resolved id (see [`Tui::current_id`]) overflow node box
+    /// in the last layout pass
+    ///
+    /// Compares taffy `content_size` to node size (without border), see
+    /// [`Tui::overflow_amount`]. Useful to show "more" indicators for clipped content
+    /// (`overflow: Clip` or `Hidden` nodes with limited size).
+    pub fn did_overflow(&self, id: egui::Id) -> egui::Vec2b {
+        const EPSILON: f32 = 0.5;
+
+        let overflow = self.overflow_amount(id);
+        egui::Vec2b::new(overflow.x > EPSILON, overflow.y > EPSILON)
+    }
+
+    /// Amount by which content of node with given resolved id (see [`Tui::current_id`])
+    /// overflows node box in the last layout pass (taffy `content_size` minus node size
+    /// without border, zero if content fits)
+    ///
+    /// For `overflow: Scroll` nodes this is the maximal scroll offset.
+    /// Together with [`Tui::scroll_offset`] and [`Tui::set_scroll_offset`] can be used
+    /// to draw custom scroll bars (see [`TuiBuilderLogic::scroll_bar_visibility`]).
+    pub fn overflow_amount(&self, id: egui::Id) -> egui::Vec2 {
+        let state = self.state.deref();
+        let Some(layout) = state
+            .id_to_node_id
+            .get(&id)
+            .and_then(|node_data| state.taffy_tree.layout(node_data.node_id).ok())
+        else {
+            return egui::Vec2::ZERO;
+        };
+
+        egui::Vec2::new(
+            layout.content_size.width - (layout.size.width - layout.border.right),
+            layout.content_size.height - (layout.size.height - layout.border.bottom),
+        )
+        .max(egui::Vec2::ZERO)
+    }
+
+    /// Directions in which node with given resolved id scrolls (`overflow: Scroll`)
+    fn scroll_directions(&self, id: egui::Id) -> egui::Vec2b {
+        let state = self.state.deref();
+        state
+            .id_to_node_id
+            .get(&id)
+            .and_then(|node_data| state.taffy_tree.style(node_data.node_id).ok())
+            .map_or(egui::Vec2b::FALSE, |style| egui::Vec2b {
+                x: style.overflow.x == taffy::Overflow::Scroll,
+                y: style.overflow.y == taffy::Overflow::Scroll,
+            })
+    }
+
+    /// Scroll position of `overflow: Scroll` node with given resolved id
+    /// (see [`Tui::current_id`]), zero for other nodes
+    ///
+    /// Position is read from [`egui::scroll_area::State`] of node scroll area, ranges from
+    /// zero to [`Tui::overflow_amount`]. Position in direction that does not scroll is zero.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, scroll_to_top: bool) {
+    /// let mut style = taffy::Style::default();
+    /// style.overflow.y = taffy::Overflow::Scroll;
+    /// let list = tui.id("list").style(style).add(|tui| {
+    ///     for idx in 0..100 {
+    ///         tui.label(format!("Item {idx}"));
+    ///     }
+    ///     tui.current_id()
+    /// });
+    /// if scroll_to_top && tui.scroll_offset(list).y > 0. {
+    ///     tui.set_scroll_offset(list, egui::Vec2::ZERO);
+    /// }
+    /// # }
+    /// ```
+    pub fn scroll_offset(&self, id: egui::Id) -> egui::Vec2 {
+        let directions = self.scroll_directions(id);
+        if !directions.any() {
+            return egui::Vec2::ZERO;
+        }
+        let Some(node_data) = self.state.id_to_node_id.get(&id) else {
+            return egui::Vec2::ZERO;
+        };
+
+        let offset = node_data
+            .scroll_state_id
+            .and_then(|state_id| egui::scroll_area::State::load(self.ui.ctx(), state_id))
+            .map(|scroll_state| scroll_state.offset)
+            // Stored offset is content position relative to node rect
+            .unwrap_or(egui::Vec2::ZERO - node_data.scroll_offset);
+
+        egui::Vec2::new(
+            if directions.x { offset.x } else { 0. },
+            if directions.y { offset.y } else { 0. },
+        )
+    }
+
+    /// Scroll velocity of `overflow: Scroll` node with given resolved id
+    /// (see [`Tui::current_id`]) in points per second, zero for other nodes
+    ///
+    /// Velocity is derived from scroll offset change between the last two frames in which
+    /// node was shown. Useful for velocity driven effects (parallax, tilt, motion blur).
+    pub fn scroll_velocity(&self, id: egui::Id) -> egui::Vec2 {
+        self.ui
+            .ctx()
+            .data(|data| data.get_temp::<ScrollVelocity>(id.with("scroll_velocity")))
+            .map(|state| state.velocity)
+            .unwrap_or_default()
+    }
+
+    /// Was pull to refresh gesture of `overflow: Scroll` node with given resolved id
+    /// (see [`Tui::current_id`]) released past threshold in this frame
+    ///
+    /// See [`TuiBuilderLogic::pull_to_refresh`].
+    pub fn refresh_requested(&self, id: egui::Id) -> bool {
+        let time = self.ui.ctx().input(|input| input.time);
+        self.ui
+            .ctx()
+            .data(|data| data.get_temp::<PullToRefresh>(id.with("pull_to_refresh")))
+            .is_some_and(|state| state.refresh && state.time >= time)
+    }
+
+    /// Scroll `overflow: Scroll` node with given resolved id (see [`Tui::current_id`])
+    /// to given position
+    ///
+    /// Position is written to [`egui::scroll_area::State`] of node scroll area and is visible
+    /// the next time node is shown (in the next frame if node has been already shown in this
+    /// frame). Only directions in which node scrolls are changed. Position of node that has
+    /// not been shown yet is applied when node is shown for the first time, e.g. to restore
+    /// persisted position.
+    pub fn set_scroll_offset(&mut self, id: egui::Id, offset: egui::Vec2) {
+        let ctx = self.ui.ctx().clone();
+        let scroll_state = self
+            .state
+            .id_to_node_id
+            .get(&id)
+            .and_then(|node_data| node_data.scroll_state_id)
+            .and_then(|state_id| Some((state_id, egui::scroll_area::State::load(&ctx, state_id)?)));
+
+        match scroll_state {
+            Some((state_id, mut scroll_state)) => {
+                let directions = self.scroll_directions(id);
+                if directions.x {
+                    scroll_state.offset.x = offset.x;
+                }
+                if directions.y {
+                    scroll_state.offset.y = offset.y;
+                }
+                scroll_state.store(&ctx, state_id);
+            }
+            None => {
+                ctx.data_mut(|data| data.insert_temp(id.with("requested_scroll_offset"), offset));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// Scroll the nearest `overflow: Scroll` ancestor of given node so that node is visible
+    /// and aligned inside of ancestor viewport as specified by `align`
+    ///
+    /// Viewport excludes [`TuiBuilderLogic::sticky_header`] that would cover the node. Nodes
+    /// that are sticky (or have sticky ancestor) in some direction are not scrolled in that
+    /// direction. Does nothing if node has no scroll ancestor. Uses layout from the last
+    /// layout pass, offset is applied as with [`Tui::set_scroll_offset`].
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, selected: usize) {
+    /// let mut style = taffy::Style::default();
+    /// style.overflow.y = taffy::Overflow::Scroll;
+    /// tui.style(style).add(|tui| {
+    ///     for idx in 0..100 {
+    ///         let node = tui.add(|tui| {
+    ///             tui.label(format!("Item {idx}"));
+    ///             tui.current_node()
+    ///         });
+    ///         if idx == selected {
+    ///             tui.scroll_to_node(node, egui::Align2::CENTER_CENTER);
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn scroll_to_node(&mut self, node_id: NodeId, align: egui::Align2) {
+        if let Some((scroll_id, offset)) = self.scroll_to_node_offset(node_id, align) {
+            self.set_scroll_offset(scroll_id, offset);
+        }
+    }
+
+    /// Nearest scroll ancestor of node and its offset that aligns node in ancestor viewport
+    fn scroll_to_node_offset(
+        &self,
+        node_id: NodeId,
+        align: egui::Align2,
+    ) -> Option<(egui::Id, egui::Vec2)> {
+        let state = self.state.deref();
+        let taffy = &state.taffy_tree;
+
+        let node_layout = taffy.layout(node_id).ok()?;
+        let size = egui::Vec2::new(node_layout.size.width, node_layout.size.height);
+
+        // Node position relative to border box of current ancestor
+        let mut position = egui::Vec2::ZERO;
+        let mut sticky = egui::Vec2b::FALSE;
+        let mut sticky_header_height: f32 = 0.;
+
+        let mut node = node_id;
+        loop {
+            if let Some(node_data) = state
+                .egui_id_of(node)
+                .and_then(|id| state.id_to_node_id.get(&id))
+            {
+                sticky = sticky.or(node_data.sticky);
+                sticky.y |= node_data.sticky_header;
+            }
+
+            let layout = taffy.layout(node).ok()?;
+            position += egui::Vec2::new(layout.location.x, layout.location.y);
+
+            let parent = taffy.parent(node)?;
+            // The closest sticky header above node is stuck at the top edge of viewport
+            let sticky_header = taffy
+                .children(parent)
+                .ok()?
+                .into_iter()
+                .filter(|sibling| {
+                    *sibling != node
+                        && state
+                            .egui_id_of(*sibling)
+                            .and_then(|id| state.id_to_node_id.get(&id))
+                            .is_some_and(|node_data| node_data.sticky_header)
+                })
+                .filter_map(|sibling| taffy.layout(sibling).ok())
+                .filter(|sibling_layout| sibling_layout.location.y <= layout.location.y)
+                .max_by(|a, b| a.location.y.total_cmp(&b.location.y));
+            if let Some(sticky_header) = sticky_header {
+                sticky_header_height = sticky_header_height.max(sticky_header.size.height);
+            }
+
+            let parent_id = state.egui_id_of(parent)?;
+            let directions = self.scroll_directions(parent_id);
+            if !directions.any() {
+                node = parent;
+                continue;
+            }
+
+            let directions = directions.and(!sticky);
+            if !directions.any() {
+                return None;
+            }
+
+            let layout = taffy.layout(parent).ok()?;
+            let header = if directions.y {
+                sticky_header_height
+            } else {
+                0.
+            };
+            let viewport_min = egui::Vec2::new(layout.border.left, layout.border.top + header);
+            let viewport_size = egui::Vec2::new(
+                layout.size.width - layout.border.left - layout.border.right,
+                layout.size.height - layout.border.top - layout.border.bottom - header,
+            );
+            let factor = egui::Vec2::new(align.x().to_factor(), align.y().to_factor());
+            let target = (position - viewport_min - factor * (viewport_size - size))
+                .max(egui::Vec2::ZERO)
+                .min(self.overflow_amount(parent_id));
+
+            let current = self.scroll_offset(parent_id);
+            let offset = egui::Vec2::new(
+                if directions.x { target.x } else { current.x },
+                if directions.y { target.y } else { current.y },
+            );
+            return Some((parent_id, offset));
+        }
+    }
+
+    /// Retrieve id of the first child of scroll container that intersects or is below
+    /// the top edge of container viewport
+    ///
+    /// Useful for navigation highlighting (scroll spy). `scroll_id` is resolved id of the
+    /// scroll container (see [`Tui::current_id`]), returned id is resolved id of its child.
+    /// Uses layout and scroll offset from the last frame.
+    pub fn topmost_visible_child(&self, scroll_id: egui::Id) -> Option<egui::Id> {
+        let state = self.state.deref();
+        let scroll_data = state.id_to_node_id.get(&scroll_id)?;
+        let taffy = &state.taffy_tree;
+
+        let viewport_top =
+            taffy.layout(scroll_data.node_id).ok()?.border.top - scroll_data.scroll_offset.y;
+
+        let child = taffy
+            .children(scroll_data.node_id)
+            .ok()?
+            .into_iter()
+            .find(|child| {
+                taffy
+                    .layout(*child)
+                    .is_ok_and(|layout| layout.location.y + layout.size.height > viewport_top)
+            })?;
+
+        state.egui_id_of(child)
+    }
+
     /// Retrieve current Tui node [`NodeId`]
     #[inline]
     pub fn current_node(&self) -> NodeId {
@@ -873,7 +2598,7 @@ impl Tui {
 
     /// Retrieve inner state of taffy layout
     #[inline]
-    fn taffy_state(&self) -> &TaffyState {
+    pub fn taffy_state(&self) -> &TaffyState {
         &self.state
     }
 
@@ -902,6 +2627,29 @@ pub struct Context {
     min_size: egui::Vec2,
     max_size: egui::Vec2,
     infinite: egui::Vec2b,
+    /// Size used for infinite dimensions instead of root size
+    infinite_limit: Option<egui::Vec2>,
+}
+
+impl Context {
+    /// Create leaf sizing context with known content size range
+    /// (see [`TaffyState::compute_layout`])
+    ///
+    /// Leaf is measured with `min_size` for min content and `max_size` for max content sizing.
+    pub fn new(min_size: egui::Vec2, max_size: egui::Vec2) -> Self {
+        Self {
+            min_size,
+            max_size,
+            ..Default::default()
+        }
+    }
+
+    /// Leaf can grow in given directions up to root size (e.g. progress bar, separator)
+    #[inline]
+    pub fn with_infinite(mut self, infinite: egui::Vec2b) -> Self {
+        self.infinite = infinite;
+        self
+    }
 }
 
 /// Helper to show the inner content of a container.
@@ -911,7 +2659,16 @@ pub struct TaffyContainerUi {
     parent_rect: egui::Rect,
     last_scroll_offset: egui::Vec2,
     sticky: egui::Vec2b,
+    /// Compensated scroll offset of [`TuiBuilderLogic::sticky_to`] ancestors
+    sticky_to_offset: egui::Vec2,
+    sticky_header_shift: f32,
+    /// Offset from layout position while node position change is animated
+    animation_offset: egui::Vec2,
+    /// Pixels per point of physical pixel grid to snap rects to
+    pixel_snap: Option<f32>,
     first_frame: bool,
+    /// Estimated content width of node that is not laid out yet
+    first_frame_width: f32,
 }
 
 impl Default for TaffyContainerUi {
@@ -921,7 +2678,12 @@ impl Default for TaffyContainerUi {
             parent_rect: egui::Rect::ZERO,
             last_scroll_offset: Default::default(),
             sticky: Default::default(),
+            sticky_to_offset: egui::Vec2::ZERO,
+            sticky_header_shift: 0.,
+            animation_offset: egui::Vec2::ZERO,
+            pixel_snap: None,
             first_frame: Default::default(),
+            first_frame_width: 0.,
         }
     }
 }
@@ -942,11 +2704,74 @@ fn top_left(rect: &taffy::Rect<f32>) -> taffy::Point<f32> {
     }
 }
 
+/// Content box width of node with `style` that is not laid out yet (zero if unknown)
+///
+/// Width is known if it is set in node style or if node is stretched to width of its parent
+/// content (block layout, flex column). In other layouts (flex row, grid) node width depends
+/// on size of its content and siblings.
+fn estimate_content_width(style: &Style, parent: Option<&Style>, parent_width: f32) -> f32 {
+    let resolve = |value: LengthPercentage| match value {
+        LengthPercentage::Length(length) => length,
+        LengthPercentage::Percent(percent) => percent * parent_width,
+    };
+    let resolve_auto = |value: LengthPercentageAuto| match value {
+        LengthPercentageAuto::Length(length) => length,
+        LengthPercentageAuto::Percent(percent) => percent * parent_width,
+        LengthPercentageAuto::Auto => 0.,
+    };
+    let resolve_dimension = |value: Dimension| match value {
+        Dimension::Length(length) => Some(length),
+        Dimension::Percent(percent) => Some(percent * parent_width),
+        Dimension::Auto => None,
+    };
+
+    let stretched = style.position == taffy::Position::Relative
+        && parent.is_some_and(|parent| match parent.display {
+            Display::Block => true,
+            Display::Flex => {
+                let align = style.align_self.or(parent.align_items);
+                matches!(
+                    parent.flex_direction,
+                    FlexDirection::Column | FlexDirection::ColumnReverse
+                ) && matches!(align, None | Some(AlignItems::Stretch))
+            }
+            _ => false,
+        });
+
+    let margin = resolve_auto(style.margin.left) + resolve_auto(style.margin.right);
+    let Some(mut width) =
+        resolve_dimension(style.size.width).or_else(|| stretched.then_some(parent_width - margin))
+    else {
+        return 0.;
+    };
+    if let Some(max_width) = resolve_dimension(style.max_size.width) {
+        width = width.min(max_width);
+    }
+    if let Some(min_width) = resolve_dimension(style.min_size.width) {
+        width = width.max(min_width);
+    }
+
+    let padding = resolve(style.padding.left) + resolve(style.padding.right);
+    let border = resolve(style.border.left) + resolve(style.border.right);
+    (width - padding - border).max(0.)
+}
+
+#[inline]
+fn vec2_to_size(size: egui::Vec2) -> taffy::Size<Dimension> {
+    taffy::Size {
+        width: length(size.x),
+        height: length(size.y),
+    }
+}
+
 impl TaffyContainerUi {
     /// Sticky element compensation amount based on last scrollable ancestor scroll offset
+    /// (includes [`TuiBuilderLogic::sticky_to`] ancestor offsets and shift of
+    /// [`TuiBuilderLogic::sticky_header`] nodes)
     #[inline]
     pub fn sticky_offset(&self) -> egui::Vec2 {
-        self.sticky.to_vec2() * self.last_scroll_offset
+        self.sticky.to_vec2() * self.last_scroll_offset + self.sticky_to_offset
+            - egui::Vec2::new(0., self.sticky_header_shift)
     }
 
     /// Full container size
@@ -956,6 +2781,8 @@ impl TaffyContainerUi {
     }
 
     /// Full container size
+    ///
+    /// Taffy layout location already includes `position: Relative` inset offset.
     #[inline]
     pub fn full_container_with(&self, scroll_offset: bool) -> egui::Rect {
         let layout = &self.layout;
@@ -963,26 +2790,69 @@ impl TaffyContainerUi {
             Pos2::new(layout.location.x, layout.location.y),
             egui::Vec2::new(layout.size.width, layout.size.height),
         );
-        let mut offset = self.parent_rect.min.to_vec2();
+        let mut offset = self.parent_rect.min.to_vec2() + self.animation_offset;
         if scroll_offset {
             offset += -self.sticky_offset();
         }
-        rect.translate(offset)
+        self.snap(rect.translate(offset))
     }
 
-    /// Full container rect without border
+    /// Full container rect including margins (margin box)
+    ///
+    /// Useful to align decorations drawn outside of node to space reserved by its margins.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.style(taffy::Style {
+    ///     margin: taffy::prelude::length(4.),
+    ///     ..Default::default()
+    /// })
+    /// .add_with_background_ui(
+    ///     |ui, container| {
+    ///         // Outline drawn in space reserved by node margins
+    ///         let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+    ///         let rect = container.margin_rect();
+    ///         ui.painter().rect_stroke(rect, 0., stroke, egui::StrokeKind::Inside);
+    ///     },
+    ///     |tui, _| {
+    ///         tui.label("Outlined");
+    ///     },
+    /// );
+    /// # }
+    /// ```
     #[inline]
-    pub fn full_container_without_border(&self) -> egui::Rect {
+    pub fn margin_rect(&self) -> egui::Rect {
         let layout = &self.layout;
 
-        let pos = layout.location + top_left(&layout.border);
+        let margin = top_left(&layout.margin);
+        let size = layout.size + sum_axis(&layout.margin);
+
+        let rect = egui::Rect::from_min_size(
+            Pos2::new(layout.location.x - margin.x, layout.location.y - margin.y),
+            egui::Vec2::new(size.width, size.height),
+        );
+        self.snap(rect.translate(
+            self.parent_rect.min.to_vec2() + self.animation_offset - self.sticky_offset(),
+        ))
+    }
+
+    /// Full container rect without border
+    #[inline]
+    pub fn full_container_without_border(&self) -> egui::Rect {
+        let layout = &self.layout;
+
+        let pos = layout.location + top_left(&layout.border);
         let size = layout.size - sum_axis(&layout.border);
 
         let rect = egui::Rect::from_min_size(
             Pos2::new(pos.x, pos.y),
             egui::Vec2::new(size.width, size.height),
         );
-        rect.translate(self.parent_rect.min.to_vec2() - self.sticky_offset())
+        self.snap(rect.translate(
+            self.parent_rect.min.to_vec2() + self.animation_offset - self.sticky_offset(),
+        ))
     }
 
     /// Full container rect without border and padding
@@ -997,7 +2867,18 @@ impl TaffyContainerUi {
             Pos2::new(pos.x, pos.y),
             egui::Vec2::new(size.width, size.height),
         );
-        rect.translate(self.parent_rect.min.to_vec2() - self.sticky_offset())
+        self.snap(rect.translate(
+            self.parent_rect.min.to_vec2() + self.animation_offset - self.sticky_offset(),
+        ))
+    }
+
+    /// Snap rect to physical pixel grid if [`TuiInitializer::snap_to_pixels`] is enabled
+    #[inline]
+    fn snap(&self, rect: egui::Rect) -> egui::Rect {
+        match self.pixel_snap {
+            Some(pixels_per_point) => rect.round_to_pixels(pixels_per_point),
+            None => rect,
+        }
     }
 
     /// Calculated taffy::Layout for this node
@@ -1012,6 +2893,35 @@ impl TaffyContainerUi {
         self.first_frame
     }
 
+    /// Width assigned to node content (content box width)
+    ///
+    /// Node is not laid out in the first frame, then width is estimated if it is set in node
+    /// style or node is stretched to width of its parent content (block layout, flex column),
+    /// otherwise it is zero. Content ui is created with this width, therefore content that
+    /// depends on its width (e.g. wrapped text) is measured at its final size and layout
+    /// converges in fewer passes.
+    ///
+    /// ```
+    /// use egui_taffy::{TuiBuilderLogic, TuiContainerResponse};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.ui_manual(|ui, container| {
+    ///     // Choose layout of content by width it will get
+    ///     let text = if container.available_width() < 100. { "🗑" } else { "🗑 Delete" };
+    ///     let response = ui.button(text);
+    ///     let size = response.rect.size();
+    ///     TuiContainerResponse::new(response, size, size)
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    pub fn available_width(&self) -> f32 {
+        match self.first_frame {
+            true => self.first_frame_width,
+            false => self.layout.content_box_width(),
+        }
+    }
+
     /// Parent rect that is used to calculate rect of this node
     #[inline]
     pub fn parent_rect(&self) -> egui::Rect {
@@ -1028,6 +2938,26 @@ impl TaffyContainerUi {
 /// Describes information about used space when laying out elements
 ///
 /// This information is used for taffy layout calculation logic
+///
+/// Baseline aligned widgets report their text baseline using
+/// [`TuiContainerResponse::first_baseline`]:
+///
+/// ```
+/// use egui_taffy::{TuiBuilderLogic, TuiContainerResponse};
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// // Icon with text baseline 12 points below its top edge
+/// tui.ui_manual(|ui, _params| {
+///     let size = egui::vec2(16., 16.);
+///     let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+///     ui.painter().circle_filled(rect.center(), 6., egui::Color32::GREEN);
+///     TuiContainerResponse {
+///         first_baseline: Some(12.),
+///         ..TuiContainerResponse::new(response, size, size)
+///     }
+/// });
+/// # }
+/// ```
 pub struct TuiContainerResponse<T> {
     /// Closure return value
     pub inner: T,
@@ -1041,6 +2971,33 @@ pub struct TuiContainerResponse<T> {
     pub max_size: egui::Vec2,
     /// Can widget grow to infinite size in given dimensions
     pub infinite: egui::Vec2b,
+    /// Distance from widget top edge to its first text row baseline
+    ///
+    /// Used when node is aligned to baseline (`align_self` or parent `align_items` is
+    /// [`taffy::AlignItems::Baseline`]), otherwise node bottom edge is its baseline.
+    ///
+    /// Baseline aligned node contains nodes stacked in a column that report the baseline
+    /// to taffy. If node style has default `flex_direction` (`Row`) or `align_items` (`None`),
+    /// they are set to `Column` and `Stretch`, other style fields are not changed.
+    pub first_baseline: Option<f32>,
+}
+
+impl<T> TuiContainerResponse<T> {
+    /// Create response for finite widget that can be sized between `min_size` and `max_size`
+    ///
+    /// Widget has no intrinsic size and its bottom edge is its baseline. Remaining fields can be
+    /// set with struct update syntax (`TuiContainerResponse { .., ..TuiContainerResponse::new(..) }`).
+    #[inline]
+    pub fn new(inner: T, min_size: egui::Vec2, max_size: egui::Vec2) -> Self {
+        Self {
+            inner,
+            min_size,
+            intrinsic_size: None,
+            max_size,
+            infinite: egui::Vec2b::FALSE,
+            first_baseline: None,
+        }
+    }
 }
 
 /// Return values from Main, Background closures
@@ -1072,6 +3029,88 @@ pub trait TuiWidget {
     fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response;
 }
 
+/// egui widget with default taffy measurement behavior
+///
+/// Implementing this trait implements [`TuiWidget`], widget is added with
+/// [`TuiBuilderLogic::ui_add`] as leaf node and [`TuiMeasuredWidget::measure`] adjusts space
+/// information reported by the widget (same as `transform` argument of
+/// [`TuiBuilderLogic::ui_add_manual`]). Measurement behavior is therefore specified once per
+/// widget type instead of in every call. Plain egui widgets are registered this way
+/// in `./egui_widgets.rs`.
+///
+/// By default widget min and max size is the size of widget response.
+/// Set [`TuiContainerResponse::min_size`] to let node shrink, [`TuiContainerResponse::max_size`]
+/// and [`TuiContainerResponse::infinite`] to let node grow.
+///
+/// ```
+/// use egui_taffy::{TuiBuilderLogic, TuiContainerResponse, TuiMeasuredWidget};
+///
+/// /// Color bar that fills available width
+/// struct ColorBar(egui::Color32);
+///
+/// impl egui::Widget for ColorBar {
+///     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+///         let size = egui::vec2(ui.available_width().max(16.), 16.);
+///         let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+///         ui.painter().rect_filled(rect, 2., self.0);
+///         response
+///     }
+/// }
+///
+/// impl TuiMeasuredWidget for ColorBar {
+///     fn measure(
+///         mut value: TuiContainerResponse<egui::Response>,
+///         _ui: &egui::Ui,
+///     ) -> TuiContainerResponse<egui::Response> {
+///         // Bar can shrink to 16 points and grow to any width
+///         value.min_size.x = 16.;
+///         value.intrinsic_size = None;
+///         value.infinite.x = true;
+///         value
+///     }
+/// }
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// tui.ui_add(ColorBar(egui::Color32::RED));
+/// # }
+/// ```
+pub trait TuiMeasuredWidget: egui::Widget + Sized {
+    /// Adjust space information measured from widget response
+    #[inline]
+    fn measure(
+        value: TuiContainerResponse<egui::Response>,
+        _ui: &egui::Ui,
+    ) -> TuiContainerResponse<egui::Response> {
+        value
+    }
+}
+
+impl<T: TuiMeasuredWidget> TuiWidget for T {
+    type Response = egui::Response;
+
+    #[inline]
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        tuib.ui_add_manual(|ui| ui.add(self), T::measure)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Duration of [`AppearAnimation`] in seconds
+const APPEAR_ANIMATION_TIME: f32 = 0.3;
+
+/// Animation of new node appearance (see [`TuiBuilderLogic::appear_animation`])
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AppearAnimation {
+    /// Fade in node
+    Fade,
+    /// Fade in node and slide it from given offset to its position
+    Slide(egui::Vec2),
+}
+
+/// Duration of [`TuiBuilderLogic::reveal`] animation in seconds
+const REVEAL_ANIMATION_TIME: f32 = 0.5;
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Id type to simplify defining layout node ids
@@ -1128,33 +3167,376 @@ where
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Egui taffy layout state which stores calculated taffy node layout and hiarchy
+///
+/// Failed taffy operations are logged instead of panicking, state is then reset and
+/// layout is rebuilt in the next pass.
 pub struct TaffyState {
     taffy_tree: TaffyTree<Context>,
 
     id_to_node_id: HashMap<egui::Id, NodeData>,
 
+    /// Reverse mapping of `id_to_node_id`
+    node_id_to_id: HashMap<NodeId, egui::Id>,
+
     last_size: egui::Vec2,
+    last_available_space: Option<Size<AvailableSpace>>,
+
+    pixel_rounding: bool,
+    last_pixel_rounding: bool,
+
+    recalculations_in_frame: usize,
+
+    /// Time of last layout recalculation, see [`TuiInitializer::relayout_debounce`]
+    last_layout_time: Option<f64>,
+
+    /// Taffy operation failed, layout state is reset in the next pass
+    layout_error: bool,
+
+    /// Nodes which content was skipped in this frame, see [`TuiBuilderLogic::add_culled`]
+    culled_nodes: HashSet<NodeId>,
+
+    /// Tab stops of the previous pass sorted by tab index, see [`TuiBuilderLogic::tab_index`]
+    tab_stops: Vec<(i32, egui::Id)>,
+
+    /// Tree was borrowed mutably with [`TaffyState::taffy_tree_mut`], layout state of
+    /// [`Tui`] is reset in the next pass
+    tree_modified: bool,
+}
+
+/// Number of widgets registered in ui layer in the current pass
+fn layer_widget_count(ui: &Ui) -> usize {
+    let layer_id = ui.layer_id();
+    ui.ctx()
+        .viewport(|viewport| viewport.this_pass.widgets.get_layer(layer_id).count())
+}
+
+/// Measure leaf node using its sizing [`Context`]
+///
+/// Infinite dimensions grow up to `root_size` unless context sets its own limit.
+fn measure_leaf(
+    known_size: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    context: Option<&mut Context>,
+    style: &Style,
+    root_size: egui::Vec2,
+) -> Size<f32> {
+    let context = context.copied().unwrap_or(Context {
+        min_size: egui::Vec2::ZERO,
+        max_size: egui::Vec2::ZERO,
+        infinite: egui::Vec2b::FALSE,
+        infinite_limit: None,
+    });
+
+    let Context {
+        mut min_size,
+        mut max_size,
+        infinite,
+        infinite_limit,
+    } = context;
+
+    if min_size.any_nan() {
+        min_size = egui::Vec2::ZERO;
+    }
+    if max_size.any_nan() {
+        max_size = root_size;
+    }
+
+    let infinite_limit = infinite_limit.unwrap_or(root_size);
+    let max_size = egui::Vec2 {
+        x: if infinite.x {
+            infinite_limit.x
+        } else {
+            max_size.x
+        },
+        y: if infinite.y {
+            infinite_limit.y
+        } else {
+            max_size.y
+        },
+    };
+
+    let width = match available_space.width {
+        AvailableSpace::Definite(num) => num.clamp(min_size.x, max_size.x.max(min_size.x)),
+        AvailableSpace::MinContent => min_size.x,
+        AvailableSpace::MaxContent => max_size.x,
+    };
+    // Zero, negative or non finite aspect ratio would produce invalid height
+    let aspect_ratio = style
+        .aspect_ratio
+        .filter(|aspect_ratio| aspect_ratio.is_finite() && *aspect_ratio > 0.);
+    let height = match (known_size.height, aspect_ratio) {
+        (None, Some(aspect_ratio)) => {
+            // Height follows width for nodes with aspect ratio
+            known_size.width.unwrap_or(width) / aspect_ratio
+        }
+        _ => match available_space.height {
+            AvailableSpace::Definite(num) => num.clamp(min_size.y, max_size.y.max(min_size.y)),
+            AvailableSpace::MinContent => min_size.y,
+            AvailableSpace::MaxContent => max_size.y,
+        },
+    };
+
+    Size { width, height }
+}
+
+/// Log failed taffy operation instead of panicking and mark layout state for reset
+///
+/// Layout state is rebuilt from scratch in the next pass (see [`Tui::create`]).
+fn taffy_result<T>(
+    result: taffy::TaffyResult<T>,
+    layout_error: &mut bool,
+    operation: &str,
+    node: impl std::fmt::Debug,
+) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(err) => {
+            log::error!("Taffy {operation} failed for node {node:?}: {err}");
+            *layout_error = true;
+            None
+        }
+    }
+}
+
+/// Calculate range covered by grid tracks and positions of lines between them
+///
+/// Taffy reports gutters before the first track and after the last track too,
+/// therefore `gutters` has one more element than `sizes`.
+fn grid_line_positions(gutters: &[f32], sizes: &[f32], origin: f32) -> (egui::Rangef, Vec<f32>) {
+    let mut position = origin + gutters.first().copied().unwrap_or(0.);
+    let start = position;
+    let mut lines = Vec::with_capacity(sizes.len().saturating_sub(1));
+
+    for (idx, size) in sizes.iter().enumerate() {
+        position += size;
+        if idx + 1 == sizes.len() {
+            break;
+        }
+        let gutter = gutters.get(idx + 1).copied().unwrap_or(0.);
+        lines.push(position + gutter / 2.);
+        position += gutter;
+    }
+
+    (egui::Rangef::new(start, position), lines)
+}
+
+/// Pull to refresh gesture state of scroll container stored in egui memory
+/// (see [`TuiBuilderLogic::pull_to_refresh`])
+#[derive(Clone, Copy)]
+struct PullToRefresh {
+    /// Distance by which content is pulled down past its top edge
+    pull: f32,
+    /// Content was released past threshold in the frame at `time`
+    refresh: bool,
+    /// Pointer was pressed on container (not covered by other layer or clipped)
+    pressed_inside: bool,
+    time: f64,
 }
 
+/// Resistance of pulled content, content moves slower than pointer
+const PULL_TO_REFRESH_RESISTANCE: f32 = 0.5;
+
+/// Update pull to refresh gesture of scroll container, returns current pull distance
+///
+/// Pull starts when pointer pressed inside of container is dragged down while content
+/// is scrolled to the top. Press is hit tested as egui widget, press covered by other layer
+/// (e.g. window) or clipped away is ignored. Refresh is requested when pointer is released
+/// past threshold.
+fn update_pull_to_refresh(
+    ui: &Ui,
+    id: egui::Id,
+    rect: egui::Rect,
+    at_top: bool,
+    threshold: f32,
+) -> f32 {
+    let ctx = ui.ctx();
+    let id = id.with("pull_to_refresh");
+    let time = ctx.input(|input| input.time);
+    let last = ctx.data(|data| data.get_temp::<PullToRefresh>(id));
+    if let Some(last) = last.filter(|last| last.time >= time) {
+        // Node is shown multiple times in the same frame (e.g. discarded pass)
+        return last.pull;
+    }
+
+    let (down, pressed, delta) = ctx.input(|input| {
+        let pointer = &input.pointer;
+        (
+            pointer.primary_down(),
+            pointer.primary_pressed(),
+            pointer.delta().y,
+        )
+    });
+    // Widget is registered every frame, hit test uses widget rects from previous frame
+    let contains_pointer = ui
+        .interact(rect, id, egui::Sense::hover())
+        .contains_pointer();
+    let pressed_inside = if pressed {
+        contains_pointer
+    } else {
+        last.is_some_and(|last| last.pressed_inside)
+    };
+
+    let last_pull = last.map_or(0., |last| last.pull);
+    let (pull, refresh) = if down && pressed_inside && (at_top || last_pull > 0.) {
+        let pull = (last_pull + delta * PULL_TO_REFRESH_RESISTANCE).max(0.);
+        (pull, false)
+    } else {
+        (0., last_pull >= threshold)
+    };
+    if pull > 0. || pull != last_pull {
+        ctx.request_repaint();
+    }
+
+    ctx.data_mut(|data| {
+        data.insert_temp(
+            id,
+            PullToRefresh {
+                pull,
+                refresh,
+                pressed_inside,
+                time,
+            },
+        )
+    });
+    pull
+}
+
+/// Paint pull to refresh spinner at the top of scroll container
+///
+/// Spinner slides down with pulled content and fades in until pull reaches threshold.
+fn paint_pull_to_refresh(ui: &mut Ui, rect: egui::Rect, pull: f32, threshold: f32) {
+    if pull <= 0. {
+        return;
+    }
+
+    let size = ui.spacing().interact_size.y;
+    let progress = (pull / threshold).min(1.);
+    let center = egui::pos2(rect.center().x, rect.top() + pull.min(threshold) / 2.);
+    let spinner_rect = egui::Rect::from_center_size(center, egui::Vec2::splat(size));
+
+    let mut ui = ui.new_child(UiBuilder::new().max_rect(spinner_rect));
+    ui.multiply_opacity(progress);
+    let visuals = ui.visuals().widgets.noninteractive;
+    ui.painter().circle(
+        center,
+        size * 0.75,
+        ui.visuals().window_fill,
+        visuals.bg_stroke,
+    );
+    if progress >= 1. {
+        egui::Spinner::new().size(size).paint_at(&ui, spinner_rect);
+    } else {
+        // Arc grows with pull progress
+        let radius = size / 2. - 1.;
+        let points: Vec<Pos2> = (0..=24)
+            .map(|i| {
+                let angle = -std::f32::consts::FRAC_PI_2
+                    + std::f32::consts::TAU * progress * i as f32 / 24.;
+                center + radius * egui::Vec2::angled(angle)
+            })
+            .collect();
+        ui.painter()
+            .add(egui::Shape::line(points, visuals.fg_stroke));
+    }
+}
+
+/// Scroll position of scroll container stored in egui memory to calculate scroll velocity
+#[derive(Clone, Copy)]
+struct ScrollVelocity {
+    position: egui::Vec2,
+    time: f64,
+    velocity: egui::Vec2,
+}
+
+/// Update scroll velocity of scroll container (see [`Tui::scroll_velocity`])
+fn update_scroll_velocity(ctx: &egui::Context, id: egui::Id, position: egui::Vec2) {
+    let id = id.with("scroll_velocity");
+    let time = ctx.input(|input| input.time);
+    let last = ctx.data(|data| data.get_temp::<ScrollVelocity>(id));
+
+    let velocity = match last {
+        // Node is shown multiple times in the same frame (e.g. discarded pass)
+        Some(last) if last.time >= time => return,
+        Some(last) => (position - last.position) / (time - last.time) as f32,
+        None => egui::Vec2::ZERO,
+    };
+    if velocity != egui::Vec2::ZERO {
+        // Repaint until velocity settles to zero
+        ctx.request_repaint();
+    }
+
+    ctx.data_mut(|data| {
+        data.insert_temp(
+            id,
+            ScrollVelocity {
+                position,
+                time,
+                velocity,
+            },
+        );
+    });
+}
+
+/// Maximal count of layout recalculations in one frame that request egui pass discard
+///
+/// Protects from endless passes if layout does not converge (e.g. node size changes on every pass)
+const MAX_RECALCULATIONS_PER_FRAME: usize = 8;
+
 /// Stores information about node that was identified by egui::Id
 pub struct NodeData {
     /// [`taffy::TaffyTree`] node id
     pub node_id: NodeId,
     keep: bool,
+    /// Last scroll offset of node content (only for scroll containers)
+    scroll_offset: egui::Vec2,
+    /// Id of [`egui::scroll_area::State`] of node scroll area (only for scroll containers)
+    scroll_state_id: Option<egui::Id>,
+    /// Node rect in screen coordinates after layout
+    rect: egui::Rect,
+    /// Directions in which node is sticky, see [`TuiBuilderLogic::sticky`]
+    /// and [`TuiBuilderLogic::sticky_to`]
+    sticky: egui::Vec2b,
+    /// Node is sticky header, see [`TuiBuilderLogic::sticky_header`]
+    sticky_header: bool,
+    /// Index of node in parent node children
+    child_index: usize,
+    /// Time when node index in parent node changed last time
+    reorder_time: f64,
+}
+
+impl Default for TaffyState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TaffyState {
-    fn new() -> Self {
+    /// Create empty layout state
+    ///
+    /// Used internally by [`Tui`], can be used directly to compute layout headlessly
+    /// (see [`TaffyState::compute_layout`]).
+    pub fn new() -> Self {
         Self {
             taffy_tree: TaffyTree::new(),
             last_size: egui::Vec2::ZERO,
+            last_available_space: None,
             id_to_node_id: HashMap::default(),
+            node_id_to_id: HashMap::default(),
+            pixel_rounding: true,
+            last_pixel_rounding: true,
+            recalculations_in_frame: 0,
+            last_layout_time: None,
+            layout_error: false,
+            culled_nodes: HashSet::default(),
+            tab_stops: Vec::new(),
+            tree_modified: false,
         }
     }
 
     #[inline]
-    fn layout(&self, node_id: NodeId) -> &Layout {
-        self.taffy_tree.layout(node_id).unwrap()
+    fn layout(&mut self, node_id: NodeId) -> Layout {
+        let layout = self.taffy_tree.layout(node_id).copied();
+        taffy_result(layout, &mut self.layout_error, "layout", node_id).unwrap_or(Layout::new())
     }
 
     /// Retrieve underlaying [`TaffyTree`] that stores calculated layout information
@@ -1163,11 +3545,97 @@ impl TaffyState {
         &self.taffy_tree
     }
 
+    /// Retrieve mutable [`TaffyTree`] to build node tree for headless layout
+    ///
+    /// Nodes created directly are not tracked by egui ids. If state is used by [`Tui`],
+    /// its layout state is rebuilt from scratch in the next pass.
+    #[inline]
+    pub fn taffy_tree_mut(&mut self) -> &mut TaffyTree<Context> {
+        self.tree_modified = true;
+        &mut self.taffy_tree
+    }
+
+    /// Compute layout of node tree without egui ui or active egui frame (headless layout)
+    ///
+    /// Leaf nodes are measured using their [`Context`] the same way as in [`Tui`],
+    /// infinite leaves grow up to `root_size`. Computed layout is available in
+    /// [`TaffyState::taffy_tree`].
+    ///
+    /// Headless layout has no access to egui fonts and widgets, therefore content size of
+    /// every leaf (e.g. text size) must be known or estimated by the caller. Egui specific
+    /// features (scroll areas, sticky nodes, interaction) are not available.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, Context, TaffyState};
+    /// use taffy::prelude::*;
+    ///
+    /// let mut state = TaffyState::new();
+    /// let tree = state.taffy_tree_mut();
+    /// let cell = Context::new(egui::vec2(20., 10.), egui::vec2(50., 10.));
+    /// let cells: Vec<NodeId> = (0..4)
+    ///     .map(|_| tree.new_leaf_with_context(Style::default(), cell).unwrap())
+    ///     .collect();
+    /// let grid = tree
+    ///     .new_with_children(
+    ///         Style {
+    ///             display: Display::Grid,
+    ///             grid_template_columns: vec![length(100.), fr(1.)],
+    ///             gap: length(10.),
+    ///             size: Size { width: length(300.), height: auto() },
+    ///             ..Default::default()
+    ///         },
+    ///         &cells,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// state
+    ///     .compute_layout(grid, Size::MAX_CONTENT, egui::vec2(300., 200.))
+    ///     .unwrap();
+    ///
+    /// let tree = state.taffy_tree();
+    /// let last = tree.layout(cells[3]).unwrap();
+    /// assert_eq!((last.location.x, last.location.y), (110., 20.));
+    /// assert_eq!((last.size.width, last.size.height), (190., 10.));
+    /// assert_eq!(tree.layout(grid).unwrap().size.height, 30.);
+    /// ```
+    pub fn compute_layout(
+        &mut self,
+        root: NodeId,
+        available_space: Size<AvailableSpace>,
+        root_size: egui::Vec2,
+    ) -> taffy::TaffyResult<()> {
+        self.taffy_tree.compute_layout_with_measure(
+            root,
+            available_space,
+            |known_size, available_space, _id, context, style| {
+                measure_leaf(known_size, available_space, context, style, root_size)
+            },
+        )
+    }
+
     /// Mapping to retrieve nodes based on [`egui::Id`]
     #[inline]
     pub fn items(&self) -> &HashMap<egui::Id, NodeData> {
         &self.id_to_node_id
     }
+
+    /// Retrieve [`egui::Id`] of node (reverse lookup of [`TaffyState::items`])
+    ///
+    /// Useful for tools that walk [`TaffyState::taffy_tree`] and need to identify nodes.
+    ///
+    /// ```
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let state = tui.taffy_state();
+    /// let tree = state.taffy_tree();
+    /// for node in tree.children(tui.current_node()).unwrap_or_default() {
+    ///     println!("{:?}: {:?}", state.egui_id_of(node), tree.layout(node));
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn egui_id_of(&self, node: NodeId) -> Option<egui::Id> {
+        self.node_id_to_id.get(&node).copied()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -1180,6 +3648,9 @@ pub struct TuiBuilder<'r> {
 }
 
 /// Parameters for creating child element in Tui layout
+///
+/// New fields can be added in future versions, construct parameters with
+/// `..Default::default()`.
 #[derive(Clone)]
 pub struct TuiBuilderParams {
     /// Child ui identifier to correctly match elements between frames
@@ -1202,6 +3673,76 @@ pub struct TuiBuilderParams {
 
     /// Sticky position (Should last scroll offset affect the position of the element)
     pub sticky: egui::Vec2b,
+
+    /// Stick element to the top edge of visible area until next sticky header pushes it out
+    pub sticky_header: bool,
+
+    /// Per axis scroll ancestor (resolved node id) which scroll offset element compensates
+    pub sticky_to: taffy::Point<Option<egui::Id>>,
+
+    /// Animate node position changes within parent node (e.g. when children are reordered)
+    pub animate_position: bool,
+
+    /// Can the user drag `overflow: Scroll` element content to scroll (with kinetic momentum)
+    pub drag_to_scroll: bool,
+
+    /// Visibility of egui scroll bars of `overflow: Scroll` element
+    pub scroll_bar_visibility: egui::scroll_area::ScrollBarVisibility,
+
+    /// Limit infinite growth of leaf node to current viewport size instead of root size
+    pub limit_growth_to_viewport: bool,
+
+    /// Show press ripple animation on interactive container
+    pub ripple: bool,
+
+    /// Animate node appearance after its first (invisible) layout frame
+    pub appear_animation: Option<AppearAnimation>,
+
+    /// Keyboard focus order of the first focusable widget in node
+    pub tab_index: Option<i32>,
+
+    /// Origin of rect revealing node content and whether content should be revealed
+    pub reveal: Option<(egui::Vec2, bool)>,
+
+    /// Vertical scroll offsets of scroll ancestor between which node fades in
+    pub scroll_fade: Option<(f32, f32)>,
+
+    /// Pull distance that triggers refresh of `overflow: Scroll` element
+    pub pull_to_refresh: Option<f32>,
+
+    /// Background corner radius as fraction of node smaller dimension
+    pub corner_radius_fraction: Option<f32>,
+
+    /// Hide node (`display: None`) independently of display type set in style
+    pub hidden: bool,
+}
+
+impl Default for TuiBuilderParams {
+    fn default() -> Self {
+        Self {
+            id: TuiId::Auto,
+            style: None,
+            disabled: false,
+            wrap_mode: None,
+            egui_style: None,
+            layout: None,
+            sticky: egui::Vec2b::FALSE,
+            sticky_header: false,
+            sticky_to: taffy::Point { x: None, y: None },
+            animate_position: false,
+            drag_to_scroll: true,
+            scroll_bar_visibility: egui::scroll_area::ScrollBarVisibility::VisibleWhenNeeded,
+            limit_growth_to_viewport: false,
+            ripple: false,
+            appear_animation: None,
+            tab_index: None,
+            reveal: None,
+            scroll_fade: None,
+            pull_to_refresh: None,
+            corner_radius_fraction: None,
+            hidden: false,
+        }
+    }
 }
 
 impl<'r> TuiBuilder<'r> {
@@ -1211,6 +3752,70 @@ impl<'r> TuiBuilder<'r> {
     }
 }
 
+/// Add egui label as child node
+///
+/// If node is aligned to baseline (`align_self` or parent `align_items` is
+/// [`taffy::AlignItems::Baseline`]), first text row baseline is reported to taffy.
+fn add_label(tuib: TuiBuilder, text: egui::WidgetText) -> Response {
+    if !tuib.tui.is_baseline_aligned(&tuib.params) {
+        return egui::Label::new(text).taffy_ui(tuib);
+    }
+
+    let galley = text.clone().into_galley(
+        tuib.tui.egui_ui(),
+        Some(egui::TextWrapMode::Extend),
+        f32::INFINITY,
+        egui::TextStyle::Body,
+    );
+    let baseline = galley
+        .rows
+        .first()
+        .and_then(|row| row.glyphs.first())
+        .map(|glyph| glyph.pos.y);
+
+    tuib.ui_add_manual(
+        |ui| ui.add(egui::Label::new(text)),
+        |mut val, _ui| {
+            val.first_baseline = baseline;
+            val
+        },
+    )
+}
+
+/// Add label wrapped at node width to at most `max_rows` rows, see [`TuiBuilderLogic::label_clamped`]
+fn add_clamped_label(tuib: TuiBuilder, text: egui::WidgetText, max_rows: usize) -> Response {
+    let ui = tuib.builder_tui().egui_ui();
+    let mut job = text.into_layout_job(ui.style(), egui::FontSelection::Default, ui.text_valign());
+    job.wrap.max_rows = max_rows.max(1);
+    job.wrap.break_anywhere = false;
+    job.wrap.overflow_character = Some('…');
+
+    // Node can grow up to text width on a single line
+    let mut single_line_job = job.clone();
+    single_line_job.wrap = Default::default();
+    let single_line_width = ui.fonts(|fonts| fonts.layout_job(single_line_job)).size().x;
+
+    // Node can shrink down to the ellipsis width
+    let font_id = job
+        .sections
+        .first()
+        .map(|section| section.format.font_id.clone())
+        .unwrap_or_default();
+    let ellipsis_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, '…'));
+
+    tuib.ui_add_manual(
+        |ui| ui.add(egui::Label::new(job).wrap_mode(egui::TextWrapMode::Wrap)),
+        |mut val, _ui| {
+            // Height of clamped text laid out at the current node width
+            let size = val.inner.rect.size();
+            val.min_size = egui::vec2(ellipsis_width.min(size.x), size.y);
+            val.intrinsic_size = None;
+            val.max_size = egui::vec2(single_line_width.max(size.x), size.y);
+            val
+        },
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Helper trait to reduce code boilerplate
@@ -1224,15 +3829,7 @@ impl<'r> AsTuiBuilder<'r> for &'r mut Tui {
     fn tui(self) -> TuiBuilder<'r> {
         TuiBuilder {
             tui: self,
-            params: TuiBuilderParams {
-                id: TuiId::Auto,
-                style: None,
-                disabled: false,
-                wrap_mode: None,
-                egui_style: None,
-                layout: None,
-                sticky: egui::Vec2b::FALSE,
-            },
+            params: TuiBuilderParams::default(),
         }
     }
 }
@@ -1279,6 +3876,29 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         tui
     }
 
+    /// Set child node style from style string, see [`style_dsl`] for syntax
+    ///
+    /// Invalid style string is logged as error and default style is used.
+    /// Use [`style_dsl::parse_style`] to handle parse errors.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.style_str("flex col gap:8 p:4 w:200").add(|tui| {
+    ///     tui.label("Title");
+    ///     tui.style_str("w:40 h:20 ml:auto").add_empty();
+    /// });
+    /// # }
+    /// ```
+    fn style_str(self, dsl: &str) -> TuiBuilder<'r> {
+        let style = style_dsl::parse_style(dsl).unwrap_or_else(|err| {
+            log::error!("{err}");
+            Default::default()
+        });
+        self.style(style)
+    }
+
     /// Set child node style to be the same as current node style
     fn reuse_style(self) -> TuiBuilder<'r> {
         let mut tui = self.tui();
@@ -1286,91 +3906,615 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         tui
     }
 
-    /// Set child node id and style
+    /// Set child node id and style
+    #[inline]
+    fn id_style(self, id: impl Into<TuiId>, style: taffy::Style) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.id = id.into();
+        tui.params.style = Some(style);
+        tui
+    }
+
+    /// Mutate child node style
+    #[inline]
+    fn mut_style(self, f: impl FnOnce(&mut taffy::Style)) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        f(tui.params.style.get_or_insert_with(Default::default));
+        tui
+    }
+
+    /// Mutate child node style based on interaction with node background
+    ///
+    /// Interaction is known only after node background is drawn, but node style is needed
+    /// before that to lay out the node. egui stores widget rects of the last frame in memory,
+    /// therefore background response is read by id before node is added
+    /// (see [`egui::Context::read_response`]). This avoids one frame lag between interaction
+    /// and style change. Response is `None` if node was not shown in the last frame.
+    ///
+    /// Works with nodes that have interactive background: [`TuiBuilderLogic::clickable`],
+    /// [`TuiBuilderLogic::button`], [`TuiBuilderLogic::selectable`], etc.
+    fn mut_style_with_interaction(
+        self,
+        f: impl FnOnce(&mut taffy::Style, Option<&egui::Response>),
+    ) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        let id = tui.params.id.clone().resolve(tui.tui);
+        let response = tui.tui.read_background_response(id);
+        f(
+            tui.params.style.get_or_insert_with(Default::default),
+            response.as_ref(),
+        );
+        tui
+    }
+
+    /// Set child enabled_ui egui flag
+    #[inline]
+    fn enabled_ui(self, enabled_ui: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.disabled |= !enabled_ui;
+        tui
+    }
+
+    /// Set child element to contain disabled [`egui::Ui`]
+    ///
+    /// See [`egui::Ui::disable`] for more information.
+    #[inline]
+    fn disabled(self) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.disabled = true;
+        tui
+    }
+
+    /// Set child element wrap mode
+    #[inline]
+    fn wrap_mode(self, wrap_mode: egui::TextWrapMode) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.wrap_mode = Some(wrap_mode);
+        tui
+    }
+
+    /// Set child element egui style
+    #[inline]
+    fn egui_style(self, style: Arc<egui::Style>) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.egui_style = Some(style);
+        tui
+    }
+
+    /// Mutate child element egui style
+    #[inline]
+    fn mut_egui_style(self, f: impl FnOnce(&mut egui::Style)) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+
+        // Unpack style efficiently
+        let mut style = if let Some(style) = tui.params.egui_style {
+            Arc::unwrap_or_clone(style)
+        } else {
+            tui.builder_tui().egui_ui().style().deref().clone()
+        };
+        f(&mut style);
+
+        tui.params.egui_style = Some(Arc::new(style));
+        tui
+    }
+
+    /// Add tui node with scaled text sizes for all its descendants
+    ///
+    /// Multiplies font sizes of egui text styles (and `override_font_id`) in child egui style.
+    /// Text is measured with scaled fonts, so layout accounts for scaled text size.
+    /// Nested calls multiply their factors. Text with explicitly set font size is not scaled.
+    #[inline]
+    fn text_scale<T>(self, factor: f32, f: impl FnOnce(&mut Tui) -> T) -> T {
+        self.mut_egui_style(|style| {
+            for font_id in style.text_styles.values_mut() {
+                font_id.size *= factor;
+            }
+            if let Some(font_id) = &mut style.override_font_id {
+                font_id.size *= factor;
+            }
+        })
+        .add(f)
+    }
+
+    /// Set child element egui layout
+    ///
+    /// Child ui spans the whole node, content is aligned inside of stretched cell.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.egui_layout(egui::Layout::top_down(egui::Align::Center))
+    ///     .label("Centered text");
+    /// # }
+    /// ```
+    #[inline]
+    fn egui_layout(self, layout: egui::Layout) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.layout = Some(layout);
+        tui
+    }
+
+    /// Lay out element content right-to-left, independently of global direction
+    ///
+    /// Sets egui layout of element (and its descendants) to wrapping [`egui::Layout::right_to_left`]:
+    /// widgets are placed from the right edge of their nodes and text is aligned to the right.
+    /// Text is measured and wrapped at node width as usual. Useful for cells with right-to-left
+    /// text (e.g. Arabic label in otherwise left-to-right app). Taffy flex and grid placement
+    /// of children is not mirrored.
+    ///
+    /// egui does not reorder bidirectional text, text is drawn in the given character order
+    /// using fonts loaded in egui context.
+    #[inline]
+    fn rtl_text(self) -> TuiBuilder<'r> {
+        self.egui_layout(egui::Layout::right_to_left(egui::Align::Min).with_main_wrap(true))
+    }
+
+    /// Set element as sticky in specified dimensions.
+    ///
+    /// Element position in specified dimensions will not be affected by ancestore `overflow: scroll` element
+    /// scroll offset in specified dimension.
+    ///
+    /// Sticky elements are painted and receive interaction above other content of the tui,
+    /// elements sticky in both dimensions are above elements sticky in one dimension.
+    ///
+    /// Sticky cells stay aligned with scrolled cells of grid with gap, padding and border.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    /// use taffy::prelude::{length, line};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.style(taffy::Style {
+    ///     display: taffy::Display::Grid,
+    ///     overflow: taffy::Point {
+    ///         x: taffy::Overflow::Scroll,
+    ///         y: taffy::Overflow::Scroll,
+    ///     },
+    ///     size: length(300.),
+    ///     grid_template_columns: vec![length(100.); 10],
+    ///     ..Default::default()
+    /// })
+    /// .add(|tui| {
+    ///     for row in 0..50 {
+    ///         for column in 0..10 {
+    ///             // First row and first column stay visible while grid is scrolled
+    ///             tui.sticky(egui::Vec2b::new(column == 0, row == 0))
+    ///                 .style(taffy::Style {
+    ///                     grid_row: line(row + 1),
+    ///                     grid_column: line(column + 1),
+    ///                     ..Default::default()
+    ///                 })
+    ///                 .label(format!("{row}:{column}"));
+    ///         }
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn sticky(self, sticky: egui::Vec2b) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.sticky = sticky;
+        tui
+    }
+
+    /// Set element as sticky per axis relative to given `overflow: scroll` ancestors
+    ///
+    /// Ancestors are referenced by resolved node id (see [`Tui::current_id`]). Element
+    /// compensates scroll offsets of the ancestor and of all scroll nodes between the ancestor
+    /// and the element in that axis, therefore it keeps its position in the ancestor viewport.
+    /// Unlike [`TuiBuilderLogic::sticky`], which follows the nearest scroll ancestor, this allows
+    /// elements in nested scroll areas to stick horizontally to one ancestor and vertically to
+    /// another. Axes without ancestor (or with id of node that is not a scroll ancestor)
+    /// are not affected. In axes with scroll ancestor [`TuiBuilderLogic::sticky`] is ignored,
+    /// offset of the nearest scroll ancestor is already compensated.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic, TuiId};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let mut style = taffy::Style::default();
+    /// style.overflow.y = taffy::Overflow::Scroll;
+    /// let outer = egui::Id::new("outer");
+    /// tui.id(TuiId::Unique(outer)).style(style.clone()).add(|tui| {
+    ///     style.overflow = taffy::Point {
+    ///         x: taffy::Overflow::Scroll,
+    ///         y: taffy::Overflow::Visible,
+    ///     };
+    ///     let inner = egui::Id::new("inner");
+    ///     tui.id(TuiId::Unique(inner)).style(style).add(|tui| {
+    ///         // Title stays in the left top corner of both viewports
+    ///         tui.sticky_to(Some(inner), Some(outer)).label("Title");
+    ///         for idx in 0..20 {
+    ///             tui.label(format!("Cell {idx}"));
+    ///         }
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn sticky_to(self, x: Option<egui::Id>, y: Option<egui::Id>) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.sticky_to = taffy::Point { x, y };
+        tui
+    }
+
+    /// Stick element to the top edge of visible area while its layout position is scrolled
+    /// past it, until the next sticky header sibling (or the end of parent node) pushes it out
+    ///
+    /// Useful for section headers in long scrollable lists (like mobile navigation).
+    /// Like [`TuiBuilderLogic::sticky`] nodes, headers are painted and receive interaction
+    /// above content they overlap.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let mut style = taffy::Style {
+    ///     flex_direction: taffy::FlexDirection::Column,
+    ///     ..Default::default()
+    /// };
+    /// style.overflow.y = taffy::Overflow::Scroll;
+    /// tui.style(style).add(|tui| {
+    ///     for section in ["Fruits", "Vegetables"] {
+    ///         tui.add(|tui| {
+    ///             tui.sticky_header().heading(section);
+    ///             for idx in 0..20 {
+    ///                 tui.label(format!("{section} {idx}"));
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn sticky_header(self) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.sticky_header = true;
+        tui
+    }
+
+    /// Fade element depending on vertical scroll offset of the nearest `overflow: scroll` ancestor
+    ///
+    /// Element is transparent when ancestor is scrolled to `from` and opaque when scrolled
+    /// to `to` offset, opacity is interpolated between them. Use `from > to` to fade element
+    /// out instead. Fully transparent element can not be interacted with.
+    /// Combine with [`TuiBuilderLogic::sticky`] or [`TuiBuilderLogic::sticky_header`]
+    /// (e.g. compact header that appears after scrolling down a bit).
+    #[inline]
+    fn scroll_fade(self, from: f32, to: f32) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.scroll_fade = Some((from, to));
+        tui
+    }
+
+    /// Animate node position changes within parent node (FLIP animation)
+    ///
+    /// When node order in parent node changes (children are reordered, inserted or removed),
+    /// node slides from its previous position to the new one over egui `animation_time`.
+    /// Other position changes (e.g. sibling resize) are not animated. Animation state is keyed by
+    /// node id, therefore node must keep its id when it moves (use [`Tui::keyed_children`] or
+    /// [`TuiId::Unique`] instead of [`TuiId::Auto`]).
+    #[inline]
+    fn animate_position(self, animate: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.animate_position = animate;
+        tui
+    }
+
+    /// Animate appearance of new node (fade or slide in)
+    ///
+    /// New node is laid out in invisible first frame, afterwards node content appears at once.
+    /// With appear animation node fades in (and slides from offset) once its size is known.
+    /// Animation state is keyed by node id, node animates again if it is removed and added back.
+    #[inline]
+    fn appear_animation(self, animation: AppearAnimation) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.appear_animation = Some(animation);
+        tui
+    }
+
+    /// Reveal (or hide) node content with animated clip rect
+    ///
+    /// Content is clipped to rect growing from `origin` when `revealed` changes to true
+    /// and shrinking when it changes to false. Origin is given relative to node rect
+    /// (`(0., 0.)` is left top corner, `(0.5, 0.5)` is center, `(0., 0.5)` wipes content
+    /// from left to right). Hidden content still takes space in layout and can not be
+    /// interacted with. Animation state is keyed by node id, node is shown without animation
+    /// in its first frame.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, revealed: bool) {
+    /// tui.reveal(egui::vec2(0.5, 0.5), revealed)
+    ///     .add_with_background(|tui| {
+    ///         tui.heading("Hidden card");
+    ///     });
+    /// # }
+    /// ```
+    #[inline]
+    fn reveal(self, origin: egui::Vec2, revealed: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.reveal = Some((origin, revealed));
+        tui
+    }
+
+    /// Enable or disable scrolling by dragging `overflow: Scroll` element content (default: true)
+    ///
+    /// Useful for touch screens, scrolling continues with kinetic momentum after drag is released.
+    /// Content widgets that sense drag (sliders, drag values, selectable labels) take priority
+    /// over drag to scroll. Disable `selectable_labels` in egui style to scroll by dragging text.
+    #[inline]
+    fn drag_to_scroll(self, drag_to_scroll: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.drag_to_scroll = drag_to_scroll;
+        tui
+    }
+
+    /// Detect pull to refresh gesture on `overflow: Scroll` element (e.g. for touch screens)
+    ///
+    /// Dragging content down while it is scrolled to the top pulls it past the top edge and shows
+    /// spinner. Releasing content pulled by more than `threshold` points requests refresh,
+    /// check it with [`Tui::refresh_requested`] using node id.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, messages: &mut Vec<String>) {
+    /// let mut style = taffy::Style::default();
+    /// style.overflow.y = taffy::Overflow::Scroll;
+    /// tui.style(style).pull_to_refresh(60.).add(|tui| {
+    ///     if tui.refresh_requested(tui.current_id()) {
+    ///         messages.push("New message".to_owned());
+    ///     }
+    ///     for message in messages.iter() {
+    ///         tui.label(message);
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn pull_to_refresh(self, threshold: f32) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.pull_to_refresh = Some(threshold);
+        tui
+    }
+
+    /// Set visibility of egui scroll bars of `overflow: Scroll` element
+    /// (default: [`egui::scroll_area::ScrollBarVisibility::VisibleWhenNeeded`])
+    ///
+    /// Hide scroll bars to draw custom ones, see [`Tui::overflow_amount`].
+    #[inline]
+    fn scroll_bar_visibility(
+        self,
+        visibility: egui::scroll_area::ScrollBarVisibility,
+    ) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.scroll_bar_visibility = visibility;
+        tui
+    }
+
+    /// Limit growth of infinite leaf nodes (e.g. progress bar, separator) to size of
+    /// current viewport (nearest `overflow: Scroll` node) instead of root size
+    ///
+    /// Inside scroll containers this keeps leaf within visible width (content wraps
+    /// instead of scrolling), which matters in nested scroll areas where root size
+    /// is larger than the viewport.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, TuiBuilderLogic};
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// let mut style = taffy::Style::default();
+    /// style.overflow.x = taffy::Overflow::Scroll;
+    /// tui.style(style).add(|tui| {
+    ///     // Progress bar is as wide as visible part of scroll container
+    ///     tui.limit_growth_to_viewport(true)
+    ///         .ui_add(egui::ProgressBar::new(0.5));
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn limit_growth_to_viewport(self, limit: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.limit_growth_to_viewport = limit;
+        tui
+    }
+
+    /// Set keyboard focus order of the first focusable widget in this node
+    ///
+    /// Tab and Shift+Tab move focus between such widgets of the tui in ascending tab index order
+    /// (nodes with equal index keep creation order) wrapping around at the ends. Useful when
+    /// visual order differs from creation order (e.g. grid placed column by column).
+    /// Focus lock filter of the widget is not changed, arrow keys keep their egui behavior.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, first: &mut String, second: &mut String) {
+    /// // Created first, but focused second
+    /// tui.tab_index(1).ui_add(egui::TextEdit::singleline(second));
+    /// tui.tab_index(0).ui_add(egui::TextEdit::singleline(first));
+    /// # }
+    /// ```
+    #[inline]
+    fn tab_index(self, tab_index: i32) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.tab_index = Some(tab_index);
+        tui
+    }
+
+    /// Show ripple animation spreading from press position when interactive container
+    /// ([`TuiBuilderLogic::button`], [`TuiBuilderLogic::clickable`]) is pressed
+    ///
+    /// Ripple is painted above container background and below its content,
+    /// clipped to container rect.
+    #[inline]
+    fn ripple(self, ripple: bool) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.ripple = ripple;
+        tui
+    }
+
+    /// Round background corners by `fraction` of node smaller dimension instead of
+    /// egui visuals corner radius (`0.5` gives pill shape at any node size)
+    ///
+    /// Applies to background helpers ([`TuiBuilderLogic::button`],
+    /// [`TuiBuilderLogic::add_with_border`], ...) and custom backgrounds that use corner
+    /// radius of egui visuals. Radius is limited to 255 points by egui.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// // Pill shaped button
+    /// tui.corner_radius_fraction(0.5).button(|tui| {
+    ///     tui.label("Subscribe");
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn corner_radius_fraction(self, fraction: f32) -> TuiBuilder<'r> {
+        let mut tui = self.tui();
+        tui.params.corner_radius_fraction = Some(fraction);
+        tui
+    }
+
+    /// Set child node display type
+    ///
+    /// [`TuiBuilderLogic::flex`], [`TuiBuilderLogic::grid_display`],
+    /// [`TuiBuilderLogic::block`] and [`TuiBuilderLogic::display_none`] are shorthands for
+    /// the corresponding display types.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, Tui, TuiBuilderLogic};
+    ///
+    /// fn settings(tui: &mut Tui, advanced: bool) {
+    ///     tui.display(taffy::Display::Grid).add(|tui| {
+    ///         tui.label("Name");
+    ///         tui.label("Value");
+    ///     });
+    ///     let display = if advanced { taffy::Display::Flex } else { taffy::Display::None };
+    ///     tui.display(display).add(|tui| {
+    ///         tui.label("Advanced settings");
+    ///     });
+    /// }
+    /// ```
+    #[inline]
+    fn display(self, display: taffy::Display) -> TuiBuilder<'r> {
+        self.mut_style(|style| style.display = display)
+    }
+
+    /// Lay out child node content using flexbox
     #[inline]
-    fn id_style(self, id: impl Into<TuiId>, style: taffy::Style) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.id = id.into();
-        tui.params.style = Some(style);
-        tui
+    fn flex(self) -> TuiBuilder<'r> {
+        self.display(taffy::Display::Flex)
     }
 
-    /// Mutate child node style
+    /// Lay out child node content using CSS grid
     #[inline]
-    fn mut_style(self, f: impl FnOnce(&mut taffy::Style)) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        f(tui.params.style.get_or_insert_with(Default::default));
-        tui
+    fn grid_display(self) -> TuiBuilder<'r> {
+        self.display(taffy::Display::Grid)
     }
 
-    /// Set child enabled_ui egui flag
+    /// Lay out child node content using block layout
     #[inline]
-    fn enabled_ui(self, enabled_ui: bool) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.disabled |= !enabled_ui;
-        tui
+    fn block(self) -> TuiBuilder<'r> {
+        self.display(taffy::Display::Block)
     }
 
-    /// Set child element to contain disabled [`egui::Ui`]
+    /// Hide child node by setting its display type to `Display::None`, node takes no space in
+    /// layout and its content is not painted
     ///
-    /// See [`egui::Ui::disable`] for more information.
+    /// Content is still built, therefore node keeps its state. Display type set afterwards
+    /// (e.g. [`TuiBuilderLogic::flex`]) shows the node again, use [`TuiBuilderLogic::visible`]
+    /// to hide node independently of its display type.
     #[inline]
-    fn disabled(self) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.disabled = true;
-        tui
+    fn display_none(self) -> TuiBuilder<'r> {
+        self.display(taffy::Display::None)
     }
 
-    /// Set child element wrap mode
+    /// Hide child node if `visible` is false, hidden node takes no space in layout and its
+    /// content is not painted
+    ///
+    /// Visibility is stored separately from display type, therefore `visible` composes with
+    /// other display methods in any order. `visible(true)` does not show node hidden with
+    /// [`TuiBuilderLogic::display_none`].
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, show_details: bool) {
+    /// tui.grid_display().visible(show_details).add(|tui| {
+    ///     tui.label("Details");
+    /// });
+    /// # }
+    /// ```
     #[inline]
-    fn wrap_mode(self, wrap_mode: egui::TextWrapMode) -> TuiBuilder<'r> {
+    fn visible(self, visible: bool) -> TuiBuilder<'r> {
         let mut tui = self.tui();
-        tui.params.wrap_mode = Some(wrap_mode);
+        tui.params.hidden = !visible;
         tui
     }
 
-    /// Set child element egui style
+    /// Offset element from its normal position without affecting position of its siblings
+    ///
+    /// Sets `position: Relative` and left, top insets of taffy style.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui, pressed: bool) {
+    /// // Pressed button content moves down without moving its siblings
+    /// let offset = if pressed { egui::vec2(0., 1.) } else { egui::Vec2::ZERO };
+    /// tui.relative_offset(offset).label("Press me");
+    /// # }
+    /// ```
     #[inline]
-    fn egui_style(self, style: Arc<egui::Style>) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.egui_style = Some(style);
-        tui
+    fn relative_offset(self, offset: egui::Vec2) -> TuiBuilder<'r> {
+        self.mut_style(|style| {
+            style.position = taffy::Position::Relative;
+            style.inset.left = LengthPercentageAuto::Length(offset.x);
+            style.inset.top = LengthPercentageAuto::Length(offset.y);
+        })
     }
 
-    /// Mutate child element egui style
+    /// Set fixed child node size in points (taffy `size` style)
+    ///
+    /// Egui [`egui::Vec2`] counterparts of taffy size styles:
+    /// [`TuiBuilderLogic::min_size`], [`TuiBuilderLogic::max_size`].
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.exact_size(egui::vec2(100., 40.))
+    ///     .min_size([50., 20.])
+    ///     .max_size([200., 80.])
+    ///     .add_with_border(|tui| {
+    ///         tui.label("Card");
+    ///     });
+    /// # }
+    /// ```
     #[inline]
-    fn mut_egui_style(self, f: impl FnOnce(&mut egui::Style)) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-
-        // Unpack style efficiently
-        let mut style = if let Some(style) = tui.params.egui_style {
-            Arc::unwrap_or_clone(style)
-        } else {
-            tui.builder_tui().egui_ui().style().deref().clone()
-        };
-        f(&mut style);
-
-        tui.params.egui_style = Some(Arc::new(style));
-        tui
+    fn exact_size(self, size: impl Into<egui::Vec2>) -> TuiBuilder<'r> {
+        let size = vec2_to_size(size.into());
+        self.mut_style(|style| style.size = size)
     }
 
-    /// Set child element egui layout
+    /// Set minimal child node size in points (taffy `min_size` style)
     #[inline]
-    fn egui_layout(self, layout: egui::Layout) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.layout = Some(layout);
-        tui
+    fn min_size(self, size: impl Into<egui::Vec2>) -> TuiBuilder<'r> {
+        let size = vec2_to_size(size.into());
+        self.mut_style(|style| style.min_size = size)
     }
 
-    /// Set element as sticky in specified dimensions.
-    ///
-    /// Element position in specified dimensions will not be affected by ancestore `overflow: scroll` element
-    /// scroll offset in specified dimension.
+    /// Set maximal child node size in points (taffy `max_size` style)
     #[inline]
-    fn sticky(self, sticky: egui::Vec2b) -> TuiBuilder<'r> {
-        let mut tui = self.tui();
-        tui.params.sticky = sticky;
-        tui
+    fn max_size(self, size: impl Into<egui::Vec2>) -> TuiBuilder<'r> {
+        let size = vec2_to_size(size.into());
+        self.mut_style(|style| style.max_size = size)
     }
 
     /// Add tui node as children to this node
@@ -1380,6 +4524,144 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         tui.tui.add_child(tui.params, (), |tui, _| f(tui)).main
     }
 
+    /// Add tui node as children to this node, content is built only if node is visible
+    ///
+    /// Content function is skipped (returns `None`) if node is entirely outside of the current
+    /// viewport (e.g. scrolled out of scroll container) extended by `overscan` in all directions.
+    /// Node keeps its last layout and size of its content, therefore skipped content still
+    /// takes space in scroll container. Lighter alternative to virtual grids for large
+    /// scrollable layouts with arbitrary content.
+    ///
+    /// Content changes of skipped nodes are not reflected in the layout until node is visible.
+    #[inline]
+    fn add_culled<T>(self, overscan: f32, f: impl FnOnce(&mut Tui) -> T) -> Option<T> {
+        let tui = self.tui();
+        tui.tui.add_child_culled(tui.params, overscan, f)
+    }
+
+    /// Add tui node that fills parent node and centers its content
+    /// horizontally and vertically
+    ///
+    /// Node size is set to fill parent node unless size is provided in the style.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ctx: &egui::Context) {
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     tui(ui, "empty_state")
+    ///         .reserve_available_space()
+    ///         .style(taffy::Style {
+    ///             size: taffy::prelude::percent(1.),
+    ///             ..Default::default()
+    ///         })
+    ///         .show(|tui| {
+    ///             tui.centered(|tui| {
+    ///                 tui.label("Nothing to show");
+    ///             });
+    ///         });
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn centered<T>(self, f: impl FnOnce(&mut Tui) -> T) -> T {
+        self.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Column;
+            style.justify_content = Some(taffy::JustifyContent::Center);
+            style.align_items = Some(taffy::AlignItems::Center);
+            if style.size == Size::auto() {
+                style.size = percent(1.);
+            }
+        })
+        .add(f)
+    }
+
+    /// Add node with content or, when `is_empty`, with centered empty state (e.g. message
+    /// for list without items)
+    ///
+    /// Empty state node is created with [`TuiBuilderLogic::centered`] and fills parent node
+    /// unless size is provided in the style. Content node uses builder style as is.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// let items: Vec<String> = Vec::new();
+    /// tui(ui, "list").reserve_available_space().show(|tui| {
+    ///     tui.empty_state(
+    ///         items.is_empty(),
+    ///         |tui| {
+    ///             tui.label("No items yet");
+    ///         },
+    ///         |tui| {
+    ///             for item in &items {
+    ///                 tui.label(item);
+    ///             }
+    ///         },
+    ///     );
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn empty_state(
+        self,
+        is_empty: bool,
+        empty: impl FnOnce(&mut Tui),
+        content: impl FnOnce(&mut Tui),
+    ) {
+        if is_empty {
+            self.centered(empty)
+        } else {
+            self.add(content)
+        }
+    }
+
+    /// Add independent logical layout region as children to this node
+    ///
+    /// Region node id is unique (not based on position in parent), therefore regions can be
+    /// added, removed or reordered without invalidating layout of other regions.
+    ///
+    /// Useful to group many small layouts under one tui instead of creating separate tui for each
+    /// of them. All regions share one [`TaffyState`] and one layout pass, which avoids per tui
+    /// state lookup, locking and layout calculation overhead.
+    #[inline]
+    fn sub_region<T>(self, id: impl Into<egui::Id>, f: impl FnOnce(&mut Tui) -> T) -> T {
+        self.id(TuiId::Unique(id.into())).add(f)
+    }
+
+    /// Add footer node that sits at the bottom of parent flex column
+    ///
+    /// Inserts [`TuiBuilderLogic::spacer`] node before the footer, therefore footer is pushed to the
+    /// bottom when content is shorter than parent and follows content (scrolls normally) when
+    /// content is taller. Parent should be full height flex column (app shell, sidebar).
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, tui, TuiBuilderLogic};
+    /// use taffy::prelude::*;
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "sidebar")
+    ///     .reserve_available_space()
+    ///     .style(taffy::Style {
+    ///         flex_direction: taffy::FlexDirection::Column,
+    ///         size: percent(1.),
+    ///         ..Default::default()
+    ///     })
+    ///     .show(|tui| {
+    ///         tui.label("Content");
+    ///         tui.add_footer(|tui| {
+    ///             tui.label("Footer");
+    ///         });
+    ///     });
+    /// # }
+    /// ```
+    fn add_footer<T>(self, f: impl FnOnce(&mut Tui) -> T) -> T {
+        let TuiBuilder { tui, params } = self.tui();
+        tui.spacer();
+        tui.tui().params(params).add(f)
+    }
+
     /// Add empty tui node as children to this node
     ///
     /// Useful to fill grid cells with empty content
@@ -1388,6 +4670,28 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         self.tui().add(|_| {})
     }
 
+    /// Add empty node that takes free space of flex container (`flex_grow: 1`)
+    ///
+    /// Useful to push following items to the end of the row (e.g. toolbar actions to the right).
+    #[inline]
+    fn spacer(self) {
+        self.mut_style(|style| {
+            style.flex_grow = 1.;
+        })
+        .add_empty()
+    }
+
+    /// Add empty node of fixed size along main axis of flex container
+    #[inline]
+    fn spacer_fixed(self, size: f32) {
+        self.mut_style(|style| {
+            style.flex_grow = 0.;
+            style.flex_shrink = 0.;
+            style.flex_basis = length(size);
+        })
+        .add_empty()
+    }
+
     /// Add tui node as children to this node and draw only background color
     #[inline]
     fn add_with_background_color<T>(self, f: impl FnOnce(&mut Tui) -> T) -> T {
@@ -1454,6 +4758,26 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         tui
     }
 
+    /// Size child node content to `cols x rows` character cells of egui monospace font
+    ///
+    /// Useful for terminal or code editor like layouts. Cell width is the glyph width of
+    /// monospace font, cell height is the font row height. Padding and border are added on top
+    /// of the content size (`box_sizing` is set to content box).
+    fn monospace_grid_size(self, cols: u16, rows: u16) -> TuiBuilder<'r> {
+        let tui = self.tui();
+        let cell_size = tui.tui.monospace_cell_size();
+        let size = taffy::Size {
+            width: length(cell_size.x * cols as f32),
+            height: length(cell_size.y * rows as f32),
+        };
+        tui.mut_style(|style| {
+            style.box_sizing = taffy::BoxSizing::ContentBox;
+            style.min_size = size;
+            style.size = size;
+            style.max_size = size;
+        })
+    }
+
     /// Add tui node as children to this node and draw simple group Frame background
     #[inline]
     fn add_with_border<T>(self, f: impl FnOnce(&mut Tui) -> T) -> T {
@@ -1478,21 +4802,51 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
     }
 
     /// Add tui node with background that acts egui Collapsing header
+    ///
+    /// See [`TuiInnerResponse::clicked_with_modifiers`] to handle Ctrl and Shift clicks.
     #[must_use = "You should check if the user clicked this with `if ….clicked() { … } "]
+    #[inline]
     fn clickable<T>(self, f: impl FnOnce(&mut Tui) -> T) -> TuiInnerResponse<T> {
+        self.clickable_with_sense(egui::Sense::click(), f)
+    }
+
+    /// Add tui node with background that senses interactions specified by [`egui::Sense`]
+    ///
+    /// Use [`egui::Sense::click_and_drag`] to detect drag on the container in addition to
+    /// clicks and double clicks.
+    #[must_use = "You should check if the user clicked this with `if ….clicked() { … } "]
+    fn clickable_with_sense<T>(
+        self,
+        sense: egui::Sense,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiInnerResponse<T> {
         let tui = self.tui();
+        let ripple = tui.params.ripple;
 
-        fn background(ui: &mut egui::Ui, container: &TaffyContainerUi) -> Response {
+        fn background(
+            ui: &mut egui::Ui,
+            container: &TaffyContainerUi,
+            sense: egui::Sense,
+            ripple: bool,
+        ) -> Response {
             let rect = container.full_container();
-            ui.interact(rect, ui.id().with("bg"), egui::Sense::click())
+            let response = ui.interact(rect, ui.id().with("bg"), sense);
+            if ripple {
+                paint_ripple(ui, &response);
+            }
+            response
         }
 
-        let return_values = tui
-            .tui
-            .add_child(tui.params, background, |tui, bg_response| {
+        let return_values = tui.tui.add_child(
+            tui.params,
+            |ui: &mut egui::Ui, container: &TaffyContainerUi| {
+                background(ui, container, sense, ripple)
+            },
+            |tui, bg_response| {
                 setup_tui_visuals(tui, bg_response);
                 f(tui)
-            });
+            },
+        );
 
         TuiInnerResponse {
             inner: return_values.main,
@@ -1509,11 +4863,13 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         f: impl FnOnce(&mut Tui) -> T,
     ) -> TuiInnerResponse<T> {
         let tui = self.with_border_style_from_egui_style();
+        let ripple = tui.params.ripple;
 
         fn background(
             ui: &mut egui::Ui,
             container: &TaffyContainerUi,
             target_tint_color: Option<egui::Color32>,
+            ripple: bool,
         ) -> Response {
             let rect = container.full_container();
             let response = ui.interact(rect, ui.id().with("bg"), egui::Sense::click());
@@ -1532,6 +4888,9 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
                 stroke,
                 egui::StrokeKind::Inside,
             );
+            if ripple {
+                paint_ripple(ui, &response);
+            }
 
             response
         }
@@ -1539,7 +4898,7 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         let return_values = tui.tui.add_child(
             tui.params,
             |ui: &mut egui::Ui, container: &TaffyContainerUi| {
-                background(ui, container, target_tint_color)
+                background(ui, container, target_tint_color, ripple)
             },
             |tui, bg_response| {
                 setup_tui_visuals(tui, bg_response);
@@ -1561,7 +4920,64 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         self.filled_button(None, f)
     }
 
+    /// Add circular button pinned to `corner` of the current node (floating action button)
+    ///
+    /// Button is absolutely positioned and sticky (see [`TuiBuilderLogic::sticky`]),
+    /// therefore it stays in the corner of scroll container viewport while content scrolls.
+    /// Add it as the last child of the container to paint it above content and give it
+    /// interaction priority. Use node margin to set distance from the corner.
+    ///
+    /// Only corners are supported, [`egui::Align::Center`] is treated as [`egui::Align::Min`].
+    #[must_use = "You should check if the user clicked this with `if ….clicked() { … } "]
+    fn floating_button<T>(
+        self,
+        corner: egui::Align2,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiInnerResponse<T> {
+        let tui = self.tui();
+        let diameter = 2. * tui.builder_tui().egui_ui().spacing().interact_size.y;
+
+        tui.sticky(egui::Vec2b::TRUE)
+            .mut_style(|style| {
+                let (start, end) = (LengthPercentageAuto::Length(0.), LengthPercentageAuto::Auto);
+                let [x, y] = corner.0.map(|align| match align {
+                    egui::Align::Max => (end, start),
+                    egui::Align::Min | egui::Align::Center => (start, end),
+                });
+
+                style.position = taffy::Position::Absolute;
+                style.inset = taffy::Rect {
+                    left: x.0,
+                    right: x.1,
+                    top: y.0,
+                    bottom: y.1,
+                };
+                if style.min_size == Size::auto() {
+                    style.min_size = length(diameter);
+                }
+                style.align_items.get_or_insert(taffy::AlignItems::Center);
+                style
+                    .justify_content
+                    .get_or_insert(taffy::JustifyContent::Center);
+            })
+            .mut_egui_style(|style| {
+                // Rounding is clamped to half of rect size by egui tessellator
+                let widgets = &mut style.visuals.widgets;
+                for visuals in [
+                    &mut widgets.inactive,
+                    &mut widgets.hovered,
+                    &mut widgets.active,
+                    &mut widgets.open,
+                ] {
+                    visuals.corner_radius = egui::CornerRadius::same(u8::MAX);
+                }
+            })
+            .button(f)
+    }
+
     /// Add tui node with background that acts as selectable button
+    ///
+    /// See [`TuiInnerResponse::clicked_with_modifiers`] to handle Ctrl and Shift clicks.
     #[must_use = "You should check if the user clicked this with `if ….clicked() { … } "]
     #[inline]
     fn selectable<T>(self, selected: bool, f: impl FnOnce(&mut Tui) -> T) -> TuiInnerResponse<T> {
@@ -1618,6 +5034,144 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         tui.tui.add_child(tui.params, content, f)
     }
 
+    /// Add tui node as children to this node and draw custom foreground above its content
+    ///
+    /// Foreground is drawn with parent ui painter after node content is drawn,
+    /// therefore it is not clipped by node overflow settings.
+    #[inline]
+    fn add_with_foreground<T>(
+        self,
+        draw: impl FnOnce(&egui::Painter, egui::Rect),
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        let TuiBuilder { tui, params } = self.tui();
+        let (inner, rect) = tui
+            .add_child(params, (), |tui, _| {
+                let inner = f(tui);
+                (inner, tui.taffy_container().full_container())
+            })
+            .main;
+        draw(tui.egui_ui().painter(), rect);
+        inner
+    }
+
+    /// Add grid node as children to this node and paint lines between its rows and columns
+    ///
+    /// Lines are painted in the middle of gutters below node content and do not affect
+    /// layout. Use `gap` at least as wide as stroke to keep lines visible
+    /// next to cells with background. Lines follow scroll offset of `overflow: Scroll` grids.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "spreadsheet").show(|tui| {
+    ///     tui.style(taffy::Style {
+    ///         grid_template_columns: vec![taffy::prelude::auto(); 3],
+    ///         gap: taffy::prelude::length(1.),
+    ///         ..Default::default()
+    ///     })
+    ///     .add_with_grid_lines(egui::Stroke::new(1., egui::Color32::GRAY), |tui| {
+    ///         for cell in 0..9 {
+    ///             tui.label(cell.to_string());
+    ///         }
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    fn add_with_grid_lines<T>(
+        self,
+        stroke: impl Into<egui::Stroke>,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        let stroke = stroke.into();
+        self.grid_display().add(|tui| {
+            tui.paint_grid_lines(stroke);
+            f(tui)
+        })
+    }
+
+    /// Add tui node as children to this node and lay out its children as a grid
+    /// with `columns` equal width columns on wide screens and as a single column on narrow screens
+    ///
+    /// Layout switches to a single flex column when content width of the parent node
+    /// in the last layout pass is smaller than `breakpoint`. Other style properties
+    /// (e.g. `gap`) are kept in both layouts, child grid placement is ignored in a single column.
+    ///
+    /// ```
+    /// use egui_taffy::{taffy, tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "dashboard").reserve_available_width().show(|tui| {
+    ///     tui.style(taffy::Style {
+    ///         gap: taffy::prelude::length(8.),
+    ///         ..Default::default()
+    ///     })
+    ///     .add_responsive_grid(600., 3, |tui| {
+    ///         for card in ["Sales", "Visitors", "Orders"] {
+    ///             tui.add_with_border(|tui| tui.label(card));
+    ///         }
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    fn add_responsive_grid<T>(
+        self,
+        breakpoint: f32,
+        columns: u16,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        let tui = self.tui();
+        let width = tui
+            .tui
+            .taffy_container()
+            .full_container_without_border_and_padding()
+            .width();
+
+        tui.mut_style(|style| {
+            if width < breakpoint {
+                style.display = taffy::Display::Flex;
+                style.flex_direction = taffy::FlexDirection::Column;
+                if style.align_items.is_none() {
+                    style.align_items = Some(taffy::AlignItems::Stretch);
+                }
+            } else {
+                style.display = taffy::Display::Grid;
+                style.grid_template_columns = vec![fr(1.); columns as usize];
+            }
+        })
+        .add(f)
+    }
+
+    /// Add tui node as children to this node and show tooltip while node is hovered
+    ///
+    /// Tooltip is shown using [`egui::Response::on_hover_ui`], therefore it respects egui
+    /// `tooltip_delay` and `show_tooltips_only_when_still` interaction settings.
+    /// Hover is sensed above node content, therefore tooltip is shown also
+    /// while child widgets are hovered. Child widget interaction is not blocked.
+    fn add_with_tooltip<T>(
+        self,
+        tooltip: impl FnOnce(&mut egui::Ui),
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiInnerResponse<T> {
+        let TuiBuilder { tui, params } = self.tui();
+        let (inner, rect, id) = tui
+            .add_child(params, (), |tui, _| {
+                let inner = f(tui);
+                (
+                    inner,
+                    tui.taffy_container().full_container(),
+                    tui.current_id(),
+                )
+            })
+            .main;
+        let response = tui
+            .egui_ui()
+            .interact(rect, id.with("tooltip"), egui::Sense::hover())
+            .on_hover_ui(tooltip);
+        TuiInnerResponse { inner, response }
+    }
+
     /// Add scroll area egui Ui
     ///
     /// Alternative: Using `overflow: Scroll` scroll area will be directly inserted in taffy layout.
@@ -1651,6 +5205,10 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
 
     /// Add scroll area egui Ui
     ///
+    /// Scroll area is sized to its content and grows until max size is reached, then scrolls.
+    /// Max size is taken from the style `max_size`, otherwise it is limited by the
+    /// [`Tui::set_limit_scroll_area_size`] fraction of root size.
+    ///
     /// Alternative: Using `overflow: Scroll` scroll area will be directly inserted in taffy layout.
     fn ui_scroll_area<T>(self, content: impl FnOnce(&mut Ui) -> T) -> T {
         let tui = self.tui();
@@ -1677,13 +5235,7 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
     fn ui_finite<T>(self, content: impl FnOnce(&mut Ui) -> T) -> T {
         self.ui_manual(|ui, _params| {
             let inner = content(ui);
-            TuiContainerResponse {
-                inner,
-                min_size: ui.min_size(),
-                intrinsic_size: None,
-                max_size: ui.min_size(),
-                infinite: egui::Vec2b::FALSE,
-            }
+            TuiContainerResponse::new(inner, ui.min_size(), ui.min_size())
         })
     }
 
@@ -1698,10 +5250,53 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
                 intrinsic_size: None,
                 max_size: ui.min_size(),
                 infinite: egui::Vec2b::TRUE,
+                first_baseline: None,
             }
         })
     }
 
+    /// Add egui ui that adapts its content to the width allotted by taffy as tui leaf node
+    ///
+    /// Content closure receives width allotted to the node in the last layout pass
+    /// (e.g. to show icon-only button when node is narrow). To let taffy both shrink and grow
+    /// the node, content is additionally run in invisible sizing passes with zero and unlimited
+    /// (`f32::INFINITY`) width to measure its narrowest and widest rendering.
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "toolbar").show(|tui| {
+    ///     tui.ui_responsive(|ui, allotted_width| {
+    ///         let text = if allotted_width < 80. { "💾" } else { "💾 Save" };
+    ///         ui.button(text)
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    fn ui_responsive<T>(self, mut content: impl FnMut(&mut Ui, f32) -> T) -> T {
+        self.ui_manual(|ui, _params| {
+            let mut measure = |width: f32| {
+                let mut max_rect = ui.max_rect();
+                max_rect.set_width(width);
+                let mut sizing_ui = ui.new_child(
+                    UiBuilder::new()
+                        .id_salt(("responsive_sizing", width.is_finite()))
+                        .max_rect(max_rect)
+                        .sizing_pass()
+                        .invisible(),
+                );
+                content(&mut sizing_ui, width);
+                sizing_ui.min_size()
+            };
+            let min_size = measure(0.);
+            let max_size = measure(f32::INFINITY);
+
+            let inner = content(ui, ui.available_width());
+            TuiContainerResponse::new(inner, min_size.min(max_size), max_size)
+        })
+    }
+
     /// Add egui ui as tui leaf node and provide custom information about necessary space for this
     /// node for layout calculation
     ///
@@ -1741,6 +5336,7 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
                 intrinsic_size: response.intrinsic_size,
                 max_size: response.rect.size(),
                 infinite: egui::Vec2b::FALSE,
+                first_baseline: None,
                 inner: response,
             };
 
@@ -1748,34 +5344,77 @@ pub trait TuiBuilderLogic<'r>: AsTuiBuilder<'r> + Sized {
         })
     }
 
+    /// Add egui widget as leaf node with fixed size reported to taffy
+    ///
+    /// Node min and max size are set to `size` regardless of the size reported by the widget.
+    /// Useful to wrap third party widgets with known size without implementing [`TuiWidget`].
+    ///
+    /// ```
+    /// use egui_taffy::{tui, TuiBuilderLogic};
+    ///
+    /// # fn example(ui: &mut egui::Ui) {
+    /// tui(ui, "sized").show(|tui| {
+    ///     tui.ui_sized(egui::vec2(120., 24.), |ui| {
+    ///         ui.add_sized(ui.available_size(), egui::Button::new("Fixed size"))
+    ///     });
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    fn ui_sized(self, size: egui::Vec2, f: impl FnOnce(&mut egui::Ui) -> Response) -> Response {
+        self.ui_add_manual(f, |mut val, _ui| {
+            val.min_size = size;
+            val.intrinsic_size = None;
+            val.max_size = size;
+            val
+        })
+    }
+
     /// Add egui label as child node
     #[inline]
     fn label(self, text: impl Into<egui::WidgetText>) -> Response {
-        egui::Label::new(text).taffy_ui(self.tui())
+        add_label(self.tui(), text.into())
     }
 
     /// Add egui colored label as child node
     #[inline]
     fn colored_label(self, color: egui::Color32, text: impl Into<egui::RichText>) -> Response {
-        egui::Label::new(text.into().color(color)).taffy_ui(self.tui())
+        add_label(self.tui(), text.into().color(color).into())
     }
 
     /// Add label as child node with strong visual formatting
     #[inline]
     fn strong(self, text: impl Into<egui::RichText>) -> Response {
-        egui::Label::new(text.into().strong()).taffy_ui(self.tui())
+        add_label(self.tui(), text.into().strong().into())
     }
 
     /// Add egui heading as child node
     #[inline]
     fn heading(self, text: impl Into<egui::RichText>) -> Response {
-        egui::Label::new(text.into().heading()).taffy_ui(self.tui())
+        add_label(self.tui(), text.into().heading().into())
     }
 
     /// Add egui small text as child node
     #[inline]
     fn small(self, text: impl Into<egui::RichText>) -> Response {
-        egui::Label::new(text.into().small()).taffy_ui(self.tui())
+        add_label(self.tui(), text.into().small().into())
+    }
+
+    /// Add label wrapped at the available width to at most `max_rows` rows as child node
+    ///
+    /// Text that does not fit is truncated with an ellipsis (`…`). Node height is the height
+    /// of clamped text, node width can shrink down to the ellipsis width.
+    ///
+    /// ```
+    /// use egui_taffy::TuiBuilderLogic;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// tui.label_clamped("Long description of the card that is shown in two rows at most", 2);
+    /// # }
+    /// ```
+    #[inline]
+    fn label_clamped(self, text: impl Into<egui::WidgetText>, max_rows: usize) -> Response {
+        add_clamped_label(self.tui(), text.into(), max_rows)
     }
 
     /// Add egui separator  as child node
@@ -1801,6 +5440,19 @@ pub struct TuiInnerResponse<R> {
     pub response: egui::Response,
 }
 
+impl<R> TuiInnerResponse<R> {
+    /// Modifier keys held down when the area was clicked, `None` if it was not clicked
+    /// in this frame
+    ///
+    /// Useful for multi-selection lists (e.g. `modifiers.command` to toggle item selection
+    /// and `modifiers.shift` to select range).
+    pub fn clicked_with_modifiers(&self) -> Option<egui::Modifiers> {
+        self.response
+            .clicked()
+            .then(|| self.response.ctx.input(|input| input.modifiers))
+    }
+}
+
 impl<R> std::ops::Deref for TuiInnerResponse<R> {
     type Target = egui::Response;
 
@@ -1936,6 +5588,56 @@ enum InteractiveElementVisualCacheKey {
     Hovered,
 }
 
+/// Paint press ripple of interactive container (see [`TuiBuilderLogic::ripple`])
+///
+/// Ripple origin (relative to container) and start time are stored in egui memory.
+fn paint_ripple(ui: &egui::Ui, response: &Response) {
+    const DURATION: f64 = 0.4;
+
+    let ctx = ui.ctx();
+    let id = response.id.with("ripple");
+    let time = ctx.input(|input| input.time);
+
+    let pressed = ctx.input(|input| input.pointer.any_pressed());
+    if pressed && response.is_pointer_button_down_on() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let origin = pos - response.rect.min;
+            ctx.data_mut(|data| data.insert_temp(id, (origin, time)));
+        }
+    }
+
+    let Some((origin, start)) = ctx.data(|data| data.get_temp::<(egui::Vec2, f64)>(id)) else {
+        return;
+    };
+    let progress = ((time - start) / DURATION) as f32;
+    if progress >= 1. {
+        ctx.data_mut(|data| data.remove::<(egui::Vec2, f64)>(id));
+        return;
+    }
+
+    let rect = response.rect;
+    let center = rect.min + origin;
+    let max_radius = [
+        rect.left_top(),
+        rect.right_top(),
+        rect.left_bottom(),
+        rect.right_bottom(),
+    ]
+    .into_iter()
+    .map(|corner| corner.distance(center))
+    .fold(0., f32::max);
+    let radius = max_radius * egui::emath::easing::cubic_out(progress);
+    let color = ui
+        .visuals()
+        .text_color()
+        .gamma_multiply(0.2 * (1. - progress));
+
+    ui.painter()
+        .with_clip_rect(ui.clip_rect().intersect(rect))
+        .circle_filled(center, radius, color);
+    ctx.request_repaint();
+}
+
 /// Helper function to set up tui visuals based on background response interaction state
 pub fn setup_tui_visuals(tui: &mut Tui, bg_response: &Response) {
     let response = bg_response;
@@ -0,0 +1,284 @@
+//! Chainable builder of [`taffy::Style`]
+//!
+//! Builder separates style construction from tui builder, therefore built style
+//! (or builder itself) can be stored and reused for multiple nodes:
+//!
+//! ```
+//! use egui_taffy::style_builder::StyleBuilder;
+//! use egui_taffy::taffy::prelude::*;
+//!
+//! let style = StyleBuilder::new()
+//!     .flex_column()
+//!     .gap(8.)
+//!     .padding(12.)
+//!     .grow(1.)
+//!     .build();
+//!
+//! assert_eq!(
+//!     style,
+//!     Style {
+//!         display: Display::Flex,
+//!         flex_direction: FlexDirection::Column,
+//!         gap: length(8.),
+//!         padding: length(12.),
+//!         flex_grow: 1.,
+//!         ..Default::default()
+//!     }
+//! );
+//! ```
+//!
+//! Built style is used like any other style:
+//!
+//! ```
+//! use egui_taffy::style_builder::StyleBuilder;
+//! use egui_taffy::{tui, TuiBuilderLogic};
+//!
+//! # fn example(ui: &mut egui::Ui) {
+//! let card = StyleBuilder::new().flex_column().padding(8.).width(120.);
+//!
+//! tui(ui, "cards").show(|tui| {
+//!     for title in ["First", "Second"] {
+//!         tui.style(card.clone().build()).add_with_border(|tui| {
+//!             tui.label(title);
+//!         });
+//!     }
+//! });
+//! # }
+//! ```
+
+use taffy::prelude::*;
+
+/// Chainable builder of [`taffy::Style`], see [module documentation](self)
+///
+/// Lengths are in points. Not covered style fields can be set with [`StyleBuilder::with`].
+#[derive(Debug, Clone, Default, PartialEq)]
+#[must_use]
+pub struct StyleBuilder {
+    style: Style,
+}
+
+impl StyleBuilder {
+    /// Create builder starting from default style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieve built style
+    pub fn build(self) -> Style {
+        self.style
+    }
+
+    /// Modify style fields that do not have builder method
+    pub fn with(mut self, f: impl FnOnce(&mut Style)) -> Self {
+        f(&mut self.style);
+        self
+    }
+
+    /// Set display type
+    pub fn display(mut self, display: Display) -> Self {
+        self.style.display = display;
+        self
+    }
+
+    /// Lay out children in flex row
+    pub fn flex_row(mut self) -> Self {
+        self.style.display = Display::Flex;
+        self.style.flex_direction = FlexDirection::Row;
+        self
+    }
+
+    /// Lay out children in flex column
+    pub fn flex_column(mut self) -> Self {
+        self.style.display = Display::Flex;
+        self.style.flex_direction = FlexDirection::Column;
+        self
+    }
+
+    /// Wrap flex items into multiple lines
+    pub fn wrap(mut self) -> Self {
+        self.style.flex_wrap = FlexWrap::Wrap;
+        self
+    }
+
+    /// Wrap flex items into multiple lines stacked from cross end (the first line is the last)
+    pub fn wrap_reverse(mut self) -> Self {
+        self.style.flex_wrap = FlexWrap::WrapReverse;
+        self
+    }
+
+    /// Lay out children in grid with given column tracks
+    pub fn grid(mut self, columns: Vec<TrackSizingFunction>) -> Self {
+        self.style.display = Display::Grid;
+        self.style.grid_template_columns = columns;
+        self
+    }
+
+    /// Set grid row tracks
+    pub fn grid_rows(mut self, rows: Vec<TrackSizingFunction>) -> Self {
+        self.style.grid_template_rows = rows;
+        self
+    }
+
+    /// Lay out children using block layout
+    pub fn block(mut self) -> Self {
+        self.style.display = Display::Block;
+        self
+    }
+
+    /// Set gap between children in both directions
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.style.gap = length(gap);
+        self
+    }
+
+    /// Set horizontal and vertical gap between children
+    pub fn gap_xy(mut self, x: f32, y: f32) -> Self {
+        self.style.gap = Size {
+            width: length(x),
+            height: length(y),
+        };
+        self
+    }
+
+    /// Set padding on all sides
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.style.padding = length(padding);
+        self
+    }
+
+    /// Set horizontal and vertical padding
+    pub fn padding_xy(mut self, x: f32, y: f32) -> Self {
+        self.style.padding = taffy::Rect {
+            left: length(x),
+            right: length(x),
+            top: length(y),
+            bottom: length(y),
+        };
+        self
+    }
+
+    /// Set margin on all sides
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.style.margin = length(margin);
+        self
+    }
+
+    /// Set horizontal and vertical margin
+    pub fn margin_xy(mut self, x: f32, y: f32) -> Self {
+        self.style.margin = taffy::Rect {
+            left: length(x),
+            right: length(x),
+            top: length(y),
+            bottom: length(y),
+        };
+        self
+    }
+
+    /// Set border width on all sides
+    pub fn border(mut self, border: f32) -> Self {
+        self.style.border = length(border);
+        self
+    }
+
+    /// Set flex grow factor
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.style.flex_grow = grow;
+        self
+    }
+
+    /// Set flex shrink factor
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.style.flex_shrink = shrink;
+        self
+    }
+
+    /// Set flex basis
+    pub fn basis(mut self, basis: f32) -> Self {
+        self.style.flex_basis = length(basis);
+        self
+    }
+
+    /// Set fixed width
+    pub fn width(mut self, width: f32) -> Self {
+        self.style.size.width = length(width);
+        self
+    }
+
+    /// Set fixed height
+    pub fn height(mut self, height: f32) -> Self {
+        self.style.size.height = length(height);
+        self
+    }
+
+    /// Set width as fraction of parent width (`1.` is full width)
+    pub fn width_percent(mut self, fraction: f32) -> Self {
+        self.style.size.width = percent(fraction);
+        self
+    }
+
+    /// Set height as fraction of parent height (`1.` is full height)
+    pub fn height_percent(mut self, fraction: f32) -> Self {
+        self.style.size.height = percent(fraction);
+        self
+    }
+
+    /// Set minimal width
+    pub fn min_width(mut self, width: f32) -> Self {
+        self.style.min_size.width = length(width);
+        self
+    }
+
+    /// Set minimal height
+    pub fn min_height(mut self, height: f32) -> Self {
+        self.style.min_size.height = length(height);
+        self
+    }
+
+    /// Set maximal width
+    pub fn max_width(mut self, width: f32) -> Self {
+        self.style.max_size.width = length(width);
+        self
+    }
+
+    /// Set maximal height
+    pub fn max_height(mut self, height: f32) -> Self {
+        self.style.max_size.height = length(height);
+        self
+    }
+
+    /// Set alignment of children in cross axis
+    pub fn align_items(mut self, align: AlignItems) -> Self {
+        self.style.align_items = Some(align);
+        self
+    }
+
+    /// Set alignment of node in parent cross axis
+    pub fn align_self(mut self, align: AlignSelf) -> Self {
+        self.style.align_self = Some(align);
+        self
+    }
+
+    /// Set distribution of children in main axis
+    pub fn justify_content(mut self, justify: JustifyContent) -> Self {
+        self.style.justify_content = Some(justify);
+        self
+    }
+
+    /// Set overflow behaviour in both directions
+    pub fn overflow(mut self, x: taffy::Overflow, y: taffy::Overflow) -> Self {
+        self.style.overflow = taffy::Point { x, y };
+        self
+    }
+}
+
+impl From<Style> for StyleBuilder {
+    fn from(style: Style) -> Self {
+        Self { style }
+    }
+}
+
+impl From<StyleBuilder> for Style {
+    fn from(builder: StyleBuilder) -> Self {
+        builder.style
+    }
+}
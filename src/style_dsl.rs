@@ -0,0 +1,303 @@
+//! Terse string syntax for [`taffy::Style`]
+//!
+//! Style is described by whitespace separated tokens, `key:value` tokens set style fields:
+//!
+//! ```
+//! use egui_taffy::style_dsl::parse_style;
+//! use egui_taffy::taffy::prelude::*;
+//!
+//! let style = parse_style("flex col gap:8 p:12 grow:1 w:50% h:auto").unwrap();
+//! assert_eq!(style.display, Display::Flex);
+//! assert_eq!(style.flex_direction, FlexDirection::Column);
+//! assert_eq!(style.gap, length(8.));
+//! assert_eq!(style.padding, length(12.));
+//! assert_eq!(style.flex_grow, 1.);
+//! assert_eq!(style.size.width, percent(0.5));
+//!
+//! let style = parse_style("grid cols:100,1fr,2fr rows:auto").unwrap();
+//! assert_eq!(style.grid_template_columns, vec![length(100.), fr(1.), fr(2.)]);
+//!
+//! assert!(parse_style("flex gap:eight").is_err());
+//! ```
+//!
+//! Supported tokens:
+//! * Display: `flex`, `grid`, `block`, `none`
+//! * Flex direction: `row`, `col`, `row-reverse`, `col-reverse`; flex wrap: `wrap`, `nowrap`,
+//!   `wrap-reverse`
+//! * Gap: `gap`, `gap-x`, `gap-y` (length or percent)
+//! * Padding: `p`, `px`, `py`, `pt`, `pr`, `pb`, `pl` (length or percent)
+//! * Margin: `m`, `mx`, `my`, `mt`, `mr`, `mb`, `ml` (length, percent or `auto`)
+//! * Flex item: `grow`, `shrink` (number), `basis` (length, percent or `auto`)
+//! * Size: `w`, `h`, `min-w`, `min-h`, `max-w`, `max-h` (length, percent or `auto`)
+//! * Alignment: `items`, `self` (`start`, `end`, `center`, `stretch`, `baseline`),
+//!   `justify`, `content` (`start`, `end`, `center`, `stretch`, `between`, `around`, `evenly`)
+//! * Grid tracks: `cols`, `rows` (comma separated lengths, percents, `fr` or `auto`)
+//!
+//! Lengths are written as `8` or `8px`, percents as `50%` and fractions as `1fr`.
+//!
+//! Lines of `wrap-reverse` container are stacked from cross end, items keep their order
+//! inside of each line:
+//!
+//! ```
+//! use egui_taffy::style_dsl::parse_style;
+//! use egui_taffy::{taffy, Context, TaffyState};
+//! use taffy::prelude::*;
+//!
+//! // Three 40x10 items in 100 wide container wrap into two lines: [0, 1] and [2]
+//! let item_positions = |dsl: &str| {
+//!     let mut state = TaffyState::new();
+//!     let tree = state.taffy_tree_mut();
+//!     let item = Context::new(egui::vec2(40., 10.), egui::vec2(40., 10.));
+//!     let items: Vec<NodeId> = (0..3)
+//!         .map(|_| tree.new_leaf_with_context(Style::default(), item).unwrap())
+//!         .collect();
+//!     let root = tree
+//!         .new_with_children(parse_style(dsl).unwrap(), &items)
+//!         .unwrap();
+//!     state
+//!         .compute_layout(root, Size::MAX_CONTENT, egui::vec2(100., 100.))
+//!         .unwrap();
+//!     items
+//!         .iter()
+//!         .map(|item| {
+//!             let location = state.taffy_tree().layout(*item).unwrap().location;
+//!             (location.x, location.y)
+//!         })
+//!         .collect::<Vec<_>>()
+//! };
+//!
+//! let wrap = item_positions("flex wrap w:100 h:20");
+//! assert_eq!(wrap, vec![(0., 0.), (40., 0.), (0., 10.)]);
+//!
+//! let wrap_reverse = item_positions("flex wrap-reverse w:100 h:20");
+//! assert_eq!(wrap_reverse, vec![(0., 10.), (40., 10.), (0., 0.)]);
+//! ```
+
+use taffy::prelude::*;
+
+/// Error returned when style string can not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyleDslError {
+    /// Token that failed to parse
+    pub token: String,
+    /// Reason why token could not be parsed
+    pub reason: &'static str,
+}
+
+impl std::fmt::Display for StyleDslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid style token `{}`: {}", self.token, self.reason)
+    }
+}
+
+impl std::error::Error for StyleDslError {}
+
+/// Parse style string into [`taffy::Style`], see [module documentation](self) for syntax
+///
+/// ```
+/// use egui_taffy::style_dsl::parse_style;
+/// use egui_taffy::taffy::prelude::*;
+///
+/// let style = parse_style("m:auto mx:4 mt:10% gap-x:2 gap-y:3").unwrap();
+/// assert_eq!(style.margin.left, length(4.));
+/// assert_eq!(style.margin.right, length(4.));
+/// assert_eq!(style.margin.top, percent(0.1));
+/// assert_eq!(style.margin.bottom, auto());
+/// assert_eq!(style.gap.width, length(2.));
+/// assert_eq!(style.gap.height, length(3.));
+///
+/// let style = parse_style("min-w:20px min-h:auto max-w:100% max-h:50").unwrap();
+/// assert_eq!(style.min_size.width, length(20.));
+/// assert_eq!(style.min_size.height, auto());
+/// assert_eq!(style.max_size.width, percent(1.));
+/// assert_eq!(style.max_size.height, length(50.));
+///
+/// // The first invalid token is reported
+/// let err = parse_style("flex p:auto gap:x").unwrap_err();
+/// assert_eq!(err.token, "p:auto");
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid style token `p:auto`: expected length (8, 8px) or percent (50%)"
+/// );
+/// assert_eq!(parse_style("colum").unwrap_err().reason, "unknown flag");
+/// assert_eq!(parse_style("width:8").unwrap_err().reason, "unknown key");
+///
+/// // Numbers must be finite
+/// for dsl in ["grow:NaN", "w:inf", "h:NaN%", "cols:1,infpx", "shrink:", "basis:8pt"] {
+///     assert!(parse_style(dsl).is_err(), "{dsl}");
+/// }
+/// ```
+pub fn parse_style(dsl: &str) -> Result<Style, StyleDslError> {
+    let mut style = Style::default();
+    for token in dsl.split_whitespace() {
+        apply_token(&mut style, token).map_err(|reason| StyleDslError {
+            token: token.to_owned(),
+            reason,
+        })?;
+    }
+    Ok(style)
+}
+
+fn apply_token(style: &mut Style, token: &str) -> Result<(), &'static str> {
+    let Some((key, value)) = token.split_once(':') else {
+        return apply_flag(style, token);
+    };
+
+    match key {
+        "gap" => {
+            let gap = length_percentage(value)?;
+            style.gap = Size {
+                width: gap,
+                height: gap,
+            };
+        }
+        "gap-x" => style.gap.width = length_percentage(value)?,
+        "gap-y" => style.gap.height = length_percentage(value)?,
+
+        "p" => style.padding = all(length_percentage(value)?),
+        "px" => (style.padding.left, style.padding.right) = both(length_percentage(value)?),
+        "py" => (style.padding.top, style.padding.bottom) = both(length_percentage(value)?),
+        "pt" => style.padding.top = length_percentage(value)?,
+        "pr" => style.padding.right = length_percentage(value)?,
+        "pb" => style.padding.bottom = length_percentage(value)?,
+        "pl" => style.padding.left = length_percentage(value)?,
+
+        "m" => style.margin = all(length_percentage_auto(value)?),
+        "mx" => (style.margin.left, style.margin.right) = both(length_percentage_auto(value)?),
+        "my" => (style.margin.top, style.margin.bottom) = both(length_percentage_auto(value)?),
+        "mt" => style.margin.top = length_percentage_auto(value)?,
+        "mr" => style.margin.right = length_percentage_auto(value)?,
+        "mb" => style.margin.bottom = length_percentage_auto(value)?,
+        "ml" => style.margin.left = length_percentage_auto(value)?,
+
+        "grow" => style.flex_grow = number(value)?,
+        "shrink" => style.flex_shrink = number(value)?,
+        "basis" => style.flex_basis = dimension(value)?,
+
+        "w" => style.size.width = dimension(value)?,
+        "h" => style.size.height = dimension(value)?,
+        "min-w" => style.min_size.width = dimension(value)?,
+        "min-h" => style.min_size.height = dimension(value)?,
+        "max-w" => style.max_size.width = dimension(value)?,
+        "max-h" => style.max_size.height = dimension(value)?,
+
+        "items" => style.align_items = Some(align_items(value)?),
+        "self" => style.align_self = Some(align_items(value)?),
+        "justify" => style.justify_content = Some(align_content(value)?),
+        "content" => style.align_content = Some(align_content(value)?),
+
+        "cols" => style.grid_template_columns = tracks(value)?,
+        "rows" => style.grid_template_rows = tracks(value)?,
+
+        _ => return Err("unknown key"),
+    }
+    Ok(())
+}
+
+fn apply_flag(style: &mut Style, token: &str) -> Result<(), &'static str> {
+    match token {
+        "flex" => style.display = Display::Flex,
+        "grid" => style.display = Display::Grid,
+        "block" => style.display = Display::Block,
+        "none" => style.display = Display::None,
+        "row" => style.flex_direction = FlexDirection::Row,
+        "col" => style.flex_direction = FlexDirection::Column,
+        "row-reverse" => style.flex_direction = FlexDirection::RowReverse,
+        "col-reverse" => style.flex_direction = FlexDirection::ColumnReverse,
+        "wrap" => style.flex_wrap = FlexWrap::Wrap,
+        "nowrap" => style.flex_wrap = FlexWrap::NoWrap,
+        "wrap-reverse" => style.flex_wrap = FlexWrap::WrapReverse,
+        _ => return Err("unknown flag"),
+    }
+    Ok(())
+}
+
+#[inline]
+fn all<T: Copy>(value: T) -> Rect<T> {
+    Rect {
+        left: value,
+        right: value,
+        top: value,
+        bottom: value,
+    }
+}
+
+#[inline]
+fn both<T: Copy>(value: T) -> (T, T) {
+    (value, value)
+}
+
+fn number(value: &str) -> Result<f32, &'static str> {
+    match value.parse::<f32>() {
+        Ok(number) if number.is_finite() => Ok(number),
+        _ => Err("expected number"),
+    }
+}
+
+/// Parse `8`, `8px` or `50%` value
+fn length_or_percent<T: FromLength + FromPercent>(value: &str) -> Result<T, &'static str> {
+    if let Some(value) = value.strip_suffix('%') {
+        return Ok(percent(number(value)? / 100.));
+    }
+    let value = value.strip_suffix("px").unwrap_or(value);
+    number(value)
+        .map(length)
+        .map_err(|_| "expected length (8, 8px) or percent (50%)")
+}
+
+fn length_percentage(value: &str) -> Result<LengthPercentage, &'static str> {
+    length_or_percent(value)
+}
+
+fn length_percentage_auto(value: &str) -> Result<LengthPercentageAuto, &'static str> {
+    if value == "auto" {
+        return Ok(auto());
+    }
+    length_or_percent(value)
+}
+
+fn dimension(value: &str) -> Result<Dimension, &'static str> {
+    if value == "auto" {
+        return Ok(auto());
+    }
+    length_or_percent(value)
+}
+
+fn tracks(value: &str) -> Result<Vec<TrackSizingFunction>, &'static str> {
+    value
+        .split(',')
+        .map(|track| {
+            if track == "auto" {
+                Ok(auto())
+            } else if let Some(flex) = track.strip_suffix("fr") {
+                Ok(fr(number(flex)?))
+            } else {
+                length_or_percent(track)
+            }
+        })
+        .collect()
+}
+
+fn align_items(value: &str) -> Result<AlignItems, &'static str> {
+    Ok(match value {
+        "start" => AlignItems::Start,
+        "end" => AlignItems::End,
+        "center" => AlignItems::Center,
+        "stretch" => AlignItems::Stretch,
+        "baseline" => AlignItems::Baseline,
+        _ => return Err("expected start, end, center, stretch or baseline"),
+    })
+}
+
+fn align_content(value: &str) -> Result<AlignContent, &'static str> {
+    Ok(match value {
+        "start" => AlignContent::Start,
+        "end" => AlignContent::End,
+        "center" => AlignContent::Center,
+        "stretch" => AlignContent::Stretch,
+        "between" => AlignContent::SpaceBetween,
+        "around" => AlignContent::SpaceAround,
+        "evenly" => AlignContent::SpaceEvenly,
+        _ => return Err("expected start, end, center, stretch, between, around or evenly"),
+    })
+}
@@ -1,6 +1,7 @@
 use taffy::prelude::{auto, length};
+use taffy::{NodeId, TaffyTree};
 
-use crate::{tid, Tui, TuiBuilderLogic, TuiId};
+use crate::{tid, Context, Tui, TuiBuilderLogic, TuiId};
 
 /// Required parameters to correctly draw grid with virtual rows
 pub struct VirtualGridRowHelperParams {
@@ -13,17 +14,49 @@ pub struct VirtualGridRowHelperParams {
 /// Helper to draw grid with virtual rows
 pub struct VirtualGridRowHelper;
 
+/// Alignment of cells in virtual grid column
+///
+/// Used by [`VirtualGridRowHelper::show_with_columns`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct VirtualGridColumnAlign {
+    /// Cell `justify_self` style
+    pub justify_self: Option<taffy::JustifySelf>,
+    /// Cell `align_self` style
+    pub align_self: Option<taffy::AlignSelf>,
+}
+
+impl VirtualGridColumnAlign {
+    /// Set cell `justify_self` style
+    #[inline]
+    pub fn justify_self(mut self, justify_self: taffy::JustifySelf) -> Self {
+        self.justify_self = Some(justify_self);
+        self
+    }
+
+    /// Set cell `align_self` style
+    #[inline]
+    pub fn align_self(mut self, align_self: taffy::AlignSelf) -> Self {
+        self.align_self = Some(align_self);
+        self
+    }
+}
+
 /// Information about grid row that needs to be drawn
-pub struct VirtualGridRow {
+pub struct VirtualGridRow<'a> {
     /// Index of data from 0..row_count
     pub idx: usize,
     /// Row position in the grid
     ///
-    /// Use [`GridRow::set_grid_row`] to retrieve closure that will set the style.
+    /// Use [`VirtualGridRow::grid_row_setter`] to retrieve closure that will set the style.
     pub grid_row: u16,
+    /// Only visible rows are drawn and height of all rows is estimated from the first row
+    ///
+    /// Rows should have equal height, see [`VirtualGridRowHelper::show_auto_sized`].
+    pub virtualized: bool,
+    columns: &'a [VirtualGridColumnAlign],
 }
 
-impl VirtualGridRow {
+impl VirtualGridRow<'_> {
     /// Retrieve closure that can be used in `tui.mut_style(_)` to set grid_row parameter.
     #[inline]
     pub fn grid_row_setter(&self) -> impl Fn(&mut taffy::Style) {
@@ -33,6 +66,26 @@ impl VirtualGridRow {
         }
     }
 
+    /// Retrieve closure that can be used in `tui.mut_style(_)` to place cell in given column (0 based)
+    ///
+    /// Sets grid_row, grid_column and column alignment
+    /// provided to [`VirtualGridRowHelper::show_with_columns`].
+    #[inline]
+    pub fn cell_setter(&self, column: usize) -> impl Fn(&mut taffy::Style) {
+        let grid_row = self.grid_row;
+        let alignment = self.columns.get(column).copied().unwrap_or_default();
+        move |style: &mut taffy::Style| {
+            style.grid_row = taffy::style_helpers::line(grid_row as i16);
+            style.grid_column = taffy::style_helpers::line(column as i16 + 1);
+            if alignment.justify_self.is_some() {
+                style.justify_self = alignment.justify_self;
+            }
+            if alignment.align_self.is_some() {
+                style.align_self = alignment.align_self;
+            }
+        }
+    }
+
     /// Retrieve closure that can be used to generate unique ids for elements in the row
     #[inline]
     pub fn id_gen(&self) -> impl FnMut() -> TuiId {
@@ -53,15 +106,204 @@ const fn round_down_to_pow2(value: usize, pow2: u8) -> usize {
     value & !((1 << pow2) - 1)
 }
 
+/// Axis along which items are virtualized
+#[derive(Clone, Copy)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    #[inline]
+    fn of(self, vec: egui::Vec2) -> f32 {
+        match self {
+            Axis::Horizontal => vec.x,
+            Axis::Vertical => vec.y,
+        }
+    }
+}
+
+/// Items that need to be drawn, see [`visible_range`]
+struct VisibleRange {
+    /// First visible item, item 0 is always drawn as reference
+    visible_from: usize,
+    visible_to: usize,
+    /// Item size including gap
+    full_item_size: f32,
+    gap: f32,
+}
+
+/// Calculate virtual items of current node visible along `axis` using the last layout
+///
+/// `first_item` returns offset of the first virtual item from node content start and its size
+/// (`None` if they are not known yet). Size of all items is estimated from the first item.
+fn visible_range(
+    axis: Axis,
+    tui: &Tui,
+    item_count: usize,
+    first_item: impl FnOnce(&TaffyTree<Context>, NodeId) -> Option<(f32, f32)>,
+) -> VisibleRange {
+    // Size used until the first item is laid out
+    const DEFAULT_ITEM_SIZE: f32 = 20.;
+
+    let node_id = tui.current_node();
+    let min_location = axis
+        .of(tui.taffy_container().full_container_with(false).min
+            - tui.current_viewport_content().min);
+
+    let (offset, item_size, gap) = {
+        let taffy_tree = tui.taffy_state().taffy_tree();
+        let style = taffy_tree.style(node_id).ok();
+
+        let gap = match style.map(|style| match axis {
+            Axis::Horizontal => style.gap.width,
+            Axis::Vertical => style.gap.height,
+        }) {
+            Some(taffy::LengthPercentage::Length(length)) => length,
+            // TODO: Percent gap is not supported yet
+            Some(taffy::LengthPercentage::Percent(_)) | None => 0.,
+        };
+
+        let overflow = style.map(|style| match axis {
+            Axis::Horizontal => style.overflow.x,
+            Axis::Vertical => style.overflow.y,
+        });
+        let content_offset = match overflow {
+            Some(taffy::Overflow::Scroll) => 0.,
+            _ => min_location,
+        };
+
+        let (offset, size) = first_item(taffy_tree, node_id).unwrap_or((0., 0.));
+        let size = if size > 0. { size } else { DEFAULT_ITEM_SIZE };
+        (content_offset + offset, size, gap)
+    };
+
+    let full_item_size = item_size + gap;
+
+    let scroll_offset = -(axis.of(tui.last_scroll_offset) + offset);
+    let visible_rect_size = axis.of(tui.current_viewport().size());
+
+    // Round to power of 2 numbers to reduce frequency of taffy layout recalculation
+    let pow2 = 3; // 2^3 = 8
+
+    // How many items should be drawn before and after visible ones
+    let buffer = 4.;
+
+    let visible_from = round_down_to_pow2(
+        ((scroll_offset / full_item_size).floor() - buffer).max(0.) as usize,
+        pow2,
+    )
+    .clamp(1, item_count);
+
+    let visible_to = round_up_to_pow2(
+        (((scroll_offset + visible_rect_size) / full_item_size).ceil() + buffer).max(0.) as usize,
+        pow2,
+    )
+    .clamp(visible_from, item_count);
+
+    VisibleRange {
+        visible_from,
+        visible_to,
+        full_item_size,
+        gap,
+    }
+}
+
+/// Offset and size of the first grid track along `axis` after `header_count` header tracks
+fn grid_first_track(
+    taffy_tree: &TaffyTree<Context>,
+    node_id: NodeId,
+    axis: Axis,
+    header_count: u16,
+) -> Option<(f32, f32)> {
+    let taffy::DetailedLayoutInfo::Grid(info) = taffy_tree.detailed_layout_info(node_id) else {
+        return None;
+    };
+    let tracks = match axis {
+        Axis::Horizontal => &info.columns,
+        Axis::Vertical => &info.rows,
+    };
+
+    let mut offset = 0.;
+    for idx in 0..header_count as usize {
+        let (Some(size), Some(gutter)) = (tracks.sizes.get(idx), tracks.gutters.get(idx)) else {
+            break;
+        };
+        offset += size + gutter;
+    }
+
+    let size = tracks
+        .sizes
+        .get(header_count as usize)
+        .copied()
+        .unwrap_or(0.);
+    Some((offset, size))
+}
+
 impl VirtualGridRowHelper {
     /// Show virtual grid rows.
     ///
     /// Closure receives information about grid row that needs to be drawn.
     /// All virtual rows should have equal heaight. One row will be used to estimate height of all rows.
-    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
-    pub fn show<F>(params: VirtualGridRowHelperParams, tui: &mut Tui, mut draw_line: F)
+    #[inline]
+    pub fn show<F>(params: VirtualGridRowHelperParams, tui: &mut Tui, draw_line: F)
     where
         F: FnMut(&mut Tui, VirtualGridRow),
+    {
+        Self::show_with_columns(params, &[], tui, draw_line);
+    }
+
+    /// Show grid rows, rows are virtualized only if there are more than `virtualize_after` rows
+    ///
+    /// Small grids draw all rows and every row is sized to its tallest cell.
+    /// Large grids are drawn as [`VirtualGridRowHelper::show_with_columns`], height of all rows
+    /// is estimated from the first row. Check [`VirtualGridRow::virtualized`] to keep rows
+    /// of virtualized grid equally tall (e.g. truncate multiline text).
+    pub fn show_auto_sized<F>(
+        params: VirtualGridRowHelperParams,
+        virtualize_after: usize,
+        columns: &[VirtualGridColumnAlign],
+        tui: &mut Tui,
+        mut draw_line: F,
+    ) where
+        F: FnMut(&mut Tui, VirtualGridRow),
+    {
+        let VirtualGridRowHelperParams {
+            row_count,
+            header_row_count,
+        } = params;
+
+        // Grid lines are limited to i16
+        let max_row_count = (i16::MAX as usize).saturating_sub(header_row_count as usize + 1);
+        if row_count > virtualize_after.min(max_row_count) {
+            Self::show_with_columns(params, columns, tui, draw_line);
+            return;
+        }
+
+        for idx in 0..row_count {
+            draw_line(
+                tui,
+                VirtualGridRow {
+                    idx,
+                    grid_row: header_row_count + 1 + idx as u16,
+                    virtualized: false,
+                    columns,
+                },
+            );
+        }
+    }
+
+    /// Show virtual grid rows with per column cell alignment.
+    ///
+    /// Use [`VirtualGridRow::cell_setter`] to apply column alignment to cell style.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    pub fn show_with_columns<F>(
+        params: VirtualGridRowHelperParams,
+        columns: &[VirtualGridColumnAlign],
+        tui: &mut Tui,
+        mut draw_line: F,
+    ) where
+        F: FnMut(&mut Tui, VirtualGridRow),
     {
         let VirtualGridRowHelperParams {
             row_count,
@@ -75,104 +317,28 @@ impl VirtualGridRowHelper {
         let mut grid_row = header_row_count + 1;
 
         // Draw first row for reference
-        draw_line(tui, VirtualGridRow { idx: 0, grid_row });
+        draw_line(
+            tui,
+            VirtualGridRow {
+                idx: 0,
+                grid_row,
+                virtualized: true,
+                columns,
+            },
+        );
 
         if row_count == 1 {
             return;
         }
 
-        let node_id = tui.current_node();
-
-        let min_location = (tui.taffy_container().full_container_with(false).min
-            - tui.current_viewport_content().min)
-            .y;
-
-        let (top_offset, row_height, gap) = {
-            let state = tui.taffy_state();
-
-            let style = state.taffy_tree().style(node_id).unwrap();
-
-            let gap = match style.gap.height {
-                taffy::LengthPercentage::Length(length) => length,
-                taffy::LengthPercentage::Percent(_) => {
-                    // TODO: Not supported yet
-                    0.
-                }
-            };
-
-            let mut top_offset = match style.overflow.y {
-                taffy::Overflow::Visible | taffy::Overflow::Clip | taffy::Overflow::Hidden => {
-                    min_location
-                }
-                taffy::Overflow::Scroll => 0.,
-            };
-            let layout_detailed_info = state.taffy_tree().detailed_layout_info(node_id);
-
-            match layout_detailed_info {
-                taffy::DetailedLayoutInfo::Grid(detailed_grid_info) => {
-                    // Calculate header offset
-                    for idx in 0..((grid_row - 1) as usize) {
-                        if let Some(row_size) = detailed_grid_info.rows.sizes.get(idx) {
-                            top_offset += row_size;
-                        } else {
-                            break;
-                        }
-                        if let Some(gutter) = detailed_grid_info.rows.gutters.get(idx) {
-                            top_offset += gutter;
-                        } else {
-                            break;
-                        }
-                    }
-
-                    let row_height = detailed_grid_info
-                        .rows
-                        .sizes
-                        .get((grid_row - 1) as usize)
-                        .copied()
-                        .unwrap_or(20.);
-
-                    (top_offset, row_height, gap)
-                }
-                taffy::DetailedLayoutInfo::None => (top_offset, 20., gap),
-            }
-        };
-
-        let full_row_height = row_height + gap;
-
-        let scroll_offset = -(tui.last_scroll_offset.y + top_offset);
-        let visible_rect_size = tui.current_viewport().size().y;
-
-        // Round to power of 2 numbers to reduce frequency of taffy layout recalculation
-        // TODO: Maybe store interval in memory?
-        let pow2 = 3; // 2^3 = 8
-
-        // How many items should be drawn at top and bottom
-        let buffer = 4.;
-
-        let visible_from = round_down_to_pow2(
-            ((scroll_offset / full_row_height).floor() - buffer).max(0.) as usize,
-            pow2,
-        )
-        .clamp(1, row_count);
-
-        let visible_to = round_up_to_pow2(
-            (((scroll_offset + visible_rect_size) / full_row_height).ceil() + buffer).max(0.)
-                as usize,
-            pow2,
-        )
-        .clamp(visible_from, row_count);
-
-        // println!(
-        //     "{} {} {} | {} {} {} {} {}",
-        //     visible_from,
-        //     visible_to,
-        //     row_count,
-        //     row_height,
-        //     gap,
-        //     scroll_offset,
-        //     top_offset,
-        //     visible_rect_size
-        // );
+        let VisibleRange {
+            visible_from,
+            visible_to,
+            full_item_size: full_row_height,
+            gap,
+        } = visible_range(Axis::Vertical, tui, row_count, |taffy_tree, node_id| {
+            grid_first_track(taffy_tree, node_id, Axis::Vertical, header_row_count)
+        });
 
         if visible_from > 1 {
             // Draw empty cell from 1..next_visible_from
@@ -207,6 +373,8 @@ impl VirtualGridRowHelper {
                     VirtualGridRow {
                         idx: row_idx,
                         grid_row,
+                        virtualized: true,
+                        columns,
                     },
                 );
             }
@@ -237,3 +405,459 @@ impl VirtualGridRowHelper {
         }
     }
 }
+
+/// Required parameters to correctly draw grid with virtual columns
+pub struct VirtualGridColumnHelperParams {
+    /// Header column count that needs to be skipped in the grid
+    pub header_column_count: u16,
+    /// Data column count in the grid excluding any header columns
+    pub column_count: usize,
+}
+
+/// Helper to draw grid with virtual columns
+///
+/// Columns are virtualized the same way as rows in [`VirtualGridRowHelper`], grid columns
+/// should be auto sized (not set with `grid_template_columns`).
+/// Combine with [`VirtualGridRowHelper`] to virtualize grid in both directions:
+///
+/// ```
+/// use egui_taffy::{taffy, tid, virtual_tui::*, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// let scroll = taffy::Overflow::Scroll;
+/// tui.style(taffy::Style {
+///     display: taffy::Display::Grid,
+///     overflow: taffy::Point { x: scroll, y: scroll },
+///     ..Default::default()
+/// })
+/// .add(|tui| {
+///     let column_params = VirtualGridColumnHelperParams {
+///         header_column_count: 0,
+///         column_count: 1000,
+///     };
+///     let columns = VirtualGridColumnHelper::visible_columns(column_params, tui);
+///     columns.add_spacers(tui);
+///
+///     let row_params = VirtualGridRowHelperParams {
+///         header_row_count: 0,
+///         row_count: 1000,
+///     };
+///     VirtualGridRowHelper::show(row_params, tui, |tui, row| {
+///         for column in columns.iter() {
+///             tui.id(tid(("cell", row.idx, column.idx)))
+///                 .mut_style(row.grid_row_setter())
+///                 .mut_style(column.grid_column_setter())
+///                 .label(format!("{}:{}", row.idx, column.idx));
+///         }
+///     });
+/// });
+/// # }
+/// ```
+pub struct VirtualGridColumnHelper;
+
+/// Information about grid column that needs to be drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualGridColumnInfo {
+    /// Index of data from 0..column_count
+    pub idx: usize,
+    /// Column position in the grid
+    ///
+    /// Use [`VirtualGridColumnInfo::grid_column_setter`] to retrieve closure that will set the style.
+    pub grid_column: u16,
+}
+
+impl VirtualGridColumnInfo {
+    /// Retrieve closure that can be used in `tui.mut_style(_)` to set grid_column parameter.
+    #[inline]
+    pub fn grid_column_setter(&self) -> impl Fn(&mut taffy::Style) {
+        let grid_column = self.grid_column;
+        move |style: &mut taffy::Style| {
+            style.grid_column = taffy::style_helpers::line(grid_column as i16);
+        }
+    }
+
+    /// Retrieve closure that can be used to generate unique ids for elements in the column
+    ///
+    /// Ids are unique only among columns, use ids based on row and column index
+    /// when combined with [`VirtualGridRowHelper`].
+    #[inline]
+    pub fn id_gen(&self) -> impl FnMut() -> TuiId {
+        let idx = self.idx;
+        let mut row_idx = 0;
+        move || {
+            row_idx += 1;
+            tid(("column_cell", idx, row_idx))
+        }
+    }
+}
+
+/// Visible columns of virtual grid, see [`VirtualGridColumnHelper::visible_columns`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualGridColumnRange {
+    header_column_count: u16,
+    column_count: usize,
+    visible_from: usize,
+    visible_to: usize,
+    full_column_width: f32,
+    gap: f32,
+}
+
+impl VirtualGridColumnRange {
+    /// Grid position of first column after header columns
+    #[inline]
+    fn first_grid_column(&self) -> u16 {
+        self.header_column_count + 1
+    }
+
+    /// Columns that need to be drawn: first (reference) column and visible columns
+    pub fn iter(&self) -> impl Iterator<Item = VirtualGridColumnInfo> {
+        let first_grid_column = self.first_grid_column();
+        // Hidden columns before visible ones are replaced with one spacer column
+        let visible_grid_column = first_grid_column + 1 + (self.visible_from > 1) as u16;
+        let visible_from = self.visible_from;
+
+        (self.column_count > 0)
+            .then_some(VirtualGridColumnInfo {
+                idx: 0,
+                grid_column: first_grid_column,
+            })
+            .into_iter()
+            .chain(
+                (self.visible_from..self.visible_to).map(move |idx| VirtualGridColumnInfo {
+                    idx,
+                    grid_column: visible_grid_column + (idx - visible_from) as u16,
+                }),
+            )
+    }
+
+    /// Add empty cells that take space of hidden columns
+    ///
+    /// Cells are placed in the first grid row, add them once per grid.
+    pub fn add_spacers(&self, tui: &mut Tui) {
+        let add_spacer = |tui: &mut Tui, id: &str, grid_column: u16, count: usize| {
+            let size = taffy::Size {
+                width: length((count as f32) * self.full_column_width - self.gap),
+                // Cell with zero height would not contribute to grid content size
+                height: auto(),
+            };
+
+            tui.id(id)
+                .style(taffy::Style {
+                    min_size: size,
+                    size,
+                    max_size: size,
+                    grid_row: taffy::style_helpers::line(1),
+                    grid_column: taffy::style_helpers::line(grid_column as i16),
+                    ..Default::default()
+                })
+                .add_empty();
+        };
+
+        let mut grid_column = self.first_grid_column();
+
+        if self.visible_from > 1 {
+            // Draw empty cell from 1..visible_from
+            grid_column += 1;
+            add_spacer(tui, "left_virtual", grid_column, self.visible_from - 1);
+        }
+
+        grid_column += (self.visible_to - self.visible_from) as u16;
+
+        if self.visible_to < self.column_count {
+            // Draw empty cell from visible_to..column_count
+            grid_column += 1;
+            add_spacer(
+                tui,
+                "right_virtual",
+                grid_column,
+                self.column_count - self.visible_to,
+            );
+        }
+    }
+}
+
+impl VirtualGridColumnHelper {
+    /// Show virtual grid columns.
+    ///
+    /// Closure receives information about grid column that needs to be drawn.
+    /// All virtual columns should have equal width. One column will be used to estimate width
+    /// of all columns.
+    pub fn show<F>(params: VirtualGridColumnHelperParams, tui: &mut Tui, mut draw_column: F)
+    where
+        F: FnMut(&mut Tui, VirtualGridColumnInfo),
+    {
+        let columns = Self::visible_columns(params, tui);
+        for column in columns.iter() {
+            draw_column(tui, column);
+        }
+        columns.add_spacers(tui);
+    }
+
+    /// Calculate visible columns of current grid node from the last layout
+    ///
+    /// Width of all columns is estimated from the first column after header columns.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    pub fn visible_columns(
+        params: VirtualGridColumnHelperParams,
+        tui: &Tui,
+    ) -> VirtualGridColumnRange {
+        let VirtualGridColumnHelperParams {
+            header_column_count,
+            column_count,
+        } = params;
+
+        let mut range = VirtualGridColumnRange {
+            header_column_count,
+            column_count,
+            visible_from: column_count.min(1),
+            visible_to: column_count.min(1),
+            full_column_width: 0.,
+            gap: 0.,
+        };
+
+        if column_count <= 1 {
+            return range;
+        }
+
+        let visible = visible_range(
+            Axis::Horizontal,
+            tui,
+            column_count,
+            |taffy_tree, node_id| {
+                grid_first_track(taffy_tree, node_id, Axis::Horizontal, header_column_count)
+            },
+        );
+
+        range.visible_from = visible.visible_from;
+        range.visible_to = visible.visible_to;
+        range.full_column_width = visible.full_item_size;
+        range.gap = visible.gap;
+        range
+    }
+}
+
+/// Required parameters to correctly draw list with virtual items
+pub struct VirtualListHelperParams {
+    /// Item count in the list
+    pub item_count: usize,
+}
+
+/// Information about list item that needs to be drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualListItem {
+    /// Index of data from 0..item_count
+    pub idx: usize,
+}
+
+impl VirtualListItem {
+    /// Id of item node
+    #[inline]
+    pub fn id(&self) -> TuiId {
+        tid(("list_item", self.idx))
+    }
+}
+
+/// Helper to draw flex column list with virtual items
+///
+/// Only visible items are added as nodes, hidden items are replaced with one spacer node
+/// above and one below visible items. All items should have equal height,
+/// height of all items is estimated from the first item.
+///
+/// ```
+/// use egui_taffy::{taffy, virtual_tui::*, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// let mut style = taffy::Style {
+///     flex_direction: taffy::FlexDirection::Column,
+///     ..Default::default()
+/// };
+/// style.overflow.y = taffy::Overflow::Scroll;
+/// tui.style(style).add(|tui| {
+///     let params = VirtualListHelperParams { item_count: 1000 };
+///     VirtualListHelper::show(params, tui, |tui, item| {
+///         tui.id(item.id()).label(format!("Item {}", item.idx));
+///     });
+/// });
+/// # }
+/// ```
+pub struct VirtualListHelper;
+
+impl VirtualListHelper {
+    /// Show virtual list items
+    ///
+    /// Closure receives information about list item that needs to be drawn.
+    /// Current node should be flex column, items should be its direct children that do not
+    /// shrink (`flex_shrink: 0`), shrunk item height would be used for all items.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    pub fn show<F>(params: VirtualListHelperParams, tui: &mut Tui, mut draw_item: F)
+    where
+        F: FnMut(&mut Tui, VirtualListItem),
+    {
+        let VirtualListHelperParams { item_count } = params;
+
+        if item_count == 0 {
+            return;
+        }
+
+        let first_item_index = tui.current_node_index;
+
+        // Draw first item for reference
+        draw_item(tui, VirtualListItem { idx: 0 });
+
+        if item_count == 1 {
+            return;
+        }
+
+        let VisibleRange {
+            visible_from,
+            visible_to,
+            full_item_size: full_item_height,
+            gap,
+        } = visible_range(Axis::Vertical, tui, item_count, |taffy_tree, node_id| {
+            // Layout of the first item from the last layout pass
+            let child = taffy_tree.child_at_index(node_id, first_item_index).ok()?;
+            let layout = taffy_tree.layout(child).ok()?;
+            Some((layout.location.y, layout.size.height))
+        });
+
+        let add_spacer = |tui: &mut Tui, id: &str, count: usize| {
+            let size = taffy::Size {
+                width: auto(),
+                height: length((count as f32) * full_item_height - gap),
+            };
+
+            tui.id(id)
+                .style(taffy::Style {
+                    min_size: size,
+                    size,
+                    max_size: size,
+                    flex_shrink: 0.,
+                    ..Default::default()
+                })
+                .add_empty();
+        };
+
+        if visible_from > 1 {
+            // Draw empty node in place of items 1..visible_from
+            add_spacer(tui, "top_virtual", visible_from - 1);
+        }
+
+        for idx in visible_from..visible_to {
+            draw_item(tui, VirtualListItem { idx });
+        }
+
+        if visible_to < item_count {
+            // Draw empty node in place of items visible_to..item_count
+            add_spacer(tui, "bottom_virtual", item_count - visible_to);
+        }
+    }
+}
+
+/// Information about virtual tree row that needs to be drawn
+pub struct VirtualTreeRow {
+    /// Index of row in the flattened list of visible tree rows
+    pub idx: usize,
+    /// Indentation depth of row (0 for root level rows)
+    pub depth: usize,
+    /// Row position in the grid
+    pub grid_row: u16,
+    key: egui::Id,
+    indent: f32,
+}
+
+impl VirtualTreeRow {
+    /// Retrieve closure that can be used in `tui.mut_style(_)` to place row node
+    ///
+    /// Sets grid_row and left margin based on row indentation depth, padding is not changed.
+    #[inline]
+    pub fn row_setter(&self) -> impl Fn(&mut taffy::Style) {
+        let grid_row = self.grid_row;
+        let indent = self.depth as f32 * self.indent;
+        move |style: &mut taffy::Style| {
+            style.grid_row = taffy::style_helpers::line(grid_row as i16);
+            style.margin.left = length(indent);
+        }
+    }
+
+    /// Id of row node
+    ///
+    /// Id is based on row key, rows keep their nodes and egui state when rows are inserted
+    /// or removed before them (e.g. directory is expanded).
+    #[inline]
+    pub fn id(&self) -> TuiId {
+        tid(("tree_row", self.key))
+    }
+}
+
+/// Helper to draw tree view (e.g. file tree) with virtual rows
+///
+/// Tree is drawn as single column grid, only visible rows are added as nodes.
+/// Caller provides flattened list of visible rows (children of collapsed rows are skipped),
+/// list can change between frames when rows are expanded or collapsed.
+/// All rows should have equal height.
+///
+/// ```
+/// use egui_taffy::{taffy, virtual_tui::VirtualTreeHelper, TuiBuilderLogic};
+///
+/// struct Row {
+///     path: String,
+///     depth: usize,
+/// }
+///
+/// # fn example(tui: &mut egui_taffy::Tui, rows: &[Row]) {
+/// let mut style = taffy::Style {
+///     display: taffy::Display::Grid,
+///     ..Default::default()
+/// };
+/// style.overflow.y = taffy::Overflow::Scroll;
+/// tui.style(style).add(|tui| {
+///     let depth = |row: &Row| row.depth;
+///     let key = |row: &Row| row.path.clone();
+///     VirtualTreeHelper::show(rows, depth, key, 16., tui, |tui, info, row| {
+///         tui.id(info.id()).mut_style(info.row_setter()).label(&row.path);
+///     });
+/// });
+/// # }
+/// ```
+pub struct VirtualTreeHelper;
+
+impl VirtualTreeHelper {
+    /// Show visible tree rows
+    ///
+    /// `depth` retrieves indentation depth of row, every depth level is indented by `indent`.
+    /// `key` retrieves unique row key used in row node id (see [`VirtualTreeRow::id`]).
+    /// Closure receives information about row that needs to be drawn and the row itself.
+    pub fn show<R, K, F>(
+        rows: &[R],
+        depth: impl Fn(&R) -> usize,
+        key: impl Fn(&R) -> K,
+        indent: f32,
+        tui: &mut Tui,
+        mut draw_row: F,
+    ) where
+        K: std::hash::Hash,
+        F: FnMut(&mut Tui, VirtualTreeRow, &R),
+    {
+        VirtualGridRowHelper::show(
+            VirtualGridRowHelperParams {
+                header_row_count: 0,
+                row_count: rows.len(),
+            },
+            tui,
+            |tui, info| {
+                let row = &rows[info.idx];
+                draw_row(
+                    tui,
+                    VirtualTreeRow {
+                        idx: info.idx,
+                        depth: depth(row),
+                        grid_row: info.grid_row,
+                        key: egui::Id::new(key(row)),
+                        indent,
+                    },
+                    row,
+                );
+            },
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use std::collections::HashSet;
+
 use egui::{Align, Ui, UiBuilder};
-use taffy::prelude::{auto, length};
+use taffy::prelude::{auto, fr, length, line};
 
-use crate::{TuiBuilder, TuiBuilderLogic, TuiWidget};
+use crate::{
+    tid, Tui, TuiBuilder, TuiBuilderLogic, TuiContainerResponse, TuiId, TuiInnerResponse, TuiWidget,
+};
 
 /// Separator that correctly grows in tui environment in both axis
 ///
@@ -84,3 +88,2114 @@ impl TuiWidget for TaffySeparator {
         return_values.background
     }
 }
+
+/// Helper to lay out form as label/field pairs
+///
+/// Form uses two column grid. All labels share first column (`auto` width) and are right
+/// aligned, fields are stretched to fill second column (`1fr` width).
+///
+/// ```ignore
+/// TuiForm::show(tui, |form| {
+///     form.row("Name", |tui| tui.ui_add(egui::TextEdit::singleline(&mut name)));
+///     form.multiline_row("Description", |tui| {
+///         tui.ui_add(egui::TextEdit::multiline(&mut description))
+///     });
+/// });
+/// ```
+pub struct TuiForm<'a> {
+    tui: &'a mut Tui,
+    grid_row: i16,
+}
+
+impl<'a> TuiForm<'a> {
+    /// Show form in a new grid node
+    ///
+    /// Grid display and column template are set up if they are not provided in the builder style.
+    pub fn show<'r, T>(tuib: impl TuiBuilderLogic<'r>, f: impl FnOnce(&mut TuiForm) -> T) -> T {
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Grid;
+            if style.grid_template_columns.is_empty() {
+                style.grid_template_columns = vec![auto(), fr(1.)];
+            }
+        })
+        .add(|tui| {
+            let mut form = TuiForm { tui, grid_row: 0 };
+            f(&mut form)
+        })
+    }
+
+    /// Add label/field pair
+    ///
+    /// Label is vertically centered next to the field.
+    pub fn row<T>(
+        &mut self,
+        label: impl Into<egui::WidgetText>,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        self.row_ext(label, taffy::AlignItems::Center, f)
+    }
+
+    /// Add label/field pair for fields spanning multiple lines (text areas, lists)
+    ///
+    /// Label is aligned with the first line of the field.
+    pub fn multiline_row<T>(
+        &mut self,
+        label: impl Into<egui::WidgetText>,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        self.row_ext(label, taffy::AlignItems::Start, f)
+    }
+
+    /// Add field that spans both label and field columns
+    ///
+    /// Useful for section headings and wide fields.
+    pub fn full_row<T>(&mut self, f: impl FnOnce(&mut Tui) -> T) -> T {
+        self.grid_row += 1;
+        let grid_row = self.grid_row;
+
+        self.tui
+            .id(tid(("form_full_row", grid_row)))
+            .style(taffy::Style {
+                grid_row: line(grid_row),
+                grid_column: taffy::Line {
+                    start: line(1),
+                    end: line(-1),
+                },
+                flex_direction: taffy::FlexDirection::Column,
+                align_items: Some(taffy::AlignItems::Stretch),
+                ..Default::default()
+            })
+            .add(f)
+    }
+
+    fn row_ext<T>(
+        &mut self,
+        label: impl Into<egui::WidgetText>,
+        label_align: taffy::AlignItems,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> T {
+        self.grid_row += 1;
+        let grid_row = self.grid_row;
+
+        self.tui
+            .id(tid(("form_label", grid_row)))
+            .style(taffy::Style {
+                grid_row: line(grid_row),
+                grid_column: line(1),
+                justify_self: Some(taffy::AlignItems::End),
+                align_self: Some(label_align),
+                ..Default::default()
+            })
+            .label(label);
+
+        self.tui
+            .id(tid(("form_field", grid_row)))
+            .style(taffy::Style {
+                grid_row: line(grid_row),
+                grid_column: line(2),
+                justify_self: Some(taffy::AlignItems::Stretch),
+                flex_direction: taffy::FlexDirection::Column,
+                align_items: Some(taffy::AlignItems::Stretch),
+                ..Default::default()
+            })
+            .add(f)
+    }
+
+    /// Access underlaying form grid Tui
+    #[inline]
+    pub fn tui(&mut self) -> &mut Tui {
+        self.tui
+    }
+}
+
+/// Collapsible section with clickable header
+///
+/// Open state is stored in egui memory using tui node id.
+/// States of all sections (including [`TuiCollapsingHeader`]) shown in a tui can be queried
+/// and changed at once (see [`TuiCollapsing::states`] and [`TuiCollapsing::set_all_open`]).
+///
+/// ```ignore
+/// TuiCollapsing::new("Details")
+///     .lazy(true)
+///     .show(tui, |tui| {
+///         tui.label("Expensive content");
+///     });
+/// ```
+pub struct TuiCollapsing {
+    header: egui::WidgetText,
+    default_open: bool,
+    lazy: bool,
+}
+
+impl TuiCollapsing {
+    /// Create collapsible section with given header text
+    pub fn new(header: impl Into<egui::WidgetText>) -> Self {
+        Self {
+            header: header.into(),
+            default_open: false,
+            lazy: false,
+        }
+    }
+
+    /// Should section be open when it is shown for the first time (default: false)
+    #[inline]
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Skip running body closure while section is collapsed (default: false)
+    ///
+    /// By default body is laid out and hidden while collapsed.
+    /// Lazy section draws zero height placeholder with the same node id instead.
+    #[inline]
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Ids and open states of collapsible sections shown in the tui in the last frame
+    ///
+    /// `taffy_id` is the id of the tui instance ([`Tui::main_taffy_id`]).
+    /// Returned ids can be passed to [`TuiCollapsing::set_open`].
+    pub fn states(ctx: &egui::Context, taffy_id: egui::Id) -> Vec<(egui::Id, bool)> {
+        let registry: CollapsingRegistry = ctx.data(|data| {
+            data.get_temp(collapsing_registry_id(taffy_id))
+                .unwrap_or_default()
+        });
+
+        registry
+            .shown_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let state = egui::collapsing_header::CollapsingState::load(ctx, id)?;
+                Some((id, state.is_open()))
+            })
+            .collect()
+    }
+
+    /// Open or close collapsible section with given state id
+    pub fn set_open(ctx: &egui::Context, id: egui::Id, open: bool) {
+        if let Some(mut state) = egui::collapsing_header::CollapsingState::load(ctx, id) {
+            state.set_open(open);
+            state.store(ctx);
+        }
+    }
+
+    /// Open or close all collapsible sections in the tui
+    ///
+    /// Shown sections are changed immediately, other sections (e.g. nested in collapsed
+    /// section) are changed when they are shown.
+    /// `taffy_id` is the id of the tui instance ([`Tui::main_taffy_id`]).
+    ///
+    /// ```
+    /// use egui_taffy::widgets::TuiCollapsing;
+    ///
+    /// # fn example(tui: &mut egui_taffy::Tui) {
+    /// if tui.egui_ui_mut().button("Expand all").clicked() {
+    ///     TuiCollapsing::set_all_open(tui.egui_ctx(), tui.main_taffy_id(), true);
+    /// }
+    /// # }
+    /// ```
+    pub fn set_all_open(ctx: &egui::Context, taffy_id: egui::Id, open: bool) {
+        let ids = ctx.data_mut(|data| {
+            let registry: &mut CollapsingRegistry =
+                data.get_temp_mut_or_default(collapsing_registry_id(taffy_id));
+            registry.open = Some(open);
+            registry.applied = registry.shown_ids();
+            registry.applied.clone()
+        });
+        for id in ids {
+            Self::set_open(ctx, id, open);
+        }
+        ctx.request_repaint();
+    }
+
+    /// Show collapsible section in a new node
+    ///
+    /// Returns header response and body closure return value
+    /// (`None` if body closure was skipped).
+    pub fn show<'r, T>(
+        self,
+        tuib: impl TuiBuilderLogic<'r>,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiInnerResponse<Option<T>> {
+        let TuiCollapsing {
+            header,
+            default_open,
+            lazy,
+        } = self;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Column;
+            if style.align_items.is_none() {
+                style.align_items = Some(taffy::AlignItems::Stretch);
+            }
+        })
+        .add(|tui| {
+            let state_id = tui.current_id().with("collapsing");
+            let (state, _, response) = collapsing_header(
+                tui,
+                tid("collapsing_header"),
+                state_id,
+                default_open,
+                header,
+            );
+
+            // Body node keeps the same id in all states
+            let body = tui.id(tid("collapsing_body"));
+            let inner = if state.is_open() {
+                Some(
+                    body.style(taffy::Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Stretch),
+                        ..Default::default()
+                    })
+                    .add(f),
+                )
+            } else if lazy {
+                body.add_empty();
+                None
+            } else {
+                let size = taffy::Size {
+                    width: auto(),
+                    height: length(0.),
+                };
+                Some(
+                    body.style(taffy::Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Stretch),
+                        size,
+                        max_size: size,
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Visible,
+                            y: taffy::Overflow::Hidden,
+                        },
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        tui.egui_ui_mut().set_invisible();
+                        f(tui)
+                    }),
+                )
+            };
+
+            TuiInnerResponse { inner, response }
+        })
+    }
+}
+
+/// Collapsible sections of one tui stored in egui memory
+#[derive(Clone, Default)]
+struct CollapsingRegistry {
+    /// Open state set by [`TuiCollapsing::set_all_open`]
+    open: Option<bool>,
+    /// Sections that already have `open` state applied
+    applied: HashSet<egui::Id>,
+    /// Sections shown in the previous pass
+    shown: HashSet<egui::Id>,
+    /// Sections shown in the current pass
+    current: HashSet<egui::Id>,
+    /// Pass of `current` sections
+    pass_nr: u64,
+}
+
+impl CollapsingRegistry {
+    /// Sections shown in the previous or current pass
+    fn shown_ids(&self) -> HashSet<egui::Id> {
+        self.shown.union(&self.current).copied().collect()
+    }
+}
+
+/// Id of [`CollapsingRegistry`] stored in egui memory
+fn collapsing_registry_id(taffy_id: egui::Id) -> egui::Id {
+    taffy_id.with("collapsing_states")
+}
+
+/// Show clickable header with animated icon of collapsible section and toggle its state on click
+///
+/// Returns state after toggle, openness before toggle and header response.
+fn collapsing_header(
+    tui: &mut Tui,
+    header_id: TuiId,
+    state_id: egui::Id,
+    default_open: bool,
+    header: egui::WidgetText,
+) -> (
+    egui::collapsing_header::CollapsingState,
+    f32,
+    egui::Response,
+) {
+    let ctx = tui.egui_ctx().clone();
+    let mut state = load_collapsing_state(tui, state_id, default_open);
+    let openness = state.openness(&ctx);
+    let gap = tui.egui_ui().spacing().item_spacing.x;
+
+    let response = tui
+        .id(header_id)
+        .style(taffy::Style {
+            flex_direction: taffy::FlexDirection::Row,
+            align_items: Some(taffy::AlignItems::Center),
+            gap: length(gap),
+            ..Default::default()
+        })
+        .clickable(|tui| {
+            tui.ui(|ui| {
+                let size = egui::Vec2::splat(ui.spacing().icon_width);
+                let (_, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+                egui::collapsing_header::paint_default_icon(ui, openness, &response);
+            });
+            tui.label(header);
+        })
+        .response;
+
+    if response.clicked() {
+        state.toggle(tui.egui_ui());
+    }
+    state.store(&ctx);
+
+    (state, openness, response)
+}
+
+/// Load collapsing state and register section as shown in the current pass
+///
+/// Section that was not shown since [`TuiCollapsing::set_all_open`] call gets its open state.
+fn load_collapsing_state(
+    tui: &Tui,
+    state_id: egui::Id,
+    default_open: bool,
+) -> egui::collapsing_header::CollapsingState {
+    let ctx = tui.egui_ctx();
+    let pass_nr = ctx.cumulative_pass_nr();
+    let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+        ctx,
+        state_id,
+        default_open,
+    );
+
+    ctx.data_mut(|data| {
+        let registry: &mut CollapsingRegistry =
+            data.get_temp_mut_or_default(collapsing_registry_id(tui.main_taffy_id()));
+        if registry.pass_nr != pass_nr {
+            // Sections that are not shown anymore are dropped
+            registry.pass_nr = pass_nr;
+            registry.shown = std::mem::take(&mut registry.current);
+        }
+        registry.current.insert(state_id);
+
+        if let Some(open) = registry.open {
+            if registry.applied.insert(state_id) {
+                state.set_open(open);
+            }
+        }
+    });
+
+    state
+}
+
+/// Collapsible section that adds header and body as sibling nodes of the current node
+///
+/// Unlike [`TuiCollapsing`] header and body are not wrapped in a container node, therefore
+/// they are laid out directly by parent flex or grid layout. Body node is added only while
+/// section is open (or animating), collapsed body is removed from taffy tree.
+///
+/// Open state is stored in egui memory using current node id and id salt
+/// (header text by default).
+///
+/// ```
+/// use egui_taffy::{widgets::TuiCollapsingHeader, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// TuiCollapsingHeader::new("Advanced")
+///     .default_open(true)
+///     .show(tui, |tui| {
+///         tui.label("Body");
+///     });
+/// # }
+/// ```
+pub struct TuiCollapsingHeader {
+    header: egui::WidgetText,
+    id_salt: egui::Id,
+    default_open: bool,
+}
+
+/// Response of [`TuiCollapsingHeader::show`]
+pub struct TuiCollapsingHeaderResponse<T> {
+    /// Response of clickable header node
+    pub header_response: egui::Response,
+    /// Was body node added in this frame (section is open or animating)
+    pub body_shown: bool,
+    /// Value returned by body closure (`None` if body was not shown)
+    pub body_returned: Option<T>,
+}
+
+impl TuiCollapsingHeader {
+    /// Create collapsible section with given header text
+    pub fn new(header: impl Into<egui::WidgetText>) -> Self {
+        let header = header.into();
+        Self {
+            id_salt: egui::Id::new(header.text()),
+            header,
+            default_open: false,
+        }
+    }
+
+    /// Use custom id salt instead of header text to store open state
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = egui::Id::new(id_salt);
+        self
+    }
+
+    /// Should section be open when it is shown for the first time (default: false)
+    #[inline]
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Add header node and (if open) body node to the current node
+    pub fn show<T>(
+        self,
+        tui: &mut Tui,
+        f: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiCollapsingHeaderResponse<T> {
+        let TuiCollapsingHeader {
+            header,
+            id_salt,
+            default_open,
+        } = self;
+
+        let ctx = tui.egui_ctx().clone();
+        let state_id = tui.current_id().with(id_salt);
+        let (_, openness, header_response) = collapsing_header(
+            tui,
+            tid(("collapsing_header", id_salt)),
+            state_id,
+            default_open,
+            header,
+        );
+
+        if openness <= 0. {
+            // Body node is not added, it is removed from the tree
+            return TuiCollapsingHeaderResponse {
+                header_response,
+                body_shown: false,
+                body_returned: None,
+            };
+        }
+
+        // Height of fully open body is remembered to animate body height
+        let height_id = state_id.with("body_height");
+        let open_height = ctx.data(|data| data.get_temp::<f32>(height_id));
+        let max_height = match open_height {
+            Some(height) if openness < 1. => length(openness * height),
+            _ => auto(),
+        };
+
+        let body = tui
+            .id(tid(("collapsing_body", id_salt)))
+            .style(taffy::Style {
+                flex_direction: taffy::FlexDirection::Column,
+                align_items: Some(taffy::AlignItems::Stretch),
+                max_size: taffy::Size {
+                    width: auto(),
+                    height: max_height,
+                },
+                overflow: taffy::Point {
+                    x: taffy::Overflow::Visible,
+                    y: taffy::Overflow::Hidden,
+                },
+                ..Default::default()
+            })
+            .add_with_background_ui(
+                |_ui, container| container.full_container().height(),
+                |tui, _| f(tui),
+            );
+
+        if openness >= 1. {
+            ctx.data_mut(|data| data.insert_temp(height_id, body.background));
+        }
+
+        TuiCollapsingHeaderResponse {
+            header_response,
+            body_shown: true,
+            body_returned: Some(body.main),
+        }
+    }
+}
+
+/// Helper to lay out inline content (text mixed with small widgets) that wraps like text
+///
+/// Text is split in words, every word is a separate node, therefore text can wrap at the
+/// available width together with inline widgets.
+///
+/// Inline egui widgets do not report text baseline to taffy. Single line items are aligned at their
+/// vertical center instead, which places text baselines of the same font on one line
+/// (egui widgets center their text vertically).
+///
+/// ```ignore
+/// TuiInlineFlow::show(tui, |flow| {
+///     flow.text("Press");
+///     let _ = flow.tui().ui_add(egui::Button::new("here"));
+///     flow.text("to continue reading this sentence.");
+/// });
+/// ```
+pub struct TuiInlineFlow<'a> {
+    tui: &'a mut Tui,
+}
+
+impl<'a> TuiInlineFlow<'a> {
+    /// Show inline content in a new wrapping node
+    pub fn show<'r, T>(
+        tuib: impl TuiBuilderLogic<'r>,
+        f: impl FnOnce(&mut TuiInlineFlow) -> T,
+    ) -> T {
+        let tuib = tuib.tui();
+        let ui = tuib.builder_tui().egui_ui();
+        let font_id = egui::TextStyle::Body.resolve(ui.style());
+        let space_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, ' '));
+        let line_gap = ui.spacing().item_spacing.y;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Row;
+            style.flex_wrap = taffy::FlexWrap::Wrap;
+            style.align_items = Some(taffy::AlignItems::Center);
+            style.gap = taffy::Size {
+                width: length(space_width),
+                height: length(line_gap),
+            };
+        })
+        .add(|tui| {
+            let mut flow = TuiInlineFlow { tui };
+            f(&mut flow)
+        })
+    }
+
+    /// Add text, every word is added as separate label node
+    pub fn text(&mut self, text: &str) {
+        for word in text.split_whitespace() {
+            self.tui.label(word);
+        }
+    }
+
+    /// Add text with custom formatting, every word is added as separate label node
+    pub fn rich_text(&mut self, text: &str, format: impl Fn(egui::RichText) -> egui::RichText) {
+        for word in text.split_whitespace() {
+            self.tui.label(format(egui::RichText::new(word)));
+        }
+    }
+
+    /// Add text block wrapped at `max_width` as a single node
+    ///
+    /// Block size is measured with [`Tui::measure_wrapped`] ahead of layout, therefore block
+    /// keeps its wrapped size on wider lines and flow is placed correctly in the first frame.
+    pub fn wrapped_text(&mut self, text: impl Into<egui::WidgetText>, max_width: f32) {
+        let text = text.into();
+        let size = self.tui.measure_wrapped(text.clone(), max_width).ceil();
+        self.tui
+            .style(taffy::Style {
+                size: taffy::Size {
+                    width: length(size.x),
+                    height: length(size.y),
+                },
+                flex_shrink: 0.,
+                ..Default::default()
+            })
+            .ui_add(egui::Label::new(text).wrap());
+    }
+
+    /// Access underlaying Tui to add inline widgets
+    #[inline]
+    pub fn tui(&mut self) -> &mut Tui {
+        self.tui
+    }
+}
+
+/// Image that scales down to fit taffy node size while preserving aspect ratio
+///
+/// Image is not scaled above its original size unless [`TaffyImage::upscale`] is enabled.
+/// Node is measured using original image size, use taffy style (size, grow, stretch)
+/// to make node larger than image. Node `aspect_ratio` is set from original image size
+/// (if not set in style), therefore node keeps image proportions when one of its sizes flexes.
+///
+/// [`egui::Image`] added with [`TuiBuilderLogic::ui_add`] uses this implementation.
+///
+/// ```
+/// use egui_taffy::{taffy, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui) {
+/// // Image fills row width, height follows image aspect ratio
+/// let grow = taffy::Style {
+///     flex_grow: 1.,
+///     ..Default::default()
+/// };
+/// tui.style(grow).ui_add(egui::Image::new("https://example.com/photo.png"));
+/// # }
+/// ```
+pub struct TaffyImage<'a> {
+    image: egui::Image<'a>,
+    upscale: bool,
+    vector: bool,
+}
+
+/// Relative size change after which vector image is rasterized again
+const VECTOR_RERASTER_THRESHOLD: f32 = 0.1;
+
+impl<'a> TaffyImage<'a> {
+    /// Create new taffy image from egui image
+    pub fn new(image: egui::Image<'a>) -> Self {
+        Self {
+            image,
+            upscale: false,
+            vector: false,
+        }
+    }
+
+    /// Allow image to grow above its original size to fill taffy node
+    #[inline]
+    pub fn upscale(mut self, upscale: bool) -> Self {
+        self.upscale = upscale;
+        self
+    }
+
+    /// Treat image as vector image (e.g. SVG) that is rasterized at its displayed size
+    ///
+    /// Image scales to fill taffy node (implies [`TaffyImage::upscale`]) and stays crisp.
+    /// Image is rasterized again only when displayed size changes by more than 10%,
+    /// to avoid rasterizing on every size change while node is resized.
+    ///
+    /// Vector image is painted without interaction sense, use container
+    /// (e.g. [`TuiBuilderLogic::clickable`]) to interact with it.
+    #[inline]
+    pub fn vector(mut self, vector: bool) -> Self {
+        self.vector = vector;
+        self
+    }
+}
+
+impl TuiWidget for TaffyImage<'_> {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, mut tui: TuiBuilder) -> Self::Response {
+        let TaffyImage {
+            image,
+            upscale,
+            vector,
+        } = self;
+
+        let ctx = tui.builder_tui().egui_ctx();
+        let poll = image.load_for_size(ctx, egui::Vec2::INFINITY);
+        if let Ok(egui::load::TexturePoll::Pending { .. }) = poll {
+            // Node is measured again once image is loaded
+            ctx.request_repaint();
+        }
+        let original_size = poll.ok().and_then(|poll| poll.size());
+
+        if let Some(size) = original_size {
+            if size.x > 0. && size.y > 0. {
+                tui = tui.mut_style(|style| {
+                    if style.aspect_ratio.is_none() {
+                        style.aspect_ratio = Some(size.x / size.y);
+                    }
+                });
+            }
+        }
+
+        tui.ui_add_manual(
+            |ui| {
+                if vector {
+                    return vector_image_ui(ui, image, original_size);
+                }
+
+                let mut max_size = ui.available_size();
+                if let (false, Some(original_size)) = (upscale, original_size) {
+                    max_size = max_size.min(original_size);
+                }
+                ui.add(image.max_size(max_size))
+            },
+            |mut val, _ui| {
+                // Image can shrink to any size
+                val.min_size = egui::Vec2::ZERO;
+                val.intrinsic_size = original_size;
+                if let Some(original_size) = original_size {
+                    val.max_size = original_size;
+                }
+                // Until image size is known, node has size of loading placeholder
+                val
+            },
+        )
+    }
+}
+
+/// Paint vector image rasterized at size close to its displayed size
+///
+/// Raster is stored per widget (egui texture cache stores one texture per uri),
+/// so the same image can be displayed crisp at several sizes.
+fn vector_image_ui(
+    ui: &mut Ui,
+    image: egui::Image<'_>,
+    original_size: Option<egui::Vec2>,
+) -> egui::Response {
+    let display_size = image.calc_size(ui.available_size(), original_size);
+    let source = image.source(ui.ctx());
+    let uri = match source.uri() {
+        Some(uri) if display_size.is_finite() && display_size.min_elem() >= 1. => uri,
+        // Nothing to rasterize (texture source or node is being measured), paint image as is
+        _ => return ui.add(image.max_size(ui.available_size())),
+    };
+
+    let raster_id = ui.id().with("vector_raster");
+    let last_raster: Option<(egui::Vec2, egui::TextureHandle)> =
+        ui.data(|data| data.get_temp(raster_id));
+
+    // Keep last raster while displayed size stays close to its size
+    let mut texture = None;
+    let mut result = Ok(());
+    match last_raster {
+        Some((raster_size, handle))
+            if (display_size - raster_size).abs().max_elem()
+                <= raster_size.max_elem() * VECTOR_RERASTER_THRESHOLD =>
+        {
+            texture = Some(handle);
+        }
+        last_raster => {
+            let pixels = (display_size * ui.ctx().pixels_per_point()).round();
+            let size_hint = egui::SizeHint::Size(pixels.x as u32, pixels.y as u32);
+            match ui.ctx().try_load_image(uri, size_hint) {
+                Ok(egui::load::ImagePoll::Ready { image }) => {
+                    let handle = ui.ctx().load_texture(uri, image, Default::default());
+                    ui.data_mut(|data| data.insert_temp(raster_id, (display_size, handle.clone())));
+                    texture = Some(handle);
+                }
+                // Paint previous raster until new one is loaded
+                Ok(egui::load::ImagePoll::Pending { .. }) => {
+                    texture = last_raster.map(|(_, handle)| handle);
+                }
+                Err(err) => result = Err(err),
+            }
+        }
+    }
+
+    let (rect, response) = ui.allocate_exact_size(display_size, egui::Sense::hover());
+    match (texture, result) {
+        (Some(texture), _) => {
+            let texture = egui::load::SizedTexture::from_handle(&texture);
+            egui::paint_texture_at(ui.painter(), rect, image.image_options(), &texture);
+            response
+        }
+        (None, Ok(())) => {
+            egui::Spinner::new().paint_at(ui, rect);
+            response
+        }
+        (None, Err(err)) => response.on_hover_text(err.to_string()),
+    }
+}
+
+/// Selectable label that fills taffy node width like a full width list item
+///
+/// Unlike [`egui::SelectableLabel`], which is drawn with its text width, highlight and
+/// interaction area stretch to the node width (e.g. `align_items: Stretch` in a column),
+/// while node height is kept. Node is measured using text size.
+pub struct TaffySelectableLabel {
+    selected: bool,
+    text: egui::WidgetText,
+}
+
+impl TaffySelectableLabel {
+    /// Create new selectable label
+    pub fn new(selected: bool, text: impl Into<egui::WidgetText>) -> Self {
+        Self {
+            selected,
+            text: text.into(),
+        }
+    }
+}
+
+impl TuiWidget for TaffySelectableLabel {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tui: TuiBuilder) -> Self::Response {
+        let TaffySelectableLabel { selected, text } = self;
+
+        tui.ui_add_manual(
+            |ui| {
+                // Size of egui::SelectableLabel drawn with text width
+                let galley = text.clone().into_galley(
+                    ui,
+                    Some(egui::TextWrapMode::Extend),
+                    f32::INFINITY,
+                    egui::TextStyle::Button,
+                );
+                let mut intrinsic_size = galley.size() + 2. * ui.spacing().button_padding;
+                intrinsic_size.y = intrinsic_size.y.max(ui.spacing().interact_size.y);
+
+                let mut response = ui
+                    .with_layout(egui::Layout::top_down_justified(Align::Min), |ui| {
+                        ui.add(egui::SelectableLabel::new(selected, text))
+                    })
+                    .inner;
+                response.intrinsic_size = Some(intrinsic_size);
+                response
+            },
+            |mut val, _ui| {
+                // Drawn rect fills node, measure node using text size
+                if let Some(intrinsic_size) = val.intrinsic_size {
+                    val.max_size = intrinsic_size;
+                }
+                val
+            },
+        )
+    }
+}
+
+/// Event returned by [`TuiChipInput`], caller applies it to the chip list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChipInputEvent {
+    /// Entered text was submitted with enter key (text is trimmed)
+    Add(String),
+    /// Chip with given index should be removed
+    /// (remove button was clicked or backspace was pressed in empty text field)
+    Remove(usize),
+}
+
+/// Tag input with removable chips followed by text field, chips wrap like text
+///
+/// ```ignore
+/// let response = TuiChipInput::new(&mut text)
+///     .hint_text("Add tag")
+///     .show(tui, tags.iter().map(String::as_str));
+/// for event in response.inner {
+///     match event {
+///         ChipInputEvent::Add(tag) => tags.push(tag),
+///         ChipInputEvent::Remove(idx) => _ = tags.remove(idx),
+///     }
+/// }
+/// ```
+pub struct TuiChipInput<'a> {
+    text: &'a mut String,
+    hint_text: egui::WidgetText,
+    min_input_width: f32,
+}
+
+impl<'a> TuiChipInput<'a> {
+    /// Create chip input editing given text field value
+    pub fn new(text: &'a mut String) -> Self {
+        Self {
+            text,
+            hint_text: Default::default(),
+            min_input_width: 60.,
+        }
+    }
+
+    /// Text shown in empty text field
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<egui::WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// Minimal width of text field (default: 60), text field fills the rest of the last line
+    #[inline]
+    pub fn min_input_width(mut self, min_input_width: f32) -> Self {
+        self.min_input_width = min_input_width;
+        self
+    }
+
+    /// Show chips and text field in a new wrapping node
+    ///
+    /// Returns text field response and events produced in this frame.
+    pub fn show<'r>(
+        self,
+        tuib: impl TuiBuilderLogic<'r>,
+        chips: impl IntoIterator<Item = impl Into<egui::WidgetText>>,
+    ) -> TuiInnerResponse<Vec<ChipInputEvent>> {
+        let TuiChipInput {
+            text,
+            hint_text,
+            min_input_width,
+        } = self;
+
+        let tuib = tuib.tui();
+        let gap = tuib.builder_tui().egui_ui().spacing().item_spacing;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Row;
+            style.flex_wrap = taffy::FlexWrap::Wrap;
+            style.align_items = Some(taffy::AlignItems::Center);
+            style.gap = taffy::Size {
+                width: length(gap.x),
+                height: length(gap.y),
+            };
+        })
+        .add(|tui| {
+            let mut events = Vec::new();
+
+            let mut chip_count = 0;
+            for (idx, chip) in chips.into_iter().enumerate() {
+                chip_count += 1;
+                let padding = tui.egui_ui().spacing().button_padding;
+                tui.style(taffy::Style {
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(2.),
+                    padding: taffy::Rect {
+                        left: length(padding.x),
+                        right: length(2.),
+                        top: length(0.),
+                        bottom: length(0.),
+                    },
+                    ..Default::default()
+                })
+                .add_with_background_ui(
+                    |ui, container| {
+                        let rect = container.full_container();
+                        ui.painter().rect_filled(
+                            rect,
+                            rect.height() / 2.,
+                            ui.visuals().widgets.inactive.weak_bg_fill,
+                        );
+                    },
+                    |tui, _| {
+                        tui.label(chip);
+                        if tui
+                            .ui_add(egui::Button::new("×").frame(false).small())
+                            .clicked()
+                        {
+                            events.push(ChipInputEvent::Remove(idx));
+                        }
+                    },
+                );
+            }
+
+            // Backspace removes last chip only if text field was empty before this key press
+            let was_empty = text.is_empty();
+            // Text field keeps its id (and focus) when chips are added or removed
+            let text_id = tui.current_id().with("chip_input_text");
+            let response = tui
+                .id(tid("chip_input_text"))
+                .style(taffy::Style {
+                    flex_grow: 1.,
+                    ..Default::default()
+                })
+                .ui_add_manual(
+                    |ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(text)
+                                .id(text_id)
+                                .hint_text(hint_text)
+                                .desired_width(ui.available_width()),
+                        )
+                    },
+                    |mut val, _ui| {
+                        // Text field fills node, node grows from minimal width
+                        let size = egui::Vec2::new(min_input_width, val.min_size.y);
+                        val.min_size = size;
+                        val.max_size = size;
+                        val.intrinsic_size = None;
+                        val
+                    },
+                );
+
+            let (enter, backspace) = tui.egui_ui().input(|input| {
+                (
+                    input.key_pressed(egui::Key::Enter),
+                    input.key_pressed(egui::Key::Backspace),
+                )
+            });
+            if response.lost_focus() && enter {
+                let value = text.trim();
+                if !value.is_empty() {
+                    events.push(ChipInputEvent::Add(value.to_owned()));
+                }
+                text.clear();
+                // Keep typing next chip
+                response.request_focus();
+            } else if response.has_focus() && was_empty && backspace && chip_count > 0 {
+                events.push(ChipInputEvent::Remove(chip_count - 1));
+            }
+
+            TuiInnerResponse {
+                inner: events,
+                response,
+            }
+        })
+    }
+}
+
+/// Horizontal step indicator (e.g. progress of multi-step wizard)
+///
+/// Steps are drawn as numbered circles with labels below them, connected by separator lines
+/// that stretch to fill the row (`flex_grow`). Steps before the current step are drawn
+/// as completed.
+///
+/// ```ignore
+/// if let Some(step) = TuiStepper::new(["Cart", "Shipping", "Payment"], current).show(tui) {
+///     current = step;
+/// }
+/// ```
+pub struct TuiStepper {
+    steps: Vec<egui::WidgetText>,
+    current: usize,
+}
+
+impl TuiStepper {
+    /// Create stepper with given step labels and current step index
+    pub fn new(
+        steps: impl IntoIterator<Item = impl Into<egui::WidgetText>>,
+        current: usize,
+    ) -> Self {
+        Self {
+            steps: steps.into_iter().map(Into::into).collect(),
+            current,
+        }
+    }
+
+    /// Show stepper in a new flex row node
+    ///
+    /// Returns index of step that was clicked in this frame.
+    pub fn show<'r>(self, tuib: impl TuiBuilderLogic<'r>) -> Option<usize> {
+        let TuiStepper { steps, current } = self;
+
+        let tuib = tuib.tui();
+        let ui = tuib.builder_tui().egui_ui();
+        let diameter = ui.spacing().interact_size.y + 4.;
+        let gap = ui.spacing().item_spacing.x;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Row;
+            style.align_items = Some(taffy::AlignItems::Start);
+            style.gap = length(gap);
+        })
+        .add(|tui| {
+            let mut clicked = None;
+            let count = steps.len();
+
+            for (idx, label) in steps.into_iter().enumerate() {
+                let response = tui
+                    .style(taffy::Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Center),
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .clickable(|tui| {
+                        tui.ui(|ui| {
+                            let (rect, _) = ui.allocate_exact_size(
+                                egui::Vec2::splat(diameter),
+                                egui::Sense::hover(),
+                            );
+                            paint_step_circle(ui, rect, idx, current);
+                        });
+                        match idx == current {
+                            true => tui.label(label.strong()),
+                            false => tui.label(label),
+                        };
+                    })
+                    .response;
+                if response.clicked() {
+                    clicked = Some(idx);
+                }
+
+                if idx + 1 < count {
+                    // Connector is vertically centered with step circles
+                    let completed = idx < current;
+                    tui.mut_egui_style(|style| {
+                        if completed {
+                            let stroke = &mut style.visuals.widgets.noninteractive.bg_stroke;
+                            stroke.color = style.visuals.selection.bg_fill;
+                            stroke.width = stroke.width.max(2.);
+                        }
+                    })
+                    .style(taffy::Style {
+                        flex_direction: taffy::FlexDirection::Column,
+                        justify_content: Some(taffy::AlignContent::Center),
+                        flex_grow: 1.,
+                        min_size: taffy::Size {
+                            width: length(diameter),
+                            height: auto(),
+                        },
+                        size: taffy::Size {
+                            width: auto(),
+                            height: length(diameter),
+                        },
+                        ..Default::default()
+                    })
+                    .add(|tui| tui.separator());
+                }
+            }
+
+            clicked
+        })
+    }
+}
+
+/// Paint numbered step circle of [`TuiStepper`]
+fn paint_step_circle(ui: &Ui, rect: egui::Rect, idx: usize, current: usize) {
+    let visuals = ui.visuals();
+    let radius = rect.width() / 2.;
+
+    let (fill, stroke, text_color, text) = match idx.cmp(&current) {
+        std::cmp::Ordering::Less => (
+            visuals.selection.bg_fill,
+            egui::Stroke::NONE,
+            visuals.selection.stroke.color,
+            "✔".to_owned(),
+        ),
+        std::cmp::Ordering::Equal => (
+            visuals.selection.bg_fill,
+            visuals.selection.stroke,
+            visuals.selection.stroke.color,
+            (idx + 1).to_string(),
+        ),
+        std::cmp::Ordering::Greater => (
+            visuals.widgets.inactive.weak_bg_fill,
+            visuals.widgets.noninteractive.bg_stroke,
+            visuals.text_color(),
+            (idx + 1).to_string(),
+        ),
+    };
+
+    let painter = ui.painter();
+    painter.circle(rect.center(), radius - stroke.width / 2., fill, stroke);
+    painter.text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        egui::TextStyle::Button.resolve(ui.style()),
+        text_color,
+    );
+}
+
+/// Horizontally scrollable tab strip with arrow buttons
+///
+/// Tabs are placed in `overflow: Scroll` flex row. When tabs do not fit
+/// (see [`Tui::did_overflow`]), arrow buttons are shown on both sides of the row,
+/// clicking them scrolls tabs by a part of visible width (see [`Tui::set_scroll_offset`]).
+///
+/// ```ignore
+/// selected = TuiTabBar::new(["Home", "Settings", "About"], selected).show(tui);
+/// ```
+pub struct TuiTabBar {
+    tabs: Vec<egui::WidgetText>,
+    selected: usize,
+}
+
+impl TuiTabBar {
+    /// Create tab bar with given tab labels and selected tab index
+    pub fn new(
+        tabs: impl IntoIterator<Item = impl Into<egui::WidgetText>>,
+        selected: usize,
+    ) -> Self {
+        Self {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            selected,
+        }
+    }
+
+    /// Show tab bar in a new flex row node
+    ///
+    /// Returns selected tab index (changed if tab was clicked in this frame).
+    pub fn show<'r>(self, tuib: impl TuiBuilderLogic<'r>) -> usize {
+        let TuiTabBar { tabs, mut selected } = self;
+
+        let tuib = tuib.tui();
+        let gap = tuib.builder_tui().egui_ui().spacing().item_spacing.x;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Row;
+            style.align_items = Some(taffy::AlignItems::Center);
+            style.gap = length(gap);
+        })
+        .add(|tui| {
+            let scroll_id = tui.current_id().with(egui::Id::new("tab_bar_scroll"));
+
+            // Scroll state of the last frame
+            let overflow = tui.did_overflow(scroll_id).x;
+            let max_offset = tui.overflow_amount(scroll_id).x;
+            let offset = tui.scroll_offset(scroll_id).x;
+            let can_scroll_left = offset > 0.5;
+            let can_scroll_right = offset < max_offset - 0.5;
+            let viewport = tui.node_rect(scroll_id).unwrap_or(egui::Rect::NOTHING);
+            let step = viewport.width().max(0.) * 0.75;
+
+            if overflow
+                && tui
+                    .style(taffy::Style {
+                        flex_shrink: 0.,
+                        ..Default::default()
+                    })
+                    .ui(|ui| ui.add_enabled(can_scroll_left, egui::Button::new("⏴")))
+                    .clicked()
+            {
+                // Applied when tabs are drawn below
+                tui.set_scroll_offset(scroll_id, egui::vec2((offset - step).max(0.), 0.));
+            }
+
+            // Tab labels are not wrapped
+            tui.id(tid("tab_bar_scroll"))
+                .wrap_mode(egui::TextWrapMode::Extend)
+                .style(taffy::Style {
+                    flex_direction: taffy::FlexDirection::Row,
+                    align_items: Some(taffy::AlignItems::Center),
+                    gap: length(gap),
+                    flex_grow: 1.,
+                    min_size: taffy::Size {
+                        width: length(0.),
+                        height: auto(),
+                    },
+                    overflow: taffy::Point {
+                        x: taffy::Overflow::Scroll,
+                        y: taffy::Overflow::Hidden,
+                    },
+                    ..Default::default()
+                })
+                .add(|tui| {
+                    for (idx, text) in tabs.into_iter().enumerate() {
+                        let response = tui
+                            .id(tid(idx))
+                            .style(taffy::Style {
+                                flex_shrink: 0.,
+                                ..Default::default()
+                            })
+                            .ui_add(egui::SelectableLabel::new(idx == selected, text));
+                        if response.clicked() {
+                            selected = idx;
+                            response.scroll_to_me(None);
+                        }
+                    }
+                });
+
+            if overflow
+                && tui
+                    .style(taffy::Style {
+                        flex_shrink: 0.,
+                        ..Default::default()
+                    })
+                    .ui(|ui| ui.add_enabled(can_scroll_right, egui::Button::new("⏵")))
+                    .clicked()
+            {
+                // Tabs are already drawn, scroll is shown in the next frame
+                let offset = (offset + step).min(max_offset);
+                tui.set_scroll_offset(scroll_id, egui::vec2(offset, 0.));
+            }
+
+            selected
+        })
+    }
+}
+
+/// Keyboard navigable menu (e.g. dropdown or command palette item list)
+///
+/// Items are placed in a flex column. Highlighted item is changed with up/down arrow keys
+/// or by hovering, `Enter` or click chooses item. Keys are handled while menu has keyboard
+/// focus (menu is focusable with `Tab` or by clicking an item) or if
+/// [`TuiMenu::keyboard_focus`] is set.
+///
+/// ```ignore
+/// if let Some(idx) = TuiMenu::new(["Open", "Save", "Quit"]).show(tui).inner {
+///     run_command(idx);
+/// }
+/// ```
+pub struct TuiMenu {
+    items: Vec<egui::WidgetText>,
+    wrap_around: bool,
+    type_ahead: bool,
+    keyboard_focus: bool,
+}
+
+/// State of [`TuiMenu`] stored in egui memory
+#[derive(Clone, Default)]
+struct TuiMenuState {
+    highlighted: Option<usize>,
+    type_ahead: String,
+    last_type_ahead_time: f64,
+}
+
+impl TuiMenu {
+    /// Create menu with given items
+    pub fn new(items: impl IntoIterator<Item = impl Into<egui::WidgetText>>) -> Self {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            wrap_around: true,
+            type_ahead: false,
+            keyboard_focus: false,
+        }
+    }
+
+    /// Move highlight from the last item to the first and vice versa (default: true)
+    #[inline]
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Highlight first item starting with typed text (default: false)
+    ///
+    /// Typed text is reset after one second without typing.
+    #[inline]
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Handle keys even if menu does not have keyboard focus (default: false)
+    ///
+    /// Useful when keyboard focus stays in a text field above the menu (command palette).
+    #[inline]
+    pub fn keyboard_focus(mut self, keyboard_focus: bool) -> Self {
+        self.keyboard_focus = keyboard_focus;
+        self
+    }
+
+    /// Show menu in a new flex column node
+    ///
+    /// Returns menu focus response and index of item chosen in this frame.
+    pub fn show<'r>(self, tuib: impl TuiBuilderLogic<'r>) -> TuiInnerResponse<Option<usize>> {
+        let TuiMenu {
+            items,
+            wrap_around,
+            type_ahead,
+            keyboard_focus,
+        } = self;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = taffy::FlexDirection::Column;
+            if style.align_items.is_none() {
+                style.align_items = Some(taffy::AlignItems::Stretch);
+            }
+        })
+        .add(|tui| {
+            let ctx = tui.egui_ui().ctx().clone();
+            let state_id = tui.current_id().with("menu_state");
+            let mut state: TuiMenuState =
+                ctx.data(|data| data.get_temp(state_id)).unwrap_or_default();
+            state.highlighted = state.highlighted.filter(|idx| *idx < items.len());
+
+            // Menu area is focusable, items keep their own click interaction
+            let response = tui.egui_ui().interact(
+                tui.taffy_container().full_container(),
+                tui.current_id().with("menu_focus"),
+                egui::Sense::FOCUSABLE,
+            );
+            if response.has_focus() {
+                // Arrow keys should not move focus to other widgets
+                ctx.memory_mut(|memory| {
+                    memory.set_focus_lock_filter(
+                        response.id,
+                        egui::EventFilter {
+                            vertical_arrows: true,
+                            ..Default::default()
+                        },
+                    )
+                });
+            }
+
+            let mut chosen = None;
+            let mut scroll_to_highlighted = false;
+            if (keyboard_focus || response.has_focus()) && !items.is_empty() {
+                let last = items.len() - 1;
+                let (down, up, enter, text) = ctx.input_mut(|input| {
+                    let text = input
+                        .events
+                        .iter()
+                        .filter_map(|event| match event {
+                            egui::Event::Text(text) => Some(text.as_str()),
+                            _ => None,
+                        })
+                        .collect::<String>();
+                    (
+                        input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                        input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                        input.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                        text,
+                    )
+                });
+
+                if down {
+                    state.highlighted = Some(match state.highlighted {
+                        None => 0,
+                        Some(idx) if idx < last => idx + 1,
+                        Some(_) if wrap_around => 0,
+                        Some(idx) => idx,
+                    });
+                    scroll_to_highlighted = true;
+                }
+                if up {
+                    state.highlighted = Some(match state.highlighted {
+                        Some(idx) if idx > 0 => idx - 1,
+                        None | Some(_) if wrap_around => last,
+                        None | Some(_) => 0,
+                    });
+                    scroll_to_highlighted = true;
+                }
+                if enter {
+                    chosen = state.highlighted;
+                }
+
+                if type_ahead && !text.is_empty() {
+                    let time = ctx.input(|input| input.time);
+                    if time - state.last_type_ahead_time > 1. {
+                        state.type_ahead.clear();
+                    }
+                    state.last_type_ahead_time = time;
+                    state.type_ahead.push_str(&text.to_lowercase());
+
+                    if let Some(idx) = items
+                        .iter()
+                        .position(|item| item.text().to_lowercase().starts_with(&state.type_ahead))
+                    {
+                        state.highlighted = Some(idx);
+                        scroll_to_highlighted = true;
+                    }
+                }
+            }
+
+            let pointer_moving = ctx.input(|input| input.pointer.is_moving());
+            for (idx, item) in items.into_iter().enumerate() {
+                let highlighted = state.highlighted == Some(idx);
+                let item_response = tui
+                    .id(tid(idx))
+                    .selectable(highlighted, |tui| {
+                        tui.label(item);
+                    })
+                    .response;
+
+                if item_response.hovered() && pointer_moving {
+                    state.highlighted = Some(idx);
+                }
+                if item_response.clicked() {
+                    state.highlighted = Some(idx);
+                    chosen = Some(idx);
+                    response.request_focus();
+                }
+                if highlighted && scroll_to_highlighted {
+                    item_response.scroll_to_me(None);
+                }
+            }
+
+            ctx.data_mut(|data| data.insert_temp(state_id, state));
+
+            TuiInnerResponse {
+                inner: chosen,
+                response,
+            }
+        })
+    }
+}
+
+/// Numeric grid value aligned on decimal separator (e.g. currency column of a table)
+///
+/// Value is split at the last decimal separator into two grid cells placed next to each other:
+/// integer part is aligned to the end of its column and fractional part (with separator) to
+/// the start of the next column. Numeric column therefore takes two grid tracks (`auto` width)
+/// and values of all rows align on decimal separator. Value without separator has empty
+/// fractional cell. Use `span(2)` grid placement for column header.
+///
+/// ```ignore
+/// // grid_template_columns: vec![fr(1.), auto(), auto()]
+/// tui.label("Rent");
+/// TuiDecimal::new("$1,250.00").show(tui);
+/// tui.label("Coffee");
+/// TuiDecimal::new("$3.5").show(tui);
+/// ```
+pub struct TuiDecimal {
+    text: String,
+    separator: char,
+}
+
+impl TuiDecimal {
+    /// Create decimal value from formatted number
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            separator: '.',
+        }
+    }
+
+    /// Set decimal separator (default: `.`)
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Add integer and fractional part cells to the current grid
+    ///
+    /// Returns label responses of integer and fractional parts.
+    pub fn show(self, tui: &mut Tui) -> (egui::Response, egui::Response) {
+        let TuiDecimal { text, separator } = self;
+        let (integer, fraction) = text
+            .rfind(separator)
+            .map_or((text.as_str(), ""), |idx| text.split_at(idx));
+
+        let mut part = |text: &str, justify: taffy::JustifySelf| {
+            tui.wrap_mode(egui::TextWrapMode::Extend)
+                .style(taffy::Style {
+                    justify_self: Some(justify),
+                    ..Default::default()
+                })
+                .label(text)
+        };
+
+        let integer = part(integer, taffy::JustifySelf::End);
+        let fraction = part(fraction, taffy::JustifySelf::Start);
+        (integer, fraction)
+    }
+}
+
+/// Two panes separated by draggable handle (split view)
+///
+/// Split ratio is bound to `f32` owned by the caller (fraction of space taken by the first pane,
+/// handle excluded), therefore application can persist it or share it between several splits.
+/// Displayed ratio is clamped to keep minimal pane sizes. Ratio is written only when handle is
+/// dragged, it snaps to `0.5` when handle is dragged close to the center.
+///
+/// ```ignore
+/// TuiSplit::new(&mut state.split_ratio)
+///     .min_sizes(100., 150.)
+///     .show(tui, |tui| tui.label("Left"), |tui| tui.label("Right"));
+/// ```
+pub struct TuiSplit<'a> {
+    ratio: &'a mut f32,
+    vertical: bool,
+    min_sizes: [f32; 2],
+    snap_distance: f32,
+}
+
+impl<'a> TuiSplit<'a> {
+    /// Create split bound to given ratio
+    pub fn new(ratio: &'a mut f32) -> Self {
+        Self {
+            ratio,
+            vertical: false,
+            min_sizes: [0., 0.],
+            snap_distance: 8.,
+        }
+    }
+
+    /// Place panes above each other instead of side by side (default: `false`)
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// Minimal sizes of the first and second pane along split direction
+    pub fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.min_sizes = [first, second];
+        self
+    }
+
+    /// Distance from center in points at which dragged handle snaps to `0.5`
+    /// (default: `8.`, `0.` disables snapping)
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.snap_distance = snap_distance;
+        self
+    }
+
+    /// Show split in a new flex node, panes fill node size
+    ///
+    /// Returns values returned by pane closures and handle response
+    /// (marked as changed when ratio was changed by dragging).
+    pub fn show<'r, A, B>(
+        self,
+        tuib: impl TuiBuilderLogic<'r>,
+        first: impl FnOnce(&mut Tui) -> A,
+        second: impl FnOnce(&mut Tui) -> B,
+    ) -> TuiInnerResponse<(A, B)> {
+        let TuiSplit {
+            ratio,
+            vertical,
+            min_sizes,
+            snap_distance,
+        } = self;
+
+        let tuib = tuib.tui();
+        let handle_size = tuib.builder_tui().egui_ui().spacing().item_spacing.x;
+
+        tuib.mut_style(|style| {
+            style.display = taffy::Display::Flex;
+            style.flex_direction = match vertical {
+                true => taffy::FlexDirection::Column,
+                false => taffy::FlexDirection::Row,
+            };
+            style.align_items = Some(taffy::AlignItems::Stretch);
+        })
+        .add(|tui| {
+            let axis = |vec: egui::Vec2| if vertical { vec.y } else { vec.x };
+            let inner = tui
+                .taffy_container()
+                .full_container_without_border_and_padding();
+            let available = (axis(inner.size()) - handle_size).max(0.);
+
+            let clamp = |value: f32| {
+                if available <= 0. {
+                    return value.clamp(0., 1.);
+                }
+                let min = (min_sizes[0] / available).min(1.);
+                let max = (1. - min_sizes[1] / available).max(min);
+                value.clamp(min, max)
+            };
+            let current = clamp(*ratio);
+
+            let pane = |grow: f32| taffy::Style {
+                flex_direction: taffy::FlexDirection::Column,
+                flex_grow: grow,
+                flex_shrink: 1.,
+                flex_basis: length(0.),
+                min_size: taffy::Size {
+                    width: length(0.),
+                    height: length(0.),
+                },
+                overflow: taffy::Point {
+                    x: taffy::Overflow::Hidden,
+                    y: taffy::Overflow::Hidden,
+                },
+                ..Default::default()
+            };
+
+            let first = tui.style(pane(current)).add(first);
+
+            let mut response = tui
+                .style(taffy::Style {
+                    flex_shrink: 0.,
+                    size: match vertical {
+                        true => taffy::Size {
+                            width: auto(),
+                            height: length(handle_size),
+                        },
+                        false => taffy::Size {
+                            width: length(handle_size),
+                            height: auto(),
+                        },
+                    },
+                    ..Default::default()
+                })
+                .ui(|ui| {
+                    let rect = ui.max_rect();
+                    let response =
+                        ui.interact(rect, ui.id().with("split handle"), egui::Sense::drag());
+                    let cursor = match vertical {
+                        true => egui::CursorIcon::ResizeVertical,
+                        false => egui::CursorIcon::ResizeHorizontal,
+                    };
+                    let response = response.on_hover_cursor(cursor);
+
+                    let stroke = ui.style().interact(&response).fg_stroke;
+                    let stroke = match response.hovered() || response.dragged() {
+                        true => stroke,
+                        false => ui.visuals().widgets.noninteractive.bg_stroke,
+                    };
+                    let center = rect.center();
+                    let line = match vertical {
+                        true => [
+                            egui::pos2(rect.left(), center.y),
+                            egui::pos2(rect.right(), center.y),
+                        ],
+                        false => [
+                            egui::pos2(center.x, rect.top()),
+                            egui::pos2(center.x, rect.bottom()),
+                        ],
+                    };
+                    ui.painter().line_segment(line, stroke);
+                    response
+                });
+
+            let second = tui.style(pane(1. - current)).add(second);
+
+            if available > 0. {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    let position = axis(pointer - inner.min) - handle_size / 2.;
+                    let mut value = clamp(position / available);
+                    if ((value - 0.5) * available).abs() < snap_distance {
+                        value = 0.5;
+                    }
+                    if value != *ratio {
+                        *ratio = value;
+                        response.mark_changed();
+                    }
+                }
+            }
+
+            TuiInnerResponse {
+                inner: (first, second),
+                response,
+            }
+        })
+    }
+}
+
+/// [`egui::ComboBox`] that can be placed in taffy layout
+///
+/// Node is measured using closed combo box button, popup is shown in separate egui area
+/// and does not affect layout. Button width fits the widest item, therefore it does not change
+/// with selected item or while popup is open. Items are measured when combo box is shown for
+/// the first time and measurement is reused until [`TuiComboBox::items_key`] or button text
+/// size changes.
+///
+/// `add_contents` is called to show popup items like in [`egui::ComboBox::show_ui`]
+/// and one more time in invisible ui when items are measured, therefore it must not have
+/// side effects other than through item responses.
+///
+/// ```
+/// use egui_taffy::{widgets::TuiComboBox, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui, selected: &mut &str) {
+/// tui.ui_add(TuiComboBox::new("letter", *selected, |ui| {
+///     ui.selectable_value(selected, "A", "A");
+///     ui.selectable_value(selected, "Long item", "Long item");
+/// }));
+/// # }
+/// ```
+pub struct TuiComboBox<F> {
+    id_salt: egui::Id,
+    selected_text: egui::WidgetText,
+    items_key: egui::Id,
+    add_contents: F,
+}
+
+impl<F, R> TuiComboBox<F>
+where
+    F: FnMut(&mut Ui) -> R,
+{
+    /// Create combo box, see [`egui::ComboBox::from_id_salt`]
+    pub fn new(
+        id_salt: impl std::hash::Hash,
+        selected_text: impl Into<egui::WidgetText>,
+        add_contents: F,
+    ) -> Self {
+        Self {
+            id_salt: egui::Id::new(id_salt),
+            selected_text: selected_text.into(),
+            items_key: egui::Id::NULL,
+            add_contents,
+        }
+    }
+
+    /// Items are measured again when key changes (e.g. item count or hash of item texts)
+    pub fn items_key(mut self, key: impl std::hash::Hash) -> Self {
+        self.items_key = egui::Id::new(key);
+        self
+    }
+}
+
+impl<F, R> TuiWidget for TuiComboBox<F>
+where
+    F: FnMut(&mut Ui) -> R,
+{
+    type Response = egui::InnerResponse<Option<R>>;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        let TuiComboBox {
+            id_salt,
+            selected_text,
+            items_key,
+            mut add_contents,
+        } = self;
+
+        tuib.ui_manual(|ui, _container| {
+            let width_id = ui.make_persistent_id(id_salt).with("items_width");
+            // Measured width is valid for the same items and text size
+            let measure_key = items_key.with(egui::TextStyle::Button.resolve(ui.style()));
+            let cached = ui.data(|data| data.get_temp::<(egui::Id, f32)>(width_id));
+            let items_width = match cached.filter(|(key, _)| *key == measure_key) {
+                Some((_, width)) => width,
+                None => {
+                    let mut items_ui = ui.new_child(
+                        UiBuilder::new()
+                            .max_rect(ui.ctx().screen_rect())
+                            .layout(egui::Layout::top_down(Align::Min))
+                            .sizing_pass()
+                            .invisible(),
+                    );
+                    items_ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                    add_contents(&mut items_ui);
+                    let width = items_ui.min_rect().width();
+                    ui.data_mut(|data| data.insert_temp(width_id, (measure_key, width)));
+                    width
+                }
+            };
+
+            // Items are measured with button padding, button adds dropdown icon
+            let spacing = ui.spacing();
+            let width = items_width + spacing.icon_spacing + spacing.icon_width;
+            let inner = egui::ComboBox::from_id_salt(id_salt)
+                .selected_text(selected_text)
+                .wrap_mode(egui::TextWrapMode::Extend)
+                .width(width)
+                .show_ui(ui, add_contents);
+
+            let size = inner.response.rect.size();
+            TuiContainerResponse::new(inner, size, size)
+        })
+    }
+}
+
+/// Color value edited by [`TuiColorEdit`]
+enum ColorEditValue<'a> {
+    Srgba(&'a mut egui::Color32),
+    Hsva(&'a mut egui::ecolor::Hsva),
+}
+
+/// Color edit button (color swatch that opens color picker popup) for taffy leaf node
+///
+/// Node has fixed size of egui color button ([`egui::style::Spacing::interact_size`]),
+/// picker popup is shown in separate egui area and does not affect layout.
+///
+/// ```
+/// use egui_taffy::{widgets::TuiColorEdit, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui, color: &mut egui::Color32) {
+/// tui.ui_add(TuiColorEdit::srgba(color));
+/// # }
+/// ```
+pub struct TuiColorEdit<'a> {
+    value: ColorEditValue<'a>,
+    alpha: egui::color_picker::Alpha,
+}
+
+impl<'a> TuiColorEdit<'a> {
+    /// Edit sRGBA color, alpha is edited as blend or additive (same as
+    /// [`egui::Ui::color_edit_button_srgba`])
+    pub fn srgba(color: &'a mut egui::Color32) -> Self {
+        Self {
+            value: ColorEditValue::Srgba(color),
+            alpha: egui::color_picker::Alpha::BlendOrAdditive,
+        }
+    }
+
+    /// Edit HSVA color, alpha is edited as blend or additive
+    pub fn hsva(color: &'a mut egui::ecolor::Hsva) -> Self {
+        Self {
+            value: ColorEditValue::Hsva(color),
+            alpha: egui::color_picker::Alpha::BlendOrAdditive,
+        }
+    }
+
+    /// Set how alpha channel is edited (e.g. [`egui::color_picker::Alpha::Opaque`]
+    /// to edit color without alpha)
+    pub fn alpha(mut self, alpha: egui::color_picker::Alpha) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl TuiWidget for TuiColorEdit<'_> {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        let TuiColorEdit { value, alpha } = self;
+
+        tuib.ui_add_manual(
+            |ui| match value {
+                ColorEditValue::Srgba(color) => {
+                    egui::color_picker::color_edit_button_srgba(ui, color, alpha)
+                }
+                ColorEditValue::Hsva(color) => {
+                    egui::color_picker::color_edit_button_hsva(ui, color, alpha)
+                }
+            },
+            |mut val, ui| {
+                // Swatch has fixed size of egui color button
+                let size = ui.spacing().interact_size;
+                val.min_size = size;
+                val.intrinsic_size = Some(size);
+                val.max_size = size;
+                val.infinite = egui::Vec2b::FALSE;
+                val
+            },
+        )
+    }
+}
+
+/// Zoomable and pannable canvas, content is laid out by taffy in logical (untransformed)
+/// space and painted through pan/zoom transform clipped to canvas node rectangle
+///
+/// Transform maps logical content coordinates to coordinates relative to canvas top-left
+/// corner, it is owned by caller (store it to persist view between frames). Dragging canvas
+/// background pans, pinch or ctrl + scroll zooms and scrolling pans. Transform is applied to
+/// egui layer, therefore hit-testing of widgets inside of canvas works as usual.
+///
+/// Canvas node size is taken from node style (set size or grow), it does not depend on content.
+/// Content root node has canvas node size in logical units, content can overflow it
+/// (e.g. absolutely positioned nodes of a node graph).
+///
+/// ```
+/// use egui_taffy::{taffy, widgets::TuiCanvas, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui, transform: &mut egui::emath::TSTransform) {
+/// let canvas = tui.style(taffy::Style {
+///     size: taffy::prelude::length(200.),
+///     ..Default::default()
+/// });
+/// TuiCanvas::new(transform).show(canvas, |tui| {
+///     // Content is laid out in logical space
+///     tui.label("Zoomed");
+/// });
+/// # }
+/// ```
+pub struct TuiCanvas<'a> {
+    transform: &'a mut egui::emath::TSTransform,
+    zoom_range: egui::Rangef,
+}
+
+impl<'a> TuiCanvas<'a> {
+    /// Create canvas bound to given transform
+    pub fn new(transform: &'a mut egui::emath::TSTransform) -> Self {
+        Self {
+            transform,
+            zoom_range: egui::Rangef::new(0.1, 4.),
+        }
+    }
+
+    /// Allowed zoom (transform scaling) range (default: `0.1..=4.`)
+    pub fn zoom_range(mut self, zoom_range: impl Into<egui::Rangef>) -> Self {
+        self.zoom_range = zoom_range.into();
+        self
+    }
+
+    /// Show canvas in a new leaf node, content is laid out in separate taffy tree
+    ///
+    /// Returns value returned by content closure and canvas background response
+    /// (marked as changed when transform was changed by user).
+    pub fn show<'r, T>(
+        self,
+        tuib: impl TuiBuilderLogic<'r>,
+        content: impl FnOnce(&mut Tui) -> T,
+    ) -> TuiInnerResponse<T> {
+        let TuiCanvas {
+            transform,
+            zoom_range,
+        } = self;
+
+        tuib.ui_manual(|ui, _params| {
+            let viewport = ui.max_rect();
+
+            // Content is painted in separate layer placed directly above parent layer,
+            // egui applies layer transform to both painting and hit-testing
+            let layer_id = egui::LayerId::new(ui.layer_id().order, ui.id().with("canvas"));
+            ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+
+            let mut canvas_ui = ui.new_child(
+                UiBuilder::new()
+                    .layer_id(layer_id)
+                    .max_rect(egui::Rect::from_min_size(egui::Pos2::ZERO, viewport.size()))
+                    .sense(egui::Sense::click_and_drag()),
+            );
+
+            let mut response = canvas_ui.response();
+            let viewport_offset =
+                egui::emath::TSTransform::from_translation(viewport.min.to_vec2());
+            let mut to_global = viewport_offset * *transform;
+            egui::Scene::new()
+                .zoom_range(zoom_range)
+                .register_pan_and_zoom(&canvas_ui, &mut response, &mut to_global);
+            if response.changed() {
+                *transform = viewport_offset.inverse() * to_global;
+            }
+
+            let visible_rect = to_global.inverse() * viewport;
+            canvas_ui.set_clip_rect(visible_rect);
+
+            // Content root has canvas size in logical units, content can overflow it
+            let inner = crate::tui(&mut canvas_ui, ui.id().with("canvas content"))
+                .reserve_space(viewport.size())
+                .show(content);
+
+            // Background covers whole viewport to catch pan and zoom anywhere on canvas
+            canvas_ui.expand_to_include_rect(visible_rect);
+            ui.ctx().set_transform_layer(layer_id, to_global);
+
+            TuiContainerResponse::new(
+                TuiInnerResponse { inner, response },
+                egui::Vec2::ZERO,
+                egui::Vec2::ZERO,
+            )
+        })
+    }
+}
+
+/// Multiline text edit that grows vertically with its content
+///
+/// Node min height is measured from laid out text at the current node width, therefore editor
+/// grows (and shrinks) while typing. Node can grow vertically without limit, so it can be
+/// stretched by layout (e.g. `flex_grow` in column). Editor fills whole node.
+///
+/// ```
+/// use egui_taffy::{widgets::TuiTextEdit, TuiBuilderLogic};
+///
+/// # fn example(tui: &mut egui_taffy::Tui, notes: &mut String) {
+/// tui.ui_add(TuiTextEdit::multiline(notes));
+/// # }
+/// ```
+pub struct TuiTextEdit<'a> {
+    text: &'a mut dyn egui::TextBuffer,
+    hint_text: egui::WidgetText,
+    min_rows: usize,
+}
+
+impl<'a> TuiTextEdit<'a> {
+    /// Create multiline text edit bound to given text
+    pub fn multiline(text: &'a mut dyn egui::TextBuffer) -> Self {
+        Self {
+            text,
+            hint_text: Default::default(),
+            min_rows: 1,
+        }
+    }
+
+    /// Text shown while text edit is empty
+    pub fn hint_text(mut self, hint_text: impl Into<egui::WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// Minimal height of text edit in text rows (default: `1`)
+    pub fn min_rows(mut self, min_rows: usize) -> Self {
+        self.min_rows = min_rows;
+        self
+    }
+}
+
+impl TuiWidget for TuiTextEdit<'_> {
+    type Response = egui::Response;
+
+    fn taffy_ui(self, tuib: TuiBuilder) -> Self::Response {
+        let TuiTextEdit {
+            text,
+            hint_text,
+            min_rows,
+        } = self;
+
+        tuib.ui_manual(|ui, _params| {
+            let margin = egui::Margin::from(ui.spacing().button_padding);
+            let output = egui::TextEdit::multiline(text)
+                .hint_text(hint_text)
+                .margin(margin)
+                .desired_rows(min_rows)
+                .desired_width(ui.available_width())
+                .min_size(ui.available_size())
+                .show(ui);
+
+            // Response fills node, content height is measured from text galley
+            let font_id = egui::FontSelection::Default.resolve(ui.style());
+            let row_height = ui.fonts(|fonts| fonts.row_height(&font_id));
+            let content_height =
+                output.galley.size().y.max(min_rows as f32 * row_height) + margin.sum().y;
+
+            let spacing = ui.spacing();
+            TuiContainerResponse {
+                inner: output.response,
+                min_size: egui::vec2(spacing.interact_size.x, content_height),
+                intrinsic_size: None,
+                max_size: egui::vec2(spacing.text_edit_width, content_height),
+                infinite: egui::Vec2b::new(false, true),
+                first_baseline: None,
+            }
+        })
+    }
+}
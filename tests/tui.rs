@@ -0,0 +1,1505 @@
+//! Behavior tests of tui layout and widgets
+//!
+//! Tests run egui frames with headless [`egui::Context`], layout of new nodes is calculated
+//! in the first frame and applied in the following passes.
+
+use std::sync::Arc;
+
+use egui_taffy::{
+    measure_ui,
+    style_builder::StyleBuilder,
+    taffy, tid, tui,
+    virtual_tui::{
+        VirtualGridColumnHelper, VirtualGridColumnHelperParams, VirtualGridRowHelper,
+        VirtualGridRowHelperParams, VirtualListHelper, VirtualListHelperParams, VirtualTreeHelper,
+    },
+    widgets::{
+        TuiCanvas, TuiCollapsing, TuiCollapsingHeader, TuiColorEdit, TuiComboBox, TuiTextEdit,
+    },
+    TaffyState, TuiBuilderLogic, TuiContainerResponse, TuiId,
+};
+use taffy::prelude::*;
+
+/// Run `frames` frames showing `f` in central panel
+fn run_frames(ctx: &egui::Context, frames: usize, mut f: impl FnMut(&mut egui::Ui)) {
+    for _ in 0..frames {
+        run_frame(ctx, Default::default(), &mut f);
+    }
+}
+
+/// Run one frame with given input showing `f` in central panel
+fn run_frame(
+    ctx: &egui::Context,
+    input: egui::RawInput,
+    mut f: impl FnMut(&mut egui::Ui),
+) -> egui::FullOutput {
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| f(ui));
+    })
+}
+
+#[test]
+fn safe_area_insets_are_added_to_root_padding() {
+    let ctx = egui::Context::default();
+    let mut offset = egui::Vec2::ZERO;
+    run_frames(&ctx, 1, |ui| {
+        let origin = ui.max_rect().min;
+        tui(ui, "safe area")
+            .style(Style {
+                padding: length(4.),
+                ..Default::default()
+            })
+            .safe_area(taffy::Rect {
+                left: 10.,
+                right: 0.,
+                top: 20.,
+                bottom: 0.,
+            })
+            .show(|tui| offset = tui.label("Content").rect.min - origin);
+    });
+    assert_eq!(offset, egui::vec2(14., 24.));
+}
+
+#[test]
+fn unstable_layout_discards_are_limited() {
+    let ctx = egui::Context::default();
+    ctx.options_mut(|options| options.max_passes = std::num::NonZeroUsize::new(100).unwrap());
+    let mut passes = 0;
+    run_frames(&ctx, 1, |ui| {
+        passes += 1;
+        tui(ui, "unstable").show(|tui| {
+            // Size changes on every pass
+            let size = if passes % 2 == 0 { 10. } else { 20. };
+            tui.style(Style {
+                size: length(size),
+                ..Default::default()
+            })
+            .add_empty();
+        });
+    });
+    // 8 discarded passes and the final one
+    assert_eq!(passes, 9);
+}
+
+#[test]
+fn measure_only_returns_shown_size() {
+    let ctx = egui::Context::default();
+    let (mut measured, mut shown) = (egui::Vec2::ZERO, egui::Vec2::ZERO);
+    run_frames(&ctx, 1, |ui| {
+        let content = |tui: &mut egui_taffy::Tui| {
+            tui.heading("Title");
+            tui.label("Description");
+        };
+        measured = tui(ui, "content").measure_only(content);
+        shown = ui
+            .scope(|ui| tui(ui, "content").show(content))
+            .response
+            .rect
+            .size();
+    });
+    assert_eq!(measured, shown);
+}
+
+#[test]
+fn measure_only_does_not_recalculate_shown_layout() {
+    let ctx = egui::Context::default();
+    let mut passes = Vec::new();
+    for _frame in 0..3 {
+        let mut frame_passes = 0;
+        let _ = ctx.run(Default::default(), |ctx| {
+            frame_passes += 1;
+            let menu = |tui: &mut egui_taffy::Tui| {
+                tui.label("First");
+                tui.label("Second");
+            };
+            let size = tui(&mut measure_ui(ctx, "menu measure"), "menu").measure_only(menu);
+            egui::SidePanel::left("menu panel")
+                .resizable(false)
+                .exact_width(size.x + 16.)
+                .show(ctx, |ui| {
+                    tui(ui, "menu").reserve_available_space().show(menu);
+                });
+        });
+        passes.push(frame_passes);
+    }
+    // Layout is not recalculated in steady frames
+    assert_eq!(passes[2], 1);
+}
+
+#[test]
+fn keyed_children_keep_nodes_and_state_when_reordered() {
+    let ctx = egui::Context::default();
+    let mut nodes = Vec::new();
+    for items in [["a", "b", "c"], ["c", "a", "b"]] {
+        let mut frame_nodes = std::collections::HashMap::new();
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "keyed").show(|tui| {
+                tui.keyed_children(
+                    |key| *key,
+                    items,
+                    |tui, key| {
+                        tui.add(|tui| {
+                            frame_nodes.insert(key, tui.current_node());
+                            // egui state stored by child ui id
+                            let ui_id = tui.egui_ui().id();
+                            let stored = tui.egui_ctx().data_mut(|data| {
+                                data.get_temp_mut_or(ui_id, key.to_string()).clone()
+                            });
+                            assert_eq!(stored, key);
+                            tui.label(key);
+                        });
+                    },
+                );
+            });
+        });
+        nodes.push(frame_nodes);
+    }
+    // Reordered children keep their nodes
+    assert_eq!(nodes[0], nodes[1]);
+}
+
+#[test]
+fn measure_wrapped_wraps_at_given_width() {
+    let ctx = egui::Context::default();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "tooltip").show(|tui| {
+            let text = "Tooltip text that does not fit on a single line of given width";
+            let line = tui.measure_wrapped(text, f32::INFINITY);
+            let wrapped = tui.measure_wrapped(text, 100.);
+            assert!(wrapped.x <= 100.);
+            assert!(wrapped.y > line.y);
+        });
+    });
+}
+
+#[test]
+fn node_context_reports_wrapped_label_sizes() {
+    let ctx = egui::Context::default();
+    let mut sizes = (None, None);
+    run_frames(&ctx, 1, |ui| {
+        let style = Style {
+            flex_direction: FlexDirection::Column,
+            size: Size {
+                width: length(60.),
+                height: auto(),
+            },
+            ..Default::default()
+        };
+        tui(ui, "sizes").style(style).show(|tui| {
+            let (wrapped, extended) = (egui::Id::new("wrapped"), egui::Id::new("extended"));
+            tui.id(TuiId::Unique(wrapped)).label("Hello measured world");
+            tui.id(TuiId::Unique(extended))
+                .wrap_mode(egui::TextWrapMode::Extend)
+                .label("Hello measured world");
+            sizes = (tui.node_context(wrapped), tui.node_context(extended));
+        });
+    });
+    let (wrapped, extended) = (sizes.0.unwrap(), sizes.1.unwrap());
+    // Label in narrow node wraps its text
+    assert!(wrapped.0.x < extended.0.x);
+    assert!(wrapped.0.y > extended.0.y);
+    assert_eq!(extended.2, egui::Vec2b::FALSE);
+}
+
+#[test]
+fn measure_aspect_ignores_node_grow() {
+    let ctx = egui::Context::default();
+    let mut aspect = None;
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "aspect").show(|tui| {
+            assert_eq!(tui.measure_aspect(egui::Id::new("unknown")), None);
+            let id = egui::Id::new("row");
+            tui.id(TuiId::Unique(id))
+                .style(Style {
+                    flex_grow: 1.,
+                    gap: length(10.),
+                    ..Default::default()
+                })
+                .add(|tui| {
+                    for _ in 0..2 {
+                        tui.style(Style {
+                            size: length(40.),
+                            ..Default::default()
+                        })
+                        .add_empty();
+                    }
+                });
+            aspect = tui.measure_aspect(id);
+        });
+    });
+    // Two 40x40 boxes with 10 gap, grow of node itself is ignored
+    assert_eq!(aspect, Some(90. / 40.));
+}
+
+#[test]
+fn min_content_size_wraps_flex_items() {
+    let ctx = egui::Context::default();
+    let mut min_size = egui::Vec2::ZERO;
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "min size")
+            .reserve_available_space()
+            .style(Style {
+                flex_wrap: FlexWrap::Wrap,
+                gap: length(10.),
+                padding: length(5.),
+                ..Default::default()
+            })
+            .show(|tui| {
+                for _ in 0..2 {
+                    tui.style(Style {
+                        size: length(40.),
+                        ..Default::default()
+                    })
+                    .add_empty();
+                }
+                min_size = tui.min_content_size();
+            });
+    });
+    // Boxes wrap into a column
+    assert_eq!(min_size, egui::vec2(50., 100.));
+}
+
+/// Column of 20 items 50 points tall scrolled vertically in 100 point viewport,
+/// returns scroll node id and item nodes
+fn scroll_list(tui: &mut egui_taffy::Tui) -> (egui::Id, Vec<taffy::NodeId>) {
+    let mut style = Style {
+        flex_direction: FlexDirection::Column,
+        size: length(100.),
+        ..Default::default()
+    };
+    style.overflow.y = taffy::Overflow::Scroll;
+
+    tui.id("scroll").style(style).add(|tui| {
+        let items = (0..20)
+            .map(|_| {
+                tui.style(Style {
+                    size: length(50.),
+                    flex_shrink: 0.,
+                    ..Default::default()
+                })
+                .add(|tui| tui.current_node())
+            })
+            .collect();
+        (tui.current_id(), items)
+    })
+}
+
+#[test]
+fn set_scroll_offset_changes_only_scrolled_direction() {
+    let ctx = egui::Context::default();
+    let mut offsets = Vec::new();
+    for frame in 0..3 {
+        let mut offset = egui::Vec2::ZERO;
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "list").reserve_available_space().show(|tui| {
+                let (list, _) = scroll_list(tui);
+                if frame == 1 {
+                    tui.set_scroll_offset(list, egui::vec2(30., 120.));
+                }
+                offset = tui.scroll_offset(list);
+            });
+        });
+        offsets.push(offset);
+    }
+    assert_eq!(
+        offsets,
+        vec![egui::Vec2::ZERO, egui::vec2(0., 120.), egui::vec2(0., 120.)]
+    );
+}
+
+#[test]
+fn scroll_to_node_centers_node() {
+    let ctx = egui::Context::default();
+    let mut offset = egui::Vec2::ZERO;
+    for frame in 0..3 {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "list").reserve_available_space().show(|tui| {
+                let (list, items) = scroll_list(tui);
+                if frame == 1 {
+                    // Center 11th item: 500 + 25 - 50
+                    tui.scroll_to_node(items[10], egui::Align2::CENTER_CENTER);
+                }
+                offset = tui.scroll_offset(list);
+            });
+        });
+    }
+    assert_eq!(offset, egui::vec2(0., 475.));
+}
+
+#[test]
+fn margin_rect_includes_margins() {
+    let ctx = egui::Context::default();
+    let mut rects = (egui::Rect::NOTHING, egui::Rect::NOTHING);
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "margins").show(|tui| {
+            tui.style(Style {
+                size: length(50.),
+                margin: length(10.),
+                ..Default::default()
+            })
+            .add_with_background_ui(
+                |_ui, container| rects = (container.margin_rect(), container.full_container()),
+                |_tui, _| {},
+            );
+        });
+    });
+    let (margin_rect, full_container) = rects;
+    assert_eq!(margin_rect, full_container.expand(10.));
+}
+
+#[test]
+fn available_width_is_known_in_first_pass() {
+    let ctx = egui::Context::default();
+    ctx.options_mut(|options| options.max_passes = std::num::NonZeroUsize::new(8).unwrap());
+    let mut passes = 0;
+    let mut widths = Vec::new();
+    run_frames(&ctx, 1, |ui| {
+        passes += 1;
+        tui(ui, "wrapped")
+            .reserve_available_width()
+            .style(Style {
+                flex_direction: FlexDirection::Column,
+                size: Size {
+                    width: length(200.),
+                    height: auto(),
+                },
+                padding: length(10.),
+                ..Default::default()
+            })
+            .show(|tui| {
+                tui.ui_manual(|ui, container| {
+                    widths.push(container.available_width());
+                    let response = ui.label("Wrapped text ".repeat(20));
+                    let size = response.rect.size();
+                    TuiContainerResponse {
+                        intrinsic_size: response.intrinsic_size,
+                        ..TuiContainerResponse::new(response, size, size)
+                    }
+                });
+            });
+    });
+    // Text is laid out at its final width already in the first pass,
+    // second pass only confirms that layout did not change
+    assert_eq!(widths, vec![180., 180.]);
+    assert_eq!(passes, 2);
+}
+
+#[test]
+fn taffy_state_is_rebuilt_after_tree_modification() {
+    let ctx = egui::Context::default();
+    let id = egui::Id::new("layout");
+    let mut node_counts = Vec::new();
+    for frame in 0..3 {
+        if frame == 1 {
+            // Nodes known to tui are removed, taffy would panic on their ids
+            let state = ctx.data(|data| data.get_temp::<Arc<parking_lot::Mutex<TaffyState>>>(id));
+            state.unwrap().lock().taffy_tree_mut().clear();
+        }
+        let mut node_count = 0;
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, id).show(|tui| {
+                tui.label("Label");
+                node_count = tui.taffy_state().taffy_tree().total_node_count();
+            });
+        });
+        node_counts.push(node_count);
+    }
+    // Root and label nodes are created again
+    assert_eq!(node_counts, vec![2, 2, 2]);
+}
+
+#[test]
+fn egui_id_of_is_reverse_of_items() {
+    let ctx = egui::Context::default();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "inspect").show(|tui| {
+            tui.id("child").add(|tui| {
+                let (id, node) = (tui.current_id(), tui.current_node());
+                let state = tui.taffy_state();
+                assert_eq!(state.items()[&id].node_id, node);
+                assert_eq!(state.egui_id_of(node), Some(id));
+            });
+        });
+    });
+}
+
+#[test]
+fn baseline_aligned_widgets_align_text_baselines() {
+    // Widget of given height with text baseline at given distance from its top edge
+    let widget = |tui: &mut egui_taffy::Tui, height: f32, baseline: f32| {
+        tui.ui_manual(|ui, _params| {
+            let size = egui::vec2(20., height);
+            let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+            TuiContainerResponse {
+                first_baseline: Some(baseline),
+                ..TuiContainerResponse::new(rect, size, size)
+            }
+        })
+    };
+
+    let ctx = egui::Context::default();
+    let mut rects = Vec::new();
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "baseline")
+            .style(Style {
+                align_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            })
+            .show(|tui| {
+                rects = vec![widget(tui, 40., 30.), widget(tui, 20., 10.)];
+            });
+    });
+
+    // Baselines are aligned, not bottom edges
+    assert_eq!(rects[0].top() + 30., rects[1].top() + 10.);
+}
+
+#[test]
+fn baseline_aligned_node_keeps_set_style_fields() {
+    let ctx = egui::Context::default();
+    let mut styles = Vec::new();
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "baseline style")
+            .style(Style {
+                align_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            })
+            .show(|tui| {
+                tui.label("Default style");
+                tui.style(Style {
+                    display: Display::Block,
+                    align_items: Some(AlignItems::Center),
+                    ..Default::default()
+                })
+                .label("Block");
+
+                let state = tui.taffy_state();
+                let tree = state.taffy_tree();
+                styles = tree
+                    .children(tui.current_node())
+                    .unwrap()
+                    .into_iter()
+                    .map(|node| tree.style(node).unwrap().clone())
+                    .collect();
+            });
+    });
+
+    // Only default fields are changed to stack baseline nodes in a column
+    let fields = |style: &Style| (style.display, style.flex_direction, style.align_items);
+    assert_eq!(
+        styles.iter().map(fields).collect::<Vec<_>>(),
+        [
+            (
+                Display::Flex,
+                FlexDirection::Column,
+                Some(AlignItems::Stretch)
+            ),
+            (
+                Display::Block,
+                FlexDirection::Column,
+                Some(AlignItems::Center)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn style_str_sets_node_style() {
+    let ctx = egui::Context::default();
+    let mut rects = Vec::new();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "style str").show(|tui| {
+            tui.style_str("flex col w:200").add(|tui| {
+                rects = [
+                    "w:40 h:20 ml:auto mt:5",
+                    "w:40 h:20px mx:auto",
+                    "w:40 h:NaN",
+                ]
+                .map(|dsl| {
+                    tui.style_str(dsl)
+                        .add(|tui| tui.taffy_container().full_container())
+                })
+                .to_vec();
+            });
+        });
+    });
+    assert_eq!(rects[0].size(), egui::vec2(40., 20.));
+    assert_eq!(rects[1].size(), egui::vec2(40., 20.));
+    // Auto margins push nodes to the right edge and to the center
+    assert_eq!(rects[0].max.x - rects[1].max.x, 80.);
+    assert_eq!(rects[1].min.y, rects[0].max.y);
+    // Invalid style falls back to default style (empty node)
+    assert_eq!(rects[2].height(), 0.);
+}
+
+#[test]
+fn egui_layout_aligns_content_in_stretched_cell() {
+    let ctx = egui::Context::default();
+    let (mut cell, mut text) = (egui::Rect::NOTHING, egui::Rect::NOTHING);
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "centered")
+            .style(Style {
+                flex_direction: FlexDirection::Column,
+                size: Size {
+                    width: length(300.),
+                    height: auto(),
+                },
+                ..Default::default()
+            })
+            .show(|tui| {
+                cell = tui.taffy_container().full_container();
+                text = tui
+                    .egui_layout(egui::Layout::top_down(egui::Align::Center))
+                    .label("Text")
+                    .rect;
+            });
+    });
+    assert!((text.center().x - cell.center().x).abs() < 1.);
+}
+
+#[test]
+fn sticky_cells_stay_aligned_with_scrolled_grid_cells() {
+    let ctx = egui::Context::default();
+    let scroll = egui::Id::new("grid");
+    let cell = |row, column| egui::Id::new((row, column));
+    let mut rects = std::collections::HashMap::new();
+    for frame in 0..4 {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "sticky grid").show(|tui| {
+                if frame == 1 {
+                    tui.set_scroll_offset(scroll, egui::vec2(37., 53.));
+                }
+                tui.id(TuiId::Unique(scroll))
+                    .style(Style {
+                        display: Display::Grid,
+                        overflow: taffy::Point {
+                            x: taffy::Overflow::Scroll,
+                            y: taffy::Overflow::Scroll,
+                        },
+                        size: length(150.),
+                        gap: length(10.),
+                        padding: length(7.),
+                        border: length(3.),
+                        grid_template_rows: vec![length(40.); 5],
+                        grid_template_columns: vec![length(60.); 5],
+                        ..Default::default()
+                    })
+                    .add_with_border(|tui| {
+                        for row in 0..5 {
+                            for column in 0..5 {
+                                tui.id(TuiId::Unique(cell(row, column)))
+                                    .sticky(egui::Vec2b::new(column == 0, row == 0))
+                                    .style(Style {
+                                        grid_row: line(row + 1),
+                                        grid_column: line(column + 1),
+                                        ..Default::default()
+                                    })
+                                    .add_empty();
+                            }
+                        }
+                    });
+                for row in 0..5 {
+                    for column in 0..5 {
+                        rects.insert((row, column), tui.node_rect(cell(row, column)).unwrap());
+                    }
+                }
+            });
+        });
+    }
+    // Corner cell stays at content origin (inside of border and padding)
+    let corner = rects[&(0, 0)];
+    // Header cells are aligned with scrolled cells in the same column
+    assert_eq!(rects[&(0, 2)].min.x, rects[&(3, 2)].min.x);
+    assert_eq!(rects[&(0, 2)].min.y, corner.min.y);
+    assert_eq!(rects[&(3, 2)].min.x - corner.min.x, 2. * 70. - 37.);
+    // Row header cells are aligned with scrolled cells in the same row
+    assert_eq!(rects[&(2, 0)].min.y, rects[&(2, 3)].min.y);
+    assert_eq!(rects[&(2, 0)].min.x, corner.min.x);
+    assert_eq!(rects[&(2, 3)].min.y - corner.min.y, 2. * 50. - 53.);
+}
+
+#[test]
+fn sticky_to_does_not_apply_sticky_twice() {
+    let ctx = egui::Context::default();
+    let scroll = egui::Id::new("scroll");
+    let mut top = Vec::new();
+    for offset in [0., 0., 30.] {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "sticky to").reserve_available_space().show(|tui| {
+                let style = StyleBuilder::new()
+                    .flex_column()
+                    .width(100.)
+                    .height(100.)
+                    .overflow(taffy::Overflow::Hidden, taffy::Overflow::Scroll)
+                    .build();
+                tui.set_scroll_offset(scroll, egui::vec2(0., offset));
+                tui.id(TuiId::Unique(scroll)).style(style).add(|tui| {
+                    // `sticky` in the same axis is not applied twice
+                    let sticky = tui
+                        .sticky(egui::Vec2b::TRUE)
+                        .sticky_to(None, Some(scroll))
+                        .label("Sticky");
+                    top.push(sticky.rect.top());
+                    let content = StyleBuilder::new().height(500.).shrink(0.).build();
+                    tui.style(content).add_empty();
+                });
+            });
+        });
+    }
+    assert_eq!(top.first(), top.last());
+}
+
+#[test]
+fn sticky_header_receives_clicks_above_scrolled_rows() {
+    let ctx = egui::Context::default();
+    let (scroll, header) = (egui::Id::new("scroll"), egui::Id::new("header"));
+    let mut header_rect = egui::Rect::NOTHING;
+    let mut clicked = Vec::new();
+    for frame in 0..5 {
+        let click = |pressed| egui::Event::PointerButton {
+            pos: header_rect.center(),
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        let events = match frame {
+            3 => vec![egui::Event::PointerMoved(header_rect.center()), click(true)],
+            4 => vec![click(false)],
+            _ => vec![],
+        };
+        let input = egui::RawInput {
+            time: Some(frame as f64 * 0.1),
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, input, |ui| {
+            tui(ui, "list").reserve_available_space().show(|tui| {
+                if frame == 1 {
+                    // First row is scrolled under the header
+                    tui.set_scroll_offset(scroll, egui::vec2(0., 30.));
+                }
+                let mut style = Style {
+                    flex_direction: FlexDirection::Column,
+                    size: length(100.),
+                    ..Default::default()
+                };
+                style.overflow.y = taffy::Overflow::Scroll;
+
+                tui.id(TuiId::Unique(scroll)).style(style).add(|tui| {
+                    let row = Style {
+                        size: length(50.),
+                        flex_shrink: 0.,
+                        ..Default::default()
+                    };
+                    let header_style = Style {
+                        size: length(20.),
+                        ..row.clone()
+                    };
+                    let response = tui
+                        .id(TuiId::Unique(header))
+                        .sticky_header()
+                        .style(header_style)
+                        .clickable(|_| ());
+                    if response.clicked() {
+                        clicked.push("header".to_owned());
+                    }
+                    for idx in 0..10 {
+                        if tui.style(row.clone()).clickable(|_| ()).clicked() {
+                            clicked.push(format!("row {idx}"));
+                        }
+                    }
+                });
+                header_rect = tui.node_rect(header).unwrap();
+            });
+        });
+    }
+    assert_eq!(clicked, vec!["header"]);
+}
+
+#[test]
+fn pull_to_refresh_requests_refresh_past_threshold() {
+    let ctx = egui::Context::default();
+    let frame = |events: Vec<egui::Event>| {
+        let (mut item_top, mut refreshed) = (0., false);
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, input, |ui| {
+            tui(ui, "pull").reserve_available_space().show(|tui| {
+                let style = StyleBuilder::new()
+                    .width(100.)
+                    .height(100.)
+                    .overflow(taffy::Overflow::Hidden, taffy::Overflow::Scroll)
+                    .build();
+                tui.style(style).pull_to_refresh(40.).add(|tui| {
+                    refreshed = tui.refresh_requested(tui.current_id());
+                    item_top = tui.label("Item").rect.top();
+                });
+            });
+        });
+        (item_top, refreshed)
+    };
+    let pointer = |pressed| egui::Event::PointerButton {
+        pos: egui::pos2(30., 30.),
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+
+    frame(vec![egui::Event::PointerMoved(egui::pos2(30., 30.))]);
+    let (top, _) = frame(vec![]);
+    frame(vec![pointer(true)]);
+    let mut pulled = (top, false);
+    for y in [60., 90., 120., 150.] {
+        pulled = frame(vec![egui::Event::PointerMoved(egui::pos2(30., y))]);
+    }
+    // Content is pulled down with resistance
+    assert!(pulled.0 > top + 40. && !pulled.1);
+
+    assert_eq!(frame(vec![pointer(false)]), (top, true));
+}
+
+#[test]
+fn limit_growth_to_viewport_caps_infinite_leaf() {
+    let ctx = egui::Context::default();
+    let (mut root, mut widths) = (0., (0., 0.));
+    run_frames(&ctx, 4, |ui| {
+        tui(ui, "growth")
+            .style(Style {
+                flex_direction: FlexDirection::Column,
+                align_items: Some(AlignItems::Start),
+                ..Default::default()
+            })
+            .show(|tui| {
+                let viewport = Style {
+                    size: Size {
+                        width: length(100.),
+                        height: length(50.),
+                    },
+                    overflow: taffy::Point {
+                        x: taffy::Overflow::Scroll,
+                        y: taffy::Overflow::Scroll,
+                    },
+                    ..Default::default()
+                };
+                for (limit, id) in [(false, "root"), (true, "viewport")] {
+                    // Grid column is sized to max content of the progress bar
+                    let grid = Style {
+                        display: Display::Grid,
+                        grid_template_columns: vec![max_content()],
+                        ..viewport.clone()
+                    };
+                    tui.style(grid).add(|tui| {
+                        tui.id(TuiId::Unique(egui::Id::new(id)))
+                            .limit_growth_to_viewport(limit)
+                            .ui_add(egui::ProgressBar::new(0.5));
+                    });
+                }
+
+                let width = |id: &str| tui.node_rect(egui::Id::new(id)).unwrap().width();
+                widths = (width("root"), width("viewport"));
+                root = tui.current_viewport().width();
+            });
+    });
+    // Progress bar grows to root width or is capped to scroll container width
+    assert_eq!(widths, (root, 100.));
+}
+
+#[test]
+fn corner_radius_fraction_applies_to_background_only() {
+    let ctx = egui::Context::default();
+    let mut radius = Vec::new();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "pills").show(|tui| {
+            radius.clear();
+            for height in [20., 40.] {
+                let style = Style {
+                    size: Size {
+                        width: length(100.),
+                        height: length(height),
+                    },
+                    ..Default::default()
+                };
+                let corner_radius =
+                    |ui: &egui::Ui| ui.style().visuals.widgets.inactive.corner_radius;
+                let result = tui
+                    .style(style)
+                    .corner_radius_fraction(0.5)
+                    .add_with_background_ui(
+                        |ui, _container| corner_radius(ui),
+                        |tui, background| (*background, corner_radius(tui.egui_ui())),
+                    );
+                radius.push(result.main);
+            }
+        });
+    });
+
+    // Content keeps corner radius of egui visuals
+    let content = egui::Style::default()
+        .visuals
+        .widgets
+        .inactive
+        .corner_radius;
+    assert_eq!(
+        radius,
+        vec![
+            (egui::CornerRadius::same(10), content),
+            (egui::CornerRadius::same(20), content),
+        ]
+    );
+}
+
+#[test]
+fn visible_composes_with_display_methods() {
+    let ctx = egui::Context::default();
+    let mut displays = Vec::new();
+    run_frames(&ctx, 1, |ui| {
+        displays.clear();
+        tui(ui, "display").show(|tui| {
+            let display = |tui: &mut egui_taffy::Tui| tui.current_style().display;
+            displays.push(tui.grid_display().visible(false).add(display));
+            displays.push(tui.visible(false).grid_display().add(display));
+            displays.push(tui.visible(true).grid_display().add(display));
+            // `visible(true)` does not show node hidden by display type
+            displays.push(tui.display_none().visible(true).add(display));
+        });
+    });
+    assert_eq!(
+        displays,
+        vec![Display::None, Display::None, Display::Grid, Display::None]
+    );
+}
+
+#[test]
+fn tab_index_moves_focus_in_tab_order() {
+    let ctx = egui::Context::default();
+    let mut texts = [String::new(), String::new(), String::new()];
+    let ids = std::cell::Cell::new([egui::Id::NULL; 3]);
+    let mut show = |input: egui::RawInput| {
+        run_frame(&ctx, input, |ui| {
+            tui(ui, "form").show(|tui| {
+                let mut field_ids = [egui::Id::NULL; 3];
+                // Created in reverse tab order
+                for (idx, text) in texts.iter_mut().enumerate().rev() {
+                    let text_edit = egui::TextEdit::singleline(text);
+                    field_ids[idx] = tui.tab_index(idx as i32).ui_add(text_edit).id;
+                }
+                ids.set(field_ids);
+            });
+            // egui would move focus from the last created field to this button
+            let _ = ui.button("After");
+        });
+    };
+    let tab = |modifiers| egui::RawInput {
+        events: vec![egui::Event::Key {
+            key: egui::Key::Tab,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }],
+        ..Default::default()
+    };
+
+    show(Default::default());
+    show(Default::default());
+    let ids = ids.get();
+    ctx.memory_mut(|memory| memory.request_focus(ids[0]));
+    show(Default::default());
+    let mut focused = Vec::new();
+    for modifiers in [
+        egui::Modifiers::NONE,
+        egui::Modifiers::NONE,
+        egui::Modifiers::NONE,
+        egui::Modifiers::SHIFT,
+    ] {
+        show(tab(modifiers));
+        focused.push(ctx.memory(|memory| memory.focused()));
+    }
+    assert_eq!(focused, [ids[1], ids[2], ids[0], ids[2]].map(Some).to_vec());
+}
+
+#[test]
+fn render_to_shapes_returns_shapes_without_painting() {
+    let ctx = egui::Context::default();
+    let mut thumbnail = Default::default();
+    let mut output = egui::FullOutput::default();
+    for _frame in 0..2 {
+        output = run_frame(&ctx, Default::default(), |ui| {
+            thumbnail = tui(ui, "swatches").render_to_shapes(|tui| {
+                tui.ui(|ui| {
+                    let size = egui::vec2(20., 10.);
+                    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                    let (left, right) = rect.split_left_right_at_fraction(0.5);
+                    ui.painter().rect_filled(left, 0., egui::Color32::RED);
+                    ui.painter().rect_filled(right, 0., egui::Color32::BLUE);
+                });
+            });
+        });
+    }
+    let is_swatch = |shape: &egui::Shape| matches!(shape, egui::Shape::Rect(rect) if rect.fill == egui::Color32::RED);
+    assert!(!output
+        .shapes
+        .iter()
+        .any(|clipped| is_swatch(&clipped.shape)));
+
+    let egui_taffy::TuiShapes { size, shapes } = thumbnail;
+    assert_eq!(size, egui::vec2(20., 10.));
+    // Shapes are relative to left top corner of tui
+    let swatch = shapes
+        .iter()
+        .find(|clipped| is_swatch(&clipped.shape))
+        .unwrap();
+    assert_eq!(
+        swatch.shape.visual_bounding_rect(),
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(10., 10.))
+    );
+}
+
+#[test]
+fn reveal_clips_content_to_growing_rect() {
+    let ctx = egui::Context::default();
+    let mut output = egui::FullOutput::default();
+    let mut node_rect = egui::Rect::NOTHING;
+    // Reveal animation is in progress in the last frame
+    for (time, revealed) in [(0., false), (0.1, false), (1., true), (1.25, true)] {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        output = run_frame(&ctx, input, |ui| {
+            tui(ui, "reveal").show(|tui| {
+                tui.reveal(egui::vec2(0., 0.5), revealed)
+                    .style(Style {
+                        size: length(40.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        node_rect = tui.taffy_container().full_container();
+                        let color = egui::Color32::RED;
+                        tui.egui_ui().painter().rect_filled(node_rect, 0., color);
+                    });
+            });
+        });
+    }
+    let clip_rect = output
+        .shapes
+        .iter()
+        .find_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) if rect.fill == egui::Color32::RED => Some(clipped.clip_rect),
+            _ => None,
+        })
+        .unwrap();
+    // Rect grows from the middle of the left edge and does not cover the node yet
+    assert_eq!(clip_rect.min.x, node_rect.min.x);
+    assert!((clip_rect.center().y - node_rect.center().y).abs() < 1e-3);
+    assert!((clip_rect.width() - clip_rect.height()).abs() < 1e-3);
+    assert!(clip_rect.width() > 0. && clip_rect.width() < node_rect.width());
+}
+
+#[test]
+fn relative_offset_does_not_move_siblings() {
+    let ctx = egui::Context::default();
+    let mut rects = Vec::new();
+    for offset in [egui::Vec2::ZERO, egui::vec2(5., 10.)] {
+        let mut frame_rects = Vec::new();
+        run_frames(&ctx, 2, |ui| {
+            frame_rects.clear();
+            tui(ui, "offset").show(|tui| {
+                frame_rects.push(tui.label("First").rect);
+                frame_rects.push(tui.relative_offset(offset).label("Second").rect);
+                frame_rects.push(tui.label("Third").rect);
+            });
+        });
+        rects.push(frame_rects);
+    }
+    // Offset element is moved, its siblings keep their positions
+    assert_eq!(rects[1][1], rects[0][1].translate(egui::vec2(5., 10.)));
+    assert_eq!((rects[1][0], rects[1][2]), (rects[0][0], rects[0][2]));
+}
+
+#[test]
+fn size_builders_set_size_styles() {
+    let ctx = egui::Context::default();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "sizes").show(|tui| {
+            tui.exact_size(egui::vec2(100., 40.))
+                .min_size([50., 20.])
+                .max_size((200., 80.))
+                .add(|tui| {
+                    let style = tui.current_style();
+                    let size = |width, height| Size {
+                        width: length(width),
+                        height: length(height),
+                    };
+                    assert_eq!(style.size, size(100., 40.));
+                    assert_eq!(style.min_size, size(50., 20.));
+                    assert_eq!(style.max_size, size(200., 80.));
+                });
+        });
+    });
+}
+
+#[test]
+fn label_clamped_limits_rows() {
+    let ctx = egui::Context::default();
+    let text = "Long description of the card that does not fit on two lines of text";
+    let mut heights = [0.; 3];
+    run_frames(&ctx, 3, |ui| {
+        tui(ui, "clamped").show(|tui| {
+            let style = Style {
+                size: Size {
+                    width: length(80.),
+                    height: auto(),
+                },
+                ..Default::default()
+            };
+            heights = [
+                tui.style(style.clone()).label(text),
+                tui.style(style.clone()).label_clamped(text, 2),
+                tui.style(style).label_clamped(text, 1),
+            ]
+            .map(|response| response.rect.height());
+        });
+    });
+    let [full, two_rows, one_row] = heights;
+    assert_eq!(two_rows, 2. * one_row);
+    assert!(full > two_rows);
+}
+
+#[test]
+fn wrap_reverse_stacks_lines_from_cross_end() {
+    // Three 40x10 items in 100 wide container wrap into two lines: [0, 1] and [2]
+    let item_positions = |container: StyleBuilder| {
+        let ctx = egui::Context::default();
+        let container = container.width(100.).height(20.).build();
+        let mut positions = Vec::new();
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "wrap").show(|tui| {
+                tui.style(container.clone()).add(|tui| {
+                    let origin = tui.taffy_container().full_container().min;
+                    let item = StyleBuilder::new().width(40.).height(10.).build();
+                    positions = (0..3)
+                        .map(|_| {
+                            let rect = tui
+                                .style(item.clone())
+                                .add(|tui| tui.taffy_container().full_container());
+                            rect.min - origin
+                        })
+                        .collect::<Vec<_>>();
+                });
+            });
+        });
+        positions
+    };
+
+    let wrap = item_positions(StyleBuilder::new().flex_row().wrap());
+    assert_eq!(
+        wrap,
+        [egui::vec2(0., 0.), egui::vec2(40., 0.), egui::vec2(0., 10.)]
+    );
+
+    let wrap_reverse = item_positions(StyleBuilder::new().flex_row().wrap_reverse());
+    assert_eq!(
+        wrap_reverse,
+        [
+            egui::vec2(0., 10.),
+            egui::vec2(40., 10.),
+            egui::vec2(0., 0.)
+        ]
+    );
+}
+
+#[test]
+fn collapsing_set_all_open_opens_nested_sections() {
+    let ctx = egui::Context::default();
+    let mut taffy_id = egui::Id::NULL;
+    for frame in 0..3 {
+        if frame == 1 {
+            TuiCollapsing::set_all_open(&ctx, taffy_id, true);
+        }
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "tree").show(|tui| {
+                taffy_id = tui.main_taffy_id();
+                TuiCollapsing::new("Outer")
+                    .lazy(true)
+                    .show(tui.id("outer"), |tui| {
+                        TuiCollapsing::new("Inner").show(tui.id("inner"), |tui| {
+                            tui.label("Leaf");
+                        });
+                    });
+            });
+        });
+    }
+    // Nested section was opened as well
+    let states = TuiCollapsing::states(&ctx, taffy_id);
+    assert_eq!(states.len(), 2);
+    assert!(states.iter().all(|(_, open)| *open));
+}
+
+#[test]
+fn collapsing_header_shows_body_only_when_open() {
+    let ctx = egui::Context::default();
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "settings").show(|tui| {
+            let response = TuiCollapsingHeader::new("Advanced")
+                .default_open(true)
+                .show(tui, |tui| tui.label("Body"));
+            assert!(response.body_shown);
+
+            let response = TuiCollapsingHeader::new("Hidden").show(tui, |tui| tui.label("Body"));
+            assert!(!response.body_shown);
+            assert!(response.body_returned.is_none());
+        });
+    });
+}
+
+#[test]
+fn image_keeps_aspect_ratio_when_width_flexes() {
+    let ctx = egui::Context::default();
+    let size = egui::vec2(200., 100.);
+    let texture = egui::load::SizedTexture::new(egui::TextureId::default(), size);
+    let mut rect = egui::Rect::NOTHING;
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "image")
+            .style(Style {
+                size: length(100.),
+                align_items: Some(AlignItems::Start),
+                ..Default::default()
+            })
+            .show(|tui| {
+                let grow = Style {
+                    flex_grow: 1.,
+                    ..Default::default()
+                };
+                rect = tui
+                    .style(grow)
+                    .ui_add(egui::Image::from_texture(texture))
+                    .rect;
+            });
+    });
+    // Image width flexes to row width, height follows image aspect ratio
+    assert_eq!(rect.size(), egui::vec2(100., 50.));
+}
+
+#[test]
+fn combo_box_width_fits_widest_item() {
+    let ctx = egui::Context::default();
+    let mut widths = Vec::new();
+    let mut selected = "A";
+    for _ in 0..2 {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "combo").show(|tui| {
+                let response = tui.ui_add(TuiComboBox::new("letter", selected, |ui| {
+                    ui.selectable_value(&mut selected, "A", "A");
+                    ui.selectable_value(&mut selected, "Long item", "Long item");
+                }));
+                // Popup is closed
+                assert!(response.inner.is_none());
+                widths.push(response.response.rect.width());
+            });
+        });
+        selected = "Long item";
+    }
+    // Width fits the widest item regardless of selected item
+    assert_eq!(widths[0], widths[1]);
+}
+
+#[test]
+fn combo_box_items_are_measured_again_when_key_changes() {
+    let ctx = egui::Context::default();
+    let mut widths = Vec::new();
+    for items in [vec!["A"], vec!["A", "Long item"]] {
+        let mut width = 0.;
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "combo").show(|tui| {
+                let mut selected = "A";
+                let combo_box = TuiComboBox::new("letter", selected, |ui| {
+                    for item in &items {
+                        ui.selectable_value(&mut selected, *item, *item);
+                    }
+                });
+                let response = tui.ui_add(combo_box.items_key(items.len()));
+                width = response.response.rect.width();
+            });
+        });
+        widths.push(width);
+    }
+    assert!(widths[0] < widths[1]);
+}
+
+#[test]
+fn checkbox_label_wraps_down_to_longest_word() {
+    let ctx = egui::Context::default();
+    let text = "Remember this device";
+    let (mut sizes, mut rect, mut expected) = (None, egui::Rect::NOTHING, (0., 0.));
+    run_frames(&ctx, 3, |ui| {
+        let icon_width = ui.spacing().icon_width + ui.spacing().icon_spacing;
+        let font_id = egui::TextStyle::Button.resolve(ui.style());
+        let text_width = |text: &str| {
+            let color = egui::Color32::WHITE;
+            ui.fonts(|fonts| fonts.layout_no_wrap(text.into(), font_id.clone(), color))
+                .size()
+                .x
+        };
+        expected = (
+            icon_width + text_width("Remember"),
+            icon_width + text_width(text),
+        );
+
+        let style = Style {
+            flex_direction: FlexDirection::Column,
+            size: Size {
+                width: length(80.),
+                height: auto(),
+            },
+            ..Default::default()
+        };
+        tui(ui, "checkbox").style(style).show(|tui| {
+            let id = egui::Id::new("remember");
+            let mut checked = false;
+            rect = tui
+                .id(TuiId::Unique(id))
+                .wrap_mode(egui::TextWrapMode::Wrap)
+                .ui_add(egui::Checkbox::new(&mut checked, text))
+                .rect;
+            sizes = tui.node_context(id);
+        });
+    });
+    let (min_size, max_size, _) = sizes.unwrap();
+    // Node shrinks down to the longest word and grows up to the single line label
+    assert_eq!(min_size.x, expected.0.ceil());
+    assert!((max_size.x - expected.1).abs() < 0.01);
+    // Height is measured with label wrapped at node width
+    assert!(rect.width() <= 80.);
+    assert_eq!(min_size.y, rect.height());
+    assert!(rect.height() > ctx.style().spacing.interact_size.y);
+}
+
+#[test]
+fn color_edit_has_interact_size() {
+    let ctx = egui::Context::default();
+    let mut color = egui::Color32::LIGHT_BLUE;
+    run_frames(&ctx, 1, |ui| {
+        tui(ui, "color").show(|tui| {
+            let response = tui.ui_add(TuiColorEdit::srgba(&mut color));
+            assert_eq!(response.rect.size(), tui.egui_ui().spacing().interact_size);
+        });
+    });
+}
+
+#[test]
+fn canvas_content_is_laid_out_in_logical_space() {
+    let ctx = egui::Context::default();
+    let mut transform = egui::emath::TSTransform::from_scaling(2.);
+    let mut label = egui::Rect::NOTHING;
+    run_frames(&ctx, 2, |ui| {
+        tui(ui, "canvas").show(|tui| {
+            let canvas = tui.style(Style {
+                size: length(200.),
+                ..Default::default()
+            });
+            TuiCanvas::new(&mut transform).show(canvas, |tui| {
+                label = tui.label("Zoomed").rect;
+            });
+        });
+    });
+    assert_eq!(label.min, egui::Pos2::ZERO);
+}
+
+#[test]
+fn text_edit_grows_with_content() {
+    let ctx = egui::Context::default();
+    let mut notes = String::from("First line");
+    let height = |notes: &mut String| {
+        let mut height = 0.;
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "notes").reserve_available_width().show(|tui| {
+                height = tui.ui_add(TuiTextEdit::multiline(notes)).rect.height();
+            });
+        });
+        height
+    };
+
+    height(&mut notes);
+    let one_row = height(&mut notes);
+    notes.push_str("\nSecond line\nThird line");
+    height(&mut notes);
+    assert!(height(&mut notes) > one_row * 2.);
+}
+
+#[test]
+fn virtual_grid_columns_and_rows_draw_only_visible_cells() {
+    let ctx = egui::Context::default();
+    let grid_id = egui::Id::new("grid");
+    let scroll = taffy::Overflow::Scroll;
+    let (mut drawn_columns, mut content_size) = (Vec::new(), Size::ZERO);
+    for frame in 0..4 {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "2d grid").reserve_available_space().show(|tui| {
+                if frame == 2 {
+                    // Scroll to column 100
+                    tui.set_scroll_offset(grid_id, egui::vec2(5400., 0.));
+                }
+                tui.id(TuiId::Unique(grid_id))
+                    .style(Style {
+                        display: Display::Grid,
+                        overflow: taffy::Point {
+                            x: scroll,
+                            y: scroll,
+                        },
+                        size: length(200.),
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        let column_params = VirtualGridColumnHelperParams {
+                            header_column_count: 0,
+                            column_count: 1000,
+                        };
+                        let columns = VirtualGridColumnHelper::visible_columns(column_params, tui);
+                        columns.add_spacers(tui);
+                        drawn_columns = columns.iter().map(|column| column.idx).collect();
+
+                        let row_params = VirtualGridRowHelperParams {
+                            header_row_count: 0,
+                            row_count: 1000,
+                        };
+                        VirtualGridRowHelper::show(row_params, tui, |tui, row| {
+                            for column in columns.iter() {
+                                tui.id(tid(("cell", row.idx, column.idx)))
+                                    .style(Style {
+                                        size: Size {
+                                            width: length(50.),
+                                            height: length(20.),
+                                        },
+                                        ..Default::default()
+                                    })
+                                    .mut_style(row.grid_row_setter())
+                                    .mut_style(column.grid_column_setter())
+                                    .add_empty();
+                            }
+                        });
+                        content_size = tui.taffy_container().layout().content_size;
+                    });
+            });
+        });
+    }
+    // Reference column and columns around the visible ones are drawn
+    assert_eq!(
+        drawn_columns,
+        [0].into_iter().chain(96..112).collect::<Vec<_>>()
+    );
+    // Spacers take space of hidden columns and rows
+    assert_eq!(
+        content_size,
+        Size {
+            width: 1000. * 54. - 4.,
+            height: 1000. * 24. - 4.
+        }
+    );
+}
+
+#[test]
+fn virtual_list_keeps_item_positions() {
+    let ctx = egui::Context::default();
+    let list_id = egui::Id::new("list");
+    let scroll_y = taffy::Point {
+        x: taffy::Overflow::Visible,
+        y: taffy::Overflow::Scroll,
+    };
+    let (mut items, mut content_height) = (Vec::new(), 0.);
+    for frame in 0..4 {
+        run_frames(&ctx, 1, |ui| {
+            tui(ui, "list").reserve_available_space().show(|tui| {
+                if frame == 2 {
+                    // Scroll to item 500
+                    tui.set_scroll_offset(list_id, egui::vec2(0., 17000.));
+                }
+                items.clear();
+                tui.id(TuiId::Unique(list_id))
+                    .style(Style {
+                        flex_direction: FlexDirection::Column,
+                        overflow: scroll_y,
+                        size: length(300.),
+                        gap: length(4.),
+                        ..Default::default()
+                    })
+                    .add(|tui| {
+                        let content_top = tui.taffy_container().full_container().min.y
+                            - tui.scroll_offset(list_id).y;
+                        let params = VirtualListHelperParams { item_count: 1000 };
+                        VirtualListHelper::show(params, tui, |tui, item| {
+                            let rect = tui
+                                .id(item.id())
+                                .style(Style {
+                                    size: Size {
+                                        width: auto(),
+                                        height: length(30.),
+                                    },
+                                    flex_shrink: 0.,
+                                    ..Default::default()
+                                })
+                                .add(|tui| tui.taffy_container().full_container());
+                            items.push((item.idx, rect.min.y - content_top));
+                        });
+                        content_height = tui.taffy_container().layout().content_size.height;
+                    });
+            });
+        });
+    }
+    // Reference item and items around the visible ones are added
+    let drawn: Vec<usize> = items.iter().map(|(idx, _)| *idx).collect();
+    assert_eq!(drawn, [0].into_iter().chain(496..520).collect::<Vec<_>>());
+    // Top spacer keeps items at their positions, both spacers keep list height
+    assert!(items.iter().all(|(idx, top)| *top == *idx as f32 * 34.));
+    assert_eq!(content_height, 1000. * 34. - 4.);
+}
+
+#[test]
+fn virtual_tree_rows_are_keyed_and_indented_with_margin() {
+    let ctx = egui::Context::default();
+    let mut frames = Vec::new();
+    // Directory is expanded in the second frame, file row is moved down
+    for rows in [
+        vec![("dir", 0), ("file.txt", 0)],
+        vec![("dir", 0), ("dir/nested.txt", 1), ("file.txt", 0)],
+    ] {
+        let mut nodes = Vec::new();
+        run_frames(&ctx, 1, |ui| {
+            nodes.clear();
+            tui(ui, "tree").show(|tui| {
+                tui.style(Style {
+                    display: Display::Grid,
+                    ..Default::default()
+                })
+                .add(|tui| {
+                    let depth = |row: &(&'static str, usize)| row.1;
+                    let key = |row: &(&'static str, usize)| row.0;
+                    VirtualTreeHelper::show(&rows, depth, key, 16., tui, |tui, info, row| {
+                        let node = tui
+                            .id(info.id())
+                            .style(Style {
+                                padding: length(4.),
+                                ..Default::default()
+                            })
+                            .mut_style(info.row_setter())
+                            .add(|tui| {
+                                tui.label(row.0);
+                                (tui.current_node(), tui.current_style().clone())
+                            });
+                        nodes.push(node);
+                    });
+                });
+            });
+        });
+        frames.push(nodes);
+    }
+    // File row keeps its node when rows are inserted before it
+    assert_eq!(frames[0][1].0, frames[1][2].0);
+    // Indentation is added as margin, row padding is kept
+    let nested = &frames[1][1].1;
+    assert_eq!(nested.margin.left, length(16.));
+    assert_eq!(nested.padding.left, length(4.));
+}